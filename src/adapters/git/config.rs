@@ -0,0 +1,42 @@
+//! Read-only access to git configuration values
+
+use std::path::PathBuf;
+use std::process::Command;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GitConfig;
+
+impl GitConfig {
+    /// A config value, or None if it is unset, empty or git cannot run.
+    pub fn get(&self, key: &str) -> Option<String> {
+        Self::run(&["config", "--get", key])
+    }
+
+    /// A boolean key, normalized by git (`yes`, `on`, `1` are all true).
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        Self::run(&["config", "--type=bool", "--get", key]).map(|v| v == "true")
+    }
+
+    /// A path-valued key with `~/` expanded by git (`git config --path`).
+    pub fn get_path(&self, key: &str) -> Option<PathBuf> {
+        Self::run(&["config", "--path", "--get", key]).map(PathBuf::from)
+    }
+
+    fn run(args: &[&str]) -> Option<String> {
+        let output = Command::new("git").args(args).output().ok()?;
+        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (output.status.success() && !value.is_empty()).then_some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_key_is_none() {
+        assert_eq!(GitConfig.get("commando.test.surely-unset-key"), None);
+        assert_eq!(GitConfig.get_path("commando.test.surely-unset-key"), None);
+        assert_eq!(GitConfig.get_bool("commando.test.surely-unset-key"), None);
+    }
+}
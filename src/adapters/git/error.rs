@@ -4,13 +4,22 @@ use std::fmt;
 pub enum GitError {
     NotAGitRepository,
     ExecutionFailed(String),
+    /// A git config key needed for the committer identity is unset.
+    MissingIdentity(String),
 }
 
 impl fmt::Display for GitError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            GitError::NotAGitRepository => write!(f, "Not a git repository"),
+            GitError::NotAGitRepository => {
+                write!(f, "Not a git repository — run `git init` or cd into one")
+            }
             GitError::ExecutionFailed(msg) => write!(f, "Git execution failed: {}", msg),
+            GitError::MissingIdentity(key) => write!(
+                f,
+                "Cannot sign off: git config {} is not set (run `git config {} <value>`)",
+                key, key
+            ),
         }
     }
 }
@@ -2,47 +2,207 @@
 
 use std::process::Command;
 
+use super::config::GitConfig;
 use super::error::GitError;
 use crate::ports::{CommitExecutor, CommitResult, DryRunner};
 
-#[derive(Debug, Default, Clone, Copy)]
-pub struct GitCommitExecutor;
+#[derive(Debug, Default, Clone)]
+pub struct GitCommitExecutor {
+    amend: bool,
+    /// None: don't sign. Some(""): sign with the default key. Some(id): that key.
+    gpg_sign: Option<String>,
+    /// Let git write to the terminal directly instead of capturing its output.
+    stream: bool,
+    no_verify: bool,
+    /// Commit only these paths (`git commit -- <paths>`); empty means all
+    /// that is staged.
+    paths: Vec<String>,
+}
 
-impl CommitExecutor for GitCommitExecutor {
-    type Error = GitError;
+impl GitCommitExecutor {
+    /// Pass `--amend` to git, replacing HEAD instead of adding a new commit.
+    pub fn with_amend(mut self, amend: bool) -> Self {
+        self.amend = amend;
+        self
+    }
 
-    fn execute(&self, message: &str) -> Result<CommitResult, Self::Error> {
-        let commit_output = Command::new("git")
+    /// Pass `-S` (or `--gpg-sign=<keyid>`) so git signs the commit.
+    /// Signing failures come back as git's stderr like any other failure.
+    pub fn with_gpg_sign(mut self, key: Option<String>) -> Self {
+        self.gpg_sign = key;
+        self
+    }
+
+    /// Pass `--no-verify` so git skips the pre-commit and commit-msg hooks.
+    pub fn with_no_verify(mut self, no_verify: bool) -> Self {
+        self.no_verify = no_verify;
+        self
+    }
+
+    /// Pass the paths after `--`. git then commits just those paths, and
+    /// takes them as they are in the working tree, not the index; the
+    /// controller refuses paths whose two versions differ.
+    pub fn with_paths(mut self, paths: Vec<String>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Inherit stdio for `git commit` so hook output appears as it is
+    /// produced. The SHA is read afterwards with `git rev-parse HEAD`.
+    pub fn with_streaming(mut self, stream: bool) -> Self {
+        self.stream = stream;
+        self
+    }
+
+    /// Arguments for `git commit`, excluding the leading "commit".
+    fn commit_args(&self, message: &str) -> Vec<String> {
+        let mut args = Vec::new();
+        if self.amend {
+            args.push("--amend".to_string());
+        }
+        if self.no_verify {
+            args.push("--no-verify".to_string());
+        }
+        match self.gpg_sign.as_deref() {
+            Some("") => args.push("-S".to_string()),
+            Some(key) => args.push(format!("--gpg-sign={}", key)),
+            None => {}
+        }
+        args.push("-m".to_string());
+        args.push(message.to_string());
+        if !self.paths.is_empty() {
+            args.push("--".to_string());
+            args.extend(self.paths.iter().cloned());
+        }
+        args
+    }
+
+    /// Run `git commit` with its output buffered; stderr becomes the error,
+    /// stdout is returned for its summary.
+    fn commit_captured(&self, message: &str) -> Result<String, GitError> {
+        let output = Command::new("git")
             .arg("commit")
-            .arg("-m")
-            .arg(message)
+            .args(self.commit_args(message))
             .output()
             .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
 
-        if !commit_output.status.success() {
-            let stderr = String::from_utf8_lossy(&commit_output.stderr);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
         }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
 
-        let sha_output = Command::new("git")
-            .arg("rev-parse")
-            .arg("HEAD")
-            .output()
+    /// Run `git commit` attached to the terminal. Its output has already
+    /// been shown, so a failure only reports the exit status.
+    fn commit_streamed(&self, message: &str) -> Result<(), GitError> {
+        let status = Command::new("git")
+            .arg("commit")
+            .args(self.commit_args(message))
+            .status()
             .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
 
-        if !sha_output.status.success() {
-            return Err(GitError::ExecutionFailed(
-                "Failed to get commit SHA".to_string(),
-            ));
+        if !status.success() {
+            return Err(streamed_failure(status.code()));
         }
+        Ok(())
+    }
 
-        let sha = String::from_utf8_lossy(&sha_output.stdout)
-            .trim()
-            .to_string();
+    /// Read a single git config value; unset or empty keys are an error.
+    fn config_value(key: &str) -> Result<String, GitError> {
+        GitConfig
+            .get(key)
+            .ok_or_else(|| GitError::MissingIdentity(key.to_string()))
+    }
+}
 
+/// Extract the SHA from `git rev-parse HEAD` output.
+fn parse_sha(stdout: &[u8]) -> Result<String, GitError> {
+    let sha = String::from_utf8_lossy(stdout).trim().to_string();
+    if sha.is_empty() || !sha.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(GitError::ExecutionFailed(
+            "Failed to get commit SHA".to_string(),
+        ));
+    }
+    Ok(sha)
+}
+
+/// The count from git commit's summary line, " 3 files changed, ..." or
+/// " 1 file changed, ...". The first line ("[main (root-commit) abc1234]
+/// subject") is skipped by requiring the leading number.
+fn parse_files_changed(stdout: &str) -> Option<usize> {
+    stdout.lines().find_map(|line| {
+        let (count, rest) = line.trim_start().split_once(' ')?;
+        if rest.starts_with("file changed") || rest.starts_with("files changed") {
+            count.parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+fn streamed_failure(code: Option<i32>) -> GitError {
+    let status = match code {
+        Some(code) => format!("exit code {}", code),
+        None => "terminated by a signal".to_string(),
+    };
+    GitError::ExecutionFailed(format!("git commit failed ({}); see output above", status))
+}
+
+impl CommitExecutor for GitCommitExecutor {
+    type Error = GitError;
+
+    fn execute(&self, message: &str) -> Result<CommitResult, Self::Error> {
+        let files_changed = if self.stream {
+            self.commit_streamed(message)?;
+            None
+        } else {
+            parse_files_changed(&self.commit_captured(message)?)
+        };
+
+        let sha = self
+            .head()?
+            .ok_or_else(|| GitError::ExecutionFailed("Failed to get commit SHA".to_string()))?;
         let summary = message.lines().next().unwrap_or("").to_string();
 
-        Ok(CommitResult { sha, summary })
+        Ok(CommitResult {
+            sha,
+            summary,
+            files_changed,
+        })
+    }
+
+    fn identity(&self) -> Result<String, Self::Error> {
+        let name = Self::config_value("user.name")?;
+        let email = Self::config_value("user.email")?;
+        Ok(format!("{} <{}>", name, email))
+    }
+
+    /// `git rev-parse --verify -q HEAD` prints nothing (and fails) on an
+    /// unborn branch; that is None, not an error.
+    fn head(&self) -> Result<Option<String>, Self::Error> {
+        let output = Command::new("git")
+            .args(["rev-parse", "--verify", "-q", "HEAD"])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            return Ok(None);
+        }
+        parse_sha(&output.stdout).map(Some)
+    }
+
+    fn reset_soft(&self, sha: &str) -> Result<(), Self::Error> {
+        let output = Command::new("git")
+            .args(["reset", "--soft", sha])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+        Ok(())
     }
 }
 
@@ -51,7 +211,8 @@ impl DryRunner for GitCommitExecutor {
 
     fn dry_run(&self, message: &str) -> Result<(), Self::Error> {
         let output = Command::new("git")
-            .args(["commit", "--dry-run", "-m", message])
+            .args(["commit", "--dry-run"])
+            .args(self.commit_args(message))
             .output()
             .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
 
@@ -75,6 +236,160 @@ mod tests {
 
     #[test]
     fn git_executor_has_default() {
-        let _executor = GitCommitExecutor::default();
+        let _executor: GitCommitExecutor = Default::default();
+    }
+
+    #[test]
+    fn missing_identity_error_names_the_key() {
+        let err = GitError::MissingIdentity("user.email".into());
+        assert!(err.to_string().contains("git config user.email"));
+    }
+
+    #[test]
+    fn commit_args_without_amend() {
+        let args = GitCommitExecutor::default().commit_args("feat: x");
+        assert_eq!(args, vec!["-m", "feat: x"]);
+    }
+
+    #[test]
+    fn commit_args_with_amend() {
+        let args = GitCommitExecutor::default()
+            .with_amend(true)
+            .commit_args("feat: x");
+        assert_eq!(args, vec!["--amend", "-m", "feat: x"]);
+    }
+
+    #[test]
+    fn commit_args_forward_the_pathspec() {
+        let args = GitCommitExecutor::default()
+            .with_amend(true)
+            .with_paths(vec!["src/a.rs".into(), "docs".into()])
+            .commit_args("feat: x");
+        assert_eq!(
+            args,
+            vec!["--amend", "-m", "feat: x", "--", "src/a.rs", "docs"]
+        );
+    }
+
+    #[test]
+    fn commit_args_without_paths_have_no_separator() {
+        let args = GitCommitExecutor::default().commit_args("feat: x");
+        assert!(!args.contains(&"--".to_string()));
+    }
+
+    #[test]
+    fn commit_args_with_no_verify() {
+        let args = GitCommitExecutor::default()
+            .with_no_verify(true)
+            .commit_args("feat: x");
+        assert_eq!(args, vec!["--no-verify", "-m", "feat: x"]);
+    }
+
+    #[test]
+    fn commit_args_verify_by_default() {
+        let args = GitCommitExecutor::default()
+            .with_amend(true)
+            .with_no_verify(false)
+            .commit_args("feat: x");
+        assert!(!args.contains(&"--no-verify".to_string()));
+    }
+
+    #[test]
+    fn commit_args_sign_with_default_key() {
+        let args = GitCommitExecutor::default()
+            .with_gpg_sign(Some(String::new()))
+            .commit_args("feat: x");
+        assert_eq!(args, vec!["-S", "-m", "feat: x"]);
+    }
+
+    #[test]
+    fn commit_args_sign_with_key_id() {
+        let args = GitCommitExecutor::default()
+            .with_amend(true)
+            .with_gpg_sign(Some("ABCD1234".into()))
+            .commit_args("feat: x");
+        assert_eq!(
+            args,
+            vec!["--amend", "--gpg-sign=ABCD1234", "-m", "feat: x"]
+        );
+    }
+
+    #[test]
+    fn parse_sha_trims_rev_parse_output() {
+        let sha = parse_sha(b"3f2a9c1e5b7d0a4c6e8f1b3d5a7c9e0f2b4d6a8c\n").unwrap();
+        assert_eq!(sha, "3f2a9c1e5b7d0a4c6e8f1b3d5a7c9e0f2b4d6a8c");
+    }
+
+    #[test]
+    fn parse_sha_rejects_empty_output() {
+        assert!(matches!(
+            parse_sha(b"\n"),
+            Err(GitError::ExecutionFailed(_))
+        ));
+    }
+
+    #[test]
+    fn parse_sha_rejects_non_hex_output() {
+        // e.g. HEAD printed back verbatim when it cannot be resolved
+        assert!(parse_sha(b"HEAD\n").is_err());
+    }
+
+    #[test]
+    fn parses_files_changed_from_commit_output() {
+        let stdout = "[main 3f2a9c1] feat(api): add search\n \
+                      3 files changed, 40 insertions(+), 2 deletions(-)\n \
+                      create mode 100644 src/search.rs\n";
+        assert_eq!(parse_files_changed(stdout), Some(3));
+    }
+
+    #[test]
+    fn parses_a_single_file_root_commit() {
+        let stdout = "[main (root-commit) 3f2a9c1] chore: init\n \
+                      1 file changed, 1 insertion(+)\n \
+                      create mode 100644 README.md\n";
+        assert_eq!(parse_files_changed(stdout), Some(1));
+    }
+
+    #[test]
+    fn files_changed_is_none_without_a_summary_line() {
+        assert_eq!(parse_files_changed(""), None);
+        assert_eq!(
+            parse_files_changed("[main 3f2a9c1] 2 files changed\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn short_sha_is_the_first_seven_characters() {
+        let result = |sha: &str| CommitResult {
+            sha: sha.into(),
+            summary: "feat: x".into(),
+            files_changed: None,
+        };
+        assert_eq!(
+            result("3f2a9c1e5b7d0a4c6e8f1b3d5a7c9e0f2b4d6a8c").short_sha(),
+            "3f2a9c1"
+        );
+        assert_eq!(result("abc").short_sha(), "abc");
+    }
+
+    #[test]
+    fn streamed_failure_points_at_printed_output() {
+        let err = streamed_failure(Some(1)).to_string();
+        assert!(err.contains("exit code 1"));
+        assert!(err.contains("see output above"));
+        assert!(
+            streamed_failure(None)
+                .to_string()
+                .contains("terminated by a signal")
+        );
+    }
+
+    #[test]
+    fn streaming_does_not_change_commit_args() {
+        let args = GitCommitExecutor::default()
+            .with_streaming(true)
+            .commit_args("feat: x");
+        assert_eq!(args, vec!["-m", "feat: x"]);
     }
 }
@@ -0,0 +1,223 @@
+//! Read-only queries against git history
+//!
+//! Not a port implementation — used by the composition root (cli.rs)
+//! to seed input sources before AppController runs.
+
+use std::process::Command;
+
+use super::error::GitError;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GitHistory;
+
+impl GitHistory {
+    /// Full message of the HEAD commit, as `git log -1 --format=%B` prints it.
+    pub fn last_message(&self) -> Result<String, GitError> {
+        let output = Command::new("git")
+            .args(["log", "-1", "--format=%B"])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Scopes from the last `RECENT_COMMITS` subjects, most used first.
+    pub fn recent_scopes(&self) -> Result<Vec<String>, GitError> {
+        let output = Command::new("git")
+            .args(["log", "--format=%s", "-n", &RECENT_COMMITS.to_string()])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(rank_scopes(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+impl GitHistory {
+    /// Full SHA and subject of `rev` (anything `git log` accepts: a short
+    /// SHA, a tag, HEAD~2).
+    pub fn commit_subject(&self, rev: &str) -> Result<(String, String), GitError> {
+        let output = Command::new("git")
+            .args(["log", "-1", "--format=%H%n%s", rev, "--"])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(split_sha_subject(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// The checked-out branch (`git rev-parse --abbrev-ref HEAD`); None
+    /// on a detached HEAD.
+    pub fn current_branch(&self) -> Result<Option<String>, GitError> {
+        let output = Command::new("git")
+            .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(branch_name(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Full messages of the commits in `range` (e.g. `v1.0..HEAD`), newest first.
+    pub fn messages(&self, range: &str) -> Result<Vec<String>, GitError> {
+        let output = Command::new("git")
+            .args(["log", "--format=%B%x00", range, "--"])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(split_messages(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+/// `git log --format=%B%x00` output → one trimmed message per commit.
+fn split_messages(log: &str) -> Vec<String> {
+    log.split('\0')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `git rev-parse --abbrev-ref HEAD` output → branch name. Detached,
+/// git prints "HEAD" itself.
+fn branch_name(output: &str) -> Option<String> {
+    let name = output.trim();
+    (!name.is_empty() && name != "HEAD").then(|| name.to_string())
+}
+
+/// `git log -1 --format=%H%n%s` output → (sha, subject).
+fn split_sha_subject(log: &str) -> (String, String) {
+    let (sha, subject) = log.trim().split_once('\n').unwrap_or((log.trim(), ""));
+    (sha.to_string(), subject.trim().to_string())
+}
+
+/// How far back recent_scopes() looks.
+const RECENT_COMMITS: usize = 200;
+
+/// Rank the scopes of `type(scope):` subjects by frequency.
+///
+/// `log` is newest first, so ties go to the scope seen most recently.
+/// Subjects without a conventional header are ignored.
+fn rank_scopes(log: &str) -> Vec<String> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for scope in log.lines().filter_map(subject_scope) {
+        match counts.iter_mut().find(|(s, _)| s == scope) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((scope.to_string(), 1)),
+        }
+    }
+    // Stable sort keeps first-seen order among equal counts.
+    counts.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+    counts.into_iter().map(|(scope, _)| scope).collect()
+}
+
+fn subject_scope(subject: &str) -> Option<&str> {
+    let (commit_type, rest) = subject.trim().split_once('(')?;
+    let (scope, after) = rest.split_once(')')?;
+    let is_word = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    };
+    let header_end = after.starts_with(':') || after.starts_with("!:");
+    (is_word(commit_type) && is_word(scope) && header_end).then_some(scope)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn git_history_can_be_created() {
+        let _history = GitHistory;
+    }
+
+    #[test]
+    fn last_message_returns_result() {
+        // Succeeds or fails depending on whether we're in a repo with commits
+        let _result = GitHistory.last_message();
+    }
+
+    #[test]
+    fn ranks_scopes_by_frequency() {
+        let log = "\
+fix(parser): handle tabs
+feat(api): add search
+docs: update readme
+feat(parser)!: drop v1 syntax
+chore(deps): bump clap
+refactor(api): split handlers
+fix(parser): off-by-one
+";
+        assert_eq!(rank_scopes(log), vec!["parser", "api", "deps"]);
+    }
+
+    #[test]
+    fn ties_keep_most_recent_first() {
+        let log = "feat(ui): a\nfix(cli): b\nfeat(ui): c\nfix(cli): d\n";
+        assert_eq!(rank_scopes(log), vec!["ui", "cli"]);
+    }
+
+    #[test]
+    fn non_conventional_subjects_are_ignored() {
+        let log = "\
+Merge branch 'main' (conflicts)
+Revert \"feat(api): add search\"
+update (docs): typo
+feat(my scope): spaces
+feat(): empty
+";
+        assert!(rank_scopes(log).is_empty());
+    }
+
+    #[test]
+    fn splits_nul_separated_messages() {
+        let log = "feat: a\n\nBody.\n\0\nfix: b\n\0\n";
+        assert_eq!(split_messages(log), vec!["feat: a\n\nBody.", "fix: b"]);
+    }
+
+    #[test]
+    fn splits_sha_and_subject() {
+        assert_eq!(
+            split_sha_subject("1a2b3c\nfeat(api): add search\n"),
+            ("1a2b3c".into(), "feat(api): add search".into())
+        );
+        assert_eq!(split_sha_subject("1a2b3c\n"), ("1a2b3c".into(), "".into()));
+    }
+
+    #[test]
+    fn detached_head_has_no_branch() {
+        assert_eq!(
+            branch_name("feat/api-oauth\n"),
+            Some("feat/api-oauth".into())
+        );
+        assert_eq!(branch_name("HEAD\n"), None);
+    }
+
+    #[test]
+    fn empty_log_has_no_scopes() {
+        assert!(rank_scopes("").is_empty());
+    }
+}
@@ -2,9 +2,15 @@
 //!
 //! This module contains Git-based implementations of the ports.
 
+mod config;
 mod error;
 mod executor;
+mod history;
+mod revert;
 mod staging;
 
+pub use config::GitConfig;
 pub use executor::GitCommitExecutor;
+pub use history::GitHistory;
+pub use revert::GitRevert;
 pub use staging::GitStagingChecker;
@@ -0,0 +1,28 @@
+//! Apply the inverse of a commit to the index
+//!
+//! Used by the composition root (cli.rs) for `--revert`: git computes the
+//! inverse patch, commando writes the message and commits as usual.
+
+use std::process::Command;
+
+use super::error::GitError;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GitRevert;
+
+impl GitRevert {
+    /// `git revert --no-commit <sha>`: stage the revert, leave committing
+    /// to the caller. Conflicts are reported as git prints them.
+    pub fn stage(&self, sha: &str) -> Result<(), GitError> {
+        let output = Command::new("git")
+            .args(["revert", "--no-commit", sha])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 use std::process::Command;
 
 use super::error::GitError;
-use crate::ports::StagingChecker;
+use crate::ports::{DiffStat, FileStat, StagedFile, StagingChecker};
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct GitStagingChecker;
@@ -11,13 +11,17 @@ pub struct GitStagingChecker;
 impl StagingChecker for GitStagingChecker {
     type Error = GitError;
 
-    fn has_staged_changes(&self) -> Result<bool, Self::Error> {
-        let is_repo_output = Command::new("git")
+    fn in_repository(&self) -> Result<bool, Self::Error> {
+        let output = Command::new("git")
             .args(["rev-parse", "--is-inside-work-tree"])
             .output()
             .map_err(|e| GitError::ExecutionFailed(format!("Failed to run git: {}", e)))?;
 
-        if !is_repo_output.status.success() {
+        Ok(output.status.success())
+    }
+
+    fn has_staged_changes(&self) -> Result<bool, Self::Error> {
+        if !self.in_repository()? {
             return Err(GitError::NotAGitRepository);
         }
 
@@ -33,6 +37,108 @@ impl StagingChecker for GitStagingChecker {
 
         Ok(!output.stdout.is_empty())
     }
+
+    fn staged_files(&self) -> Result<Vec<StagedFile>, Self::Error> {
+        let output = Command::new("git")
+            .args(["diff", "--cached", "--name-status"])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(parse_name_status(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    fn staged_diffstat(&self) -> Result<DiffStat, Self::Error> {
+        // A wide stat so long paths are not shortened to ".../name.rs";
+        // the graph keeps git's usual width.
+        let output = Command::new("git")
+            .args(["diff", "--cached", "--stat=1000", "--stat-graph-width=40"])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(parse_stat(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    fn is_staged(&self, pathspec: &str) -> Result<bool, Self::Error> {
+        let output = Command::new("git")
+            .args(["diff", "--cached", "--name-only", "--", pathspec])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::ExecutionFailed(stderr.trim().to_string()));
+        }
+
+        Ok(!output.stdout.is_empty())
+    }
+
+    fn has_unstaged_changes(&self, pathspec: &str) -> Result<bool, Self::Error> {
+        let output = Command::new("git")
+            .args(["diff", "--quiet", "--", pathspec])
+            .output()
+            .map_err(|e| GitError::ExecutionFailed(e.to_string()))?;
+
+        // --quiet: 1 means there are differences, anything else failed.
+        match output.status.code() {
+            Some(0) => Ok(false),
+            Some(1) => Ok(true),
+            _ => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(GitError::ExecutionFailed(stderr.trim().to_string()))
+            }
+        }
+    }
+}
+
+/// File lines are ` path | 12 ++++----`; the last line is the summary.
+/// The padding git adds is dropped, Display re-aligns.
+fn parse_stat(output: &str) -> DiffStat {
+    let mut stat = DiffStat::default();
+    for line in output.lines().filter(|l| !l.trim().is_empty()) {
+        match line.rsplit_once(" | ") {
+            Some((path, changes)) => stat.files.push(FileStat {
+                path: path.trim().to_string(),
+                changes: changes.trim().to_string(),
+            }),
+            None => stat.summary = line.trim().to_string(),
+        }
+    }
+    stat
+}
+
+/// Lines are `M\tpath`, or `R100\told\tnew` for renames and copies.
+/// Anything else is skipped rather than failing the commit over a summary.
+fn parse_name_status(output: &str) -> Vec<StagedFile> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let status = fields.next()?.chars().next()?;
+            let first = fields.next()?.to_string();
+            Some(match fields.next() {
+                Some(to) => StagedFile {
+                    status,
+                    path: to.to_string(),
+                    from: Some(first),
+                },
+                None => StagedFile {
+                    status,
+                    path: first,
+                    from: None,
+                },
+            })
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -41,17 +147,102 @@ mod tests {
 
     #[test]
     fn git_staging_checker_can_be_created() {
-        let _checker = GitStagingChecker::default();
+        let _checker = GitStagingChecker;
     }
 
     #[test]
     fn git_staging_checker_has_default() {
-        let _checker = GitStagingChecker::default();
+        let _checker: GitStagingChecker = Default::default();
+    }
+
+    #[test]
+    fn in_repository_returns_result() {
+        // Ok(true) inside a work tree, Ok(false) outside; Err only if git is missing
+        let _result = GitStagingChecker.in_repository();
+    }
+
+    #[test]
+    fn not_a_repository_error_has_guidance() {
+        assert_eq!(
+            GitError::NotAGitRepository.to_string(),
+            "Not a git repository — run `git init` or cd into one"
+        );
+    }
+
+    #[test]
+    fn parses_name_status_including_renames() {
+        let output = "M\tsrc/x.rs\nA\tsrc/y.rs\nD\told.txt\nR100\tsrc/a.rs\tsrc/b.rs\n";
+        let files = parse_name_status(output);
+        assert_eq!(
+            files,
+            vec![
+                StagedFile {
+                    status: 'M',
+                    path: "src/x.rs".into(),
+                    from: None
+                },
+                StagedFile {
+                    status: 'A',
+                    path: "src/y.rs".into(),
+                    from: None
+                },
+                StagedFile {
+                    status: 'D',
+                    path: "old.txt".into(),
+                    from: None
+                },
+                StagedFile {
+                    status: 'R',
+                    path: "src/b.rs".into(),
+                    from: Some("src/a.rs".into())
+                },
+            ]
+        );
+        assert_eq!(files[3].to_string(), "R src/a.rs → src/b.rs");
+    }
+
+    #[test]
+    fn name_status_skips_blank_and_malformed_lines() {
+        assert!(parse_name_status("").is_empty());
+        assert!(parse_name_status("\nM\n").is_empty());
+    }
+
+    #[test]
+    fn parses_stat_into_an_aligned_summary() {
+        let output = " src/app.rs                  |  12 ++++++------\n \
+                      src/adapters/git/staging.rs |   3 +++\n \
+                      logo.png                    | Bin 0 -> 1024 bytes\n \
+                      3 files changed, 9 insertions(+), 6 deletions(-)\n";
+        let stat = parse_stat(output);
+        assert_eq!(
+            stat.files[0],
+            FileStat {
+                path: "src/app.rs".into(),
+                changes: "12 ++++++------".into()
+            }
+        );
+        assert_eq!(stat.files[2].changes, "Bin 0 -> 1024 bytes");
+        assert_eq!(
+            stat.summary,
+            "3 files changed, 9 insertions(+), 6 deletions(-)"
+        );
+        assert_eq!(
+            stat.to_string(),
+            " src/app.rs                  | 12 ++++++------\n \
+             src/adapters/git/staging.rs | 3 +++\n \
+             logo.png                    | Bin 0 -> 1024 bytes\n \
+             3 files changed, 9 insertions(+), 6 deletions(-)"
+        );
+    }
+
+    #[test]
+    fn empty_stat_has_no_files() {
+        assert_eq!(parse_stat(""), DiffStat::default());
     }
 
     #[test]
     fn has_staged_changes_returns_result() {
-        let checker = GitStagingChecker::default();
+        let checker: GitStagingChecker = Default::default();
         // This will succeed or fail depending on whether we're in a git repo
         let _result = checker.has_staged_changes();
     }
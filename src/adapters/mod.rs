@@ -6,5 +6,8 @@ mod git;
 pub mod ui;
 
 pub use git::GitCommitExecutor;
+pub use git::GitConfig;
+pub use git::GitHistory;
+pub use git::GitRevert;
 pub use git::GitStagingChecker;
 pub use ui::TerminalUI;
@@ -2,13 +2,23 @@
 ///
 /// This is the production UI. RatatuiUI will be a second impl of the same
 /// trait. Swapping them requires changing one line in cli.rs.
-use std::io::{self, Write};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
 use crate::ports::ui::{Ui, UiError};
 
 pub struct TerminalUI;
 
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+
 impl Ui for TerminalUI {
+    /// One line in the terminal's canonical mode, so editing is the tty
+    /// driver's: Ctrl-U kills the line and Ctrl-W the previous word.
+    /// Cursor movement (Ctrl-A/Ctrl-E/Ctrl-K) needs raw-mode input, which
+    /// is left to RatatuiUI.
     fn prompt(&self, label: &str) -> Result<String, UiError> {
         print!("{}", label);
         io::stdout().flush().map_err(UiError::from)?;
@@ -26,11 +36,55 @@ impl Ui for TerminalUI {
     }
 
     fn confirm(&self, msg: &str) -> Result<bool, UiError> {
-        let input = self.prompt(&format!("{} (y/N): ", msg))?;
-        Ok(matches!(input.to_lowercase().as_str(), "y" | "yes"))
+        self.confirm_or(msg, false)
     }
 
     fn println(&self, msg: &str) {
         println!("{}", msg);
     }
+
+    /// Lines until EOF, so the body may hold blank lines between
+    /// paragraphs. Ctrl-D ends only this read: a terminal's stdin keeps
+    /// working for the prompts after it.
+    fn multiline_prompt(&self, label: &str) -> Result<String, UiError> {
+        println!("{} (Ctrl-D on an empty line to finish):", label);
+        println!();
+        let mut text = String::new();
+        for line in io::stdin().lock().lines() {
+            text.push_str(&line.map_err(UiError::from)?);
+            text.push('\n');
+        }
+        Ok(text.trim().to_string())
+    }
+
+    /// A spinner on stderr, redrawn on a helper thread while `f` runs on
+    /// this one. Skipped when stderr is not a terminal (pipes, CI logs).
+    fn with_progress<T>(&self, label: &str, f: impl FnOnce() -> T) -> T {
+        if !io::stderr().is_terminal() {
+            return f();
+        }
+        let (done, stop) = mpsc::channel::<()>();
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                let mut stderr = io::stderr();
+                for frame in SPINNER.iter().cycle() {
+                    let _ = write!(stderr, "\r{} {}", frame, label);
+                    let _ = stderr.flush();
+                    // Anything but a timeout means the sender is gone: f returned.
+                    if !matches!(
+                        stop.recv_timeout(SPINNER_INTERVAL),
+                        Err(mpsc::RecvTimeoutError::Timeout)
+                    ) {
+                        break;
+                    }
+                }
+                let _ = write!(stderr, "\r\x1b[2K");
+                let _ = stderr.flush();
+            });
+            let result = f();
+            // The scope joins the spinner before returning; stop it first.
+            drop(done);
+            result
+        })
+    }
 }
@@ -8,14 +8,18 @@
 ///   AppController → ports::{StagingChecker, CommitMessageSource, Ui, CommitExecutor}
 ///   AppController → domain::CommitMessage (returned by source.resolve())
 ///   AppController → nothing from adapters/, compiler/, or input/
+use std::cell::RefCell;
+use std::io::Write;
 use std::process::ExitCode;
 
+use crate::domain::CommitMessage;
 use crate::ports::{
-    executor::{CommitExecutor, DryRunner},
+    executor::{CommitExecutor, CommitResult, DryRunner},
     input::CommitMessageSource,
-    staging::StagingChecker,
+    staging::{StagedFile, StagingChecker},
     ui::Ui,
 };
+use crate::symbols::Symbols;
 
 pub struct AppController<S, M, U, E>
 where
@@ -28,6 +32,66 @@ where
     source: M,
     ui: U,
     executor: E,
+    amend: bool,
+    signoff: bool,
+    co_authors: Vec<String>,
+    wrap_width: Option<usize>,
+    preview: bool,
+    show_diff: bool,
+    confirm: bool,
+    confirm_default: bool,
+    decline_unconfirmed: bool,
+    spinner: bool,
+    quiet: bool,
+    symbols: Symbols,
+    paths: Vec<String>,
+    strict: bool,
+    output: Option<RefCell<Box<dyn Write>>>,
+}
+
+/// Body wrap column used unless the caller overrides it.
+pub const DEFAULT_WRAP_WIDTH: usize = 72;
+
+/// Exit statuses of run(), distinct so scripts and CI can tell failures apart.
+/// The message was rejected (compile or validation error, editor aborted).
+pub const EXIT_INVALID_MESSAGE: u8 = 1;
+/// Nothing is staged (and not amending).
+pub const EXIT_NO_STAGED_CHANGES: u8 = 2;
+/// git itself failed: not a repository, no identity, commit rejected.
+pub const EXIT_GIT_ERROR: u8 = 3;
+/// The user declined (or closed) the confirmation prompt.
+pub const EXIT_CANCELLED: u8 = 4;
+
+/// Staged files listed by name; the rest are only counted.
+const MAX_LISTED_FILES: usize = 5;
+
+/// "3 files staged: M src/x.rs, A src/y.rs, D old.txt"
+fn staged_summary(files: &[StagedFile]) -> String {
+    let mut listed: Vec<String> = files
+        .iter()
+        .take(MAX_LISTED_FILES)
+        .map(ToString::to_string)
+        .collect();
+    if files.len() > MAX_LISTED_FILES {
+        listed.push(format!("and {} more", files.len() - MAX_LISTED_FILES));
+    }
+    let noun = if files.len() == 1 { "file" } else { "files" };
+    format!("{} {} staged: {}", files.len(), noun, listed.join(", "))
+}
+
+/// "✓ Committed abc1234 (3 files)"; without the count when git's output
+/// was not captured.
+fn committed_line(result: &CommitResult, symbols: Symbols) -> String {
+    match result.files_changed {
+        Some(1) => format!("{} Committed {} (1 file)", symbols.ok, result.short_sha()),
+        Some(n) => format!(
+            "{} Committed {} ({} files)",
+            symbols.ok,
+            result.short_sha(),
+            n
+        ),
+        None => format!("{} Committed {}", symbols.ok, result.short_sha()),
+    }
 }
 
 impl<S, M, U, E> AppController<S, M, U, E>
@@ -47,25 +111,245 @@ where
             source,
             ui,
             executor,
+            amend: false,
+            signoff: false,
+            co_authors: Vec::new(),
+            wrap_width: Some(DEFAULT_WRAP_WIDTH),
+            preview: true,
+            show_diff: false,
+            confirm: true,
+            confirm_default: false,
+            decline_unconfirmed: false,
+            spinner: true,
+            quiet: false,
+            symbols: Symbols::default(),
+            paths: Vec::new(),
+            strict: false,
+            output: None,
+        }
+    }
+
+    /// Amending can rewrite just the message, so an empty index is not fatal.
+    pub fn with_amend(mut self, amend: bool) -> Self {
+        self.amend = amend;
+        self
+    }
+
+    /// Append a Signed-off-by trailer using the executor's identity.
+    pub fn with_signoff(mut self, signoff: bool) -> Self {
+        self.signoff = signoff;
+        self
+    }
+
+    /// Co-authored-by trailers to add, as "Name <email>" (already checked
+    /// with CommitMessage::parse_co_author). They precede the sign-off.
+    pub fn with_co_authors(mut self, co_authors: Vec<String>) -> Self {
+        self.co_authors = co_authors;
+        self
+    }
+
+    /// Column to wrap body lines at; None commits the body verbatim.
+    pub fn with_wrap_width(mut self, width: Option<usize>) -> Self {
+        self.wrap_width = width;
+        self
+    }
+
+    /// Show the rendered message before committing.
+    pub fn with_preview(mut self, preview: bool) -> Self {
+        self.preview = preview;
+        self
+    }
+
+    /// Show `git diff --stat` of the index above the preview, to check
+    /// the message against what is actually committed.
+    pub fn with_show_diff(mut self, show_diff: bool) -> Self {
+        self.show_diff = show_diff;
+        self
+    }
+
+    /// Ask before committing (and offer edit/retry/dry-run after a failure).
+    /// Off for scripted use, where nobody is there to answer.
+    pub fn with_confirmation(mut self, confirm: bool) -> Self {
+        self.confirm = confirm;
+        self
+    }
+
+    /// What pressing Enter at "Proceed with commit?" means (default: no).
+    pub fn with_confirm_default(mut self, default: bool) -> Self {
+        self.confirm_default = default;
+        self
+    }
+
+    /// With confirmation off, stop after the preview instead of committing:
+    /// what an unattended run (CI) answers when told never to commit.
+    pub fn with_decline_unconfirmed(mut self, decline: bool) -> Self {
+        self.decline_unconfirmed = decline;
+        self
+    }
+
+    /// Show progress while git runs. Off when git's own output is streamed
+    /// to the terminal, or gpg may prompt there, which a spinner would draw
+    /// over.
+    pub fn with_spinner(mut self, spinner: bool) -> Self {
+        self.spinner = spinner;
+        self
+    }
+
+    /// Only errors and the new commit's SHA are printed; the progress
+    /// lines and the spinner are dropped. Prompts are unaffected.
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Commit only these paths. Each must have something staged and no
+    /// unstaged changes (git would commit those too), else the run stops
+    /// before asking for a message. The executor is given the same paths.
+    pub fn with_paths(mut self, paths: Vec<String>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Refuse to commit a message with warnings (see CommitMessage::warnings)
+    /// instead of showing them under the preview.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Write the formatted message here instead of committing (--output).
+    /// Staging is not checked and nothing is previewed or confirmed:
+    /// commando is only the formatter and validator.
+    pub fn with_output(mut self, output: Option<Box<dyn Write>>) -> Self {
+        self.output = output.map(RefCell::new);
+        self
+    }
+
+    /// The marks in front of status lines: Unicode, or ASCII for --ascii.
+    pub fn with_symbols(mut self, symbols: Symbols) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// A progress line — suppressed by --quiet, unlike errors.
+    fn status(&self, msg: &str) {
+        if !self.quiet {
+            self.ui.println(msg);
+        }
+    }
+
+    /// With `-- <paths>`: every path must have something staged, and
+    /// nothing unstaged. `git commit -- <paths>` takes the working tree, so
+    /// after `git add -p` it would commit the hunks left out as well.
+    fn check_paths(&self) -> Result<(), ExitCode> {
+        if self.paths.is_empty() {
+            return Ok(());
+        }
+        let mut unstaged = Vec::new();
+        let mut partly_staged = Vec::new();
+        for path in &self.paths {
+            let checked = self.staging.is_staged(path).and_then(|staged| {
+                Ok((staged, staged && self.staging.has_unstaged_changes(path)?))
+            });
+            match checked {
+                Ok((true, false)) => {}
+                Ok((true, true)) => partly_staged.push(path.as_str()),
+                Ok((false, _)) => unstaged.push(path.as_str()),
+                Err(e) => {
+                    self.ui.println(&format!("Error checking staging: {}", e));
+                    return Err(ExitCode::from(EXIT_GIT_ERROR));
+                }
+            }
+        }
+        if !unstaged.is_empty() {
+            self.ui.println(&format!(
+                "{} Nothing staged in: {}\n",
+                self.symbols.error,
+                unstaged.join(", ")
+            ));
+            self.ui.println("Stage them first, or leave them out:");
+            self.ui
+                .println(&format!("  git add {}\n", unstaged.join(" ")));
+            return Err(ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        }
+        if !partly_staged.is_empty() {
+            self.ui.println(&format!(
+                "{} Unstaged changes in: {} {} git would commit them too.\n",
+                self.symbols.error,
+                partly_staged.join(", "),
+                self.symbols.dash
+            ));
+            self.ui
+                .println("Stage them as well, or set them aside and rerun:");
+            self.ui.println("  git stash push --keep-index\n");
+            return Err(ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        }
+        self.status(&format!("Committing only: {}\n", self.paths.join(", ")));
+        Ok(())
+    }
+
+    fn render(&self, message: &CommitMessage) -> String {
+        match self.wrap_width {
+            Some(width) => message.to_wrapped(width),
+            None => message.to_conventional_commit(),
         }
     }
 
     pub fn run(&self) -> ExitCode {
+        if let Some(output) = &self.output {
+            return self.write_to(output);
+        }
+
+        // ── Step 0: inside a repository? ──────────────────────────────
+        // Before any prompt or editor: there is nothing to commit into.
+        match self.staging.in_repository() {
+            Ok(true) => {}
+            Ok(false) => {
+                self.ui.println(&format!(
+                    "{} Not a git repository {} run `git init` or cd into one.",
+                    self.symbols.error, self.symbols.dash
+                ));
+                return ExitCode::from(EXIT_GIT_ERROR);
+            }
+            Err(e) => {
+                self.ui
+                    .println(&format!("Error checking repository: {}", e));
+                return ExitCode::from(EXIT_GIT_ERROR);
+            }
+        }
+
         // ── Step 1: staged changes ────────────────────────────────────
-        self.ui.println("Checking for staged changes...");
+        self.status("Checking for staged changes...");
         match self.staging.has_staged_changes() {
-            Ok(true) => self.ui.println("✓ Staged changes detected\n"),
+            // The list is a convenience; failing to get it is not an error.
+            Ok(true) if self.quiet => {}
+            Ok(true) => match self.staging.staged_files() {
+                Ok(files) if !files.is_empty() => {
+                    self.status(&format!("{} {}\n", self.symbols.ok, staged_summary(&files)))
+                }
+                _ => self.status(&format!("{} Staged changes detected\n", self.symbols.ok)),
+            },
+            Ok(false) if self.amend => self.status(&format!(
+                "No staged changes {} amending the message only\n",
+                self.symbols.dash
+            )),
             Ok(false) => {
-                self.ui.println("✗ No staged changes found.\n");
+                self.ui.println(&format!(
+                    "{} No staged changes found.\n",
+                    self.symbols.error
+                ));
                 self.ui.println("Stage your changes first:");
                 self.ui.println("  git add <files>\n");
-                return ExitCode::FAILURE;
+                return ExitCode::from(EXIT_NO_STAGED_CHANGES);
             }
             Err(e) => {
                 self.ui.println(&format!("Error checking staging: {}", e));
-                return ExitCode::FAILURE;
+                return ExitCode::from(EXIT_GIT_ERROR);
             }
         }
+        if let Err(code) = self.check_paths() {
+            return code;
+        }
 
         // ── Step 2: resolve input → CommitMessage ─────────────────────
         // One call. Editor, direct, or interactive — AppController doesn't know.
@@ -73,55 +357,389 @@ where
             Ok(m) => m,
             Err(e) => {
                 self.ui.println(&format!("Error: {}", e));
-                return ExitCode::FAILURE;
+                return ExitCode::from(EXIT_INVALID_MESSAGE);
             }
         };
 
-        // ── Step 3: preview + confirm ─────────────────────────────────
-        self.ui.show_preview(&message.to_conventional_commit());
+        let mut message = match self.signed(message) {
+            Ok(m) => m,
+            Err(code) => return code,
+        };
 
-        match self.ui.confirm("Proceed with commit?") {
-            Ok(true) => {}
-            Ok(false) => {
-                self.ui.println("\nCommit aborted.");
-                return ExitCode::FAILURE;
+        // ── Step 3: preview + confirm ─────────────────────────────────
+        if self.show_diff {
+            // Like the staged file list, a missing stat never blocks the commit.
+            match self.staging.staged_diffstat() {
+                Ok(stat) if !stat.files.is_empty() => self.ui.println(&format!("\n{}", stat)),
+                Ok(_) => {}
+                Err(e) => self.ui.println(&format!("Could not show the diff: {}", e)),
             }
-            Err(e) => {
-                self.ui.println(&format!("Error: {}", e));
-                return ExitCode::FAILURE;
+        }
+
+        let mut rendered = self.render(&message);
+        if let Err(code) = self.preview_and_check(&message, &rendered) {
+            return code;
+        }
+
+        if self.confirm {
+            match self
+                .ui
+                .confirm_or("Proceed with commit?", self.confirm_default)
+            {
+                Ok(true) => {}
+                Ok(false) => {
+                    self.ui.println("\nCommit aborted.");
+                    return ExitCode::from(EXIT_CANCELLED);
+                }
+                Err(e) => {
+                    self.ui.println(&format!("Error: {}", e));
+                    return ExitCode::from(EXIT_CANCELLED);
+                }
             }
+        } else if self.decline_unconfirmed {
+            self.ui
+                .println("\nNot committing: nobody is there to confirm.");
+            return ExitCode::from(EXIT_CANCELLED);
         }
 
         // ── Step 4: execute ───────────────────────────────────────────
-        self.ui.println("\nExecuting git commit...");
-        match self.executor.execute(&message.to_conventional_commit()) {
-            Ok(result) => {
-                self.ui.println(&format!("✓ Committed: {}", result.summary));
-                self.ui.println(&format!("  SHA: {}", result.sha));
-                ExitCode::SUCCESS
+        // A failure (typically a hook) keeps the message: the user can
+        // edit it, retry as-is, or diagnose with a dry-run.
+        loop {
+            // The editor or a retry may have taken a while; something else
+            // (another shell, a hook on the last attempt) can empty the index.
+            if self.index_emptied() {
+                return self.nothing_left_staged("Nothing is staged any more");
             }
-            Err(e) => {
-                self.ui.println(&format!("✗ Commit failed: {}", e));
-                if let Ok(true) = self.ui.confirm("Try a dry-run to diagnose?") {
-                    match self.executor.dry_run(&message.to_conventional_commit()) {
+            self.status("\nExecuting git commit...");
+            // None if HEAD cannot be read: then a failure is taken at its word.
+            let before = self.executor.head().ok();
+            let outcome = if self.spinner && !self.quiet {
+                self.ui
+                    .with_progress("Running git commit", || self.executor.execute(&rendered))
+            } else {
+                self.executor.execute(&rendered)
+            };
+            let e = match outcome {
+                // Quiet: the bare SHA, for `sha=$(commando -q ...)`.
+                Ok(result) if self.quiet => {
+                    self.ui.println(&result.sha);
+                    return ExitCode::SUCCESS;
+                }
+                Ok(result) => {
+                    self.ui.println(&committed_line(&result, self.symbols));
+                    self.ui.println(&format!("  {}", result.summary));
+                    return ExitCode::SUCCESS;
+                }
+                Err(e) => e,
+            };
+            if let Some(before) = &before
+                && let Ok(after) = self.executor.head()
+                && let Some(sha) = head_moved(before.as_deref(), after.as_deref())
+            {
+                return self.committed_despite(&e, sha, before.as_deref());
+            }
+            // git's own "nothing to commit" does not say a hook did it.
+            if self.index_emptied() {
+                self.ui
+                    .println(&format!("{} Commit failed: {}", self.symbols.error, e));
+                return self.nothing_left_staged("A hook unstaged every change");
+            }
+            self.ui
+                .println(&format!("{} Commit failed: {}", self.symbols.error, e));
+            if !self.confirm {
+                return ExitCode::from(EXIT_GIT_ERROR);
+            }
+
+            loop {
+                match self.recovery() {
+                    Recovery::Edit => match self.source.revise(&message) {
+                        Ok(revised) => {
+                            message = match self.signed(revised) {
+                                Ok(m) => m,
+                                Err(code) => return code,
+                            };
+                            rendered = self.render(&message);
+                            // The edit may have added a warning.
+                            if let Err(code) = self.preview_and_check(&message, &rendered) {
+                                return code;
+                            }
+                            break;
+                        }
+                        Err(e) => self.ui.println(&format!("Error: {}", e)),
+                    },
+                    Recovery::Retry => break,
+                    Recovery::DryRun => match self.executor.dry_run(&rendered) {
                         Ok(_) => self.ui.println("Dry-run succeeded. Check your git config."),
                         Err(e) => self.ui.println(&format!("Dry-run also failed: {}", e)),
-                    }
+                    },
+                    Recovery::Abort => return ExitCode::from(EXIT_GIT_ERROR),
+                }
+            }
+        }
+    }
+
+    /// Preview the message with its warnings; under --strict a warning
+    /// stops the run instead.
+    fn preview_and_check(&self, message: &CommitMessage, rendered: &str) -> Result<(), ExitCode> {
+        let warnings = message.warnings();
+        if self.preview {
+            self.ui.show_preview(rendered);
+            if !self.strict {
+                for warning in &warnings {
+                    self.ui
+                        .println(&format!("{} {}.", self.symbols.warning, warning));
                 }
+            }
+        }
+        self.strict_check(&warnings, "Not committing")
+    }
+
+    /// --strict: any warning is printed as an error and fails the run,
+    /// e.g. "Not committing: --strict treats warnings as errors."
+    fn strict_check(&self, warnings: &[String], refusal: &str) -> Result<(), ExitCode> {
+        if !self.strict || warnings.is_empty() {
+            return Ok(());
+        }
+        for warning in warnings {
+            self.ui
+                .println(&format!("{} {}.", self.symbols.error, warning));
+        }
+        self.ui.println(&format!(
+            "\n{}: --strict treats warnings as errors.",
+            refusal
+        ));
+        Err(ExitCode::from(EXIT_INVALID_MESSAGE))
+    }
+
+    /// Staged at step 1, nothing staged now. Never while amending, which
+    /// may legitimately have nothing staged; a failed check is no answer.
+    fn index_emptied(&self) -> bool {
+        !self.amend && matches!(self.staging.has_staged_changes(), Ok(false))
+    }
+
+    fn nothing_left_staged(&self, reason: &str) -> ExitCode {
+        self.ui.println(&format!(
+            "{} {} {} not committing.\n",
+            self.symbols.error, reason, self.symbols.dash
+        ));
+        self.ui
+            .println("Check what changed, stage it again and rerun:");
+        self.ui.println("  git status\n");
+        ExitCode::from(EXIT_NO_STAGED_CHANGES)
+    }
+
+    /// --output: resolve and format the message as for a commit, then
+    /// write it out. Warnings only fail the run with --strict.
+    fn write_to(&self, output: &RefCell<Box<dyn Write>>) -> ExitCode {
+        let message = match self.source.resolve() {
+            Ok(m) => m,
+            Err(e) => {
+                self.ui.println(&format!("Error: {}", e));
+                return ExitCode::from(EXIT_INVALID_MESSAGE);
+            }
+        };
+        let message = match self.signed(message) {
+            Ok(m) => m,
+            Err(code) => return code,
+        };
+
+        if let Err(code) = self.strict_check(&message.warnings(), "Not writing") {
+            return code;
+        }
+
+        let mut output = output.borrow_mut();
+        match writeln!(output, "{}", self.render(&message)).and_then(|_| output.flush()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                self.ui
+                    .println(&format!("Error writing the message: {}", e));
                 ExitCode::FAILURE
             }
         }
     }
+
+    /// git made the commit `sha` but still failed, e.g. in a hook run after
+    /// it. Report that rather than a plain failure, and offer to undo it
+    /// back to `before`. Exits with EXIT_GIT_ERROR either way.
+    fn committed_despite(
+        &self,
+        e: &impl std::fmt::Display,
+        sha: &str,
+        before: Option<&str>,
+    ) -> ExitCode {
+        self.ui.println(&format!(
+            "{} Commit created, but a hook failed: {}",
+            self.symbols.warning, e
+        ));
+        self.ui.println(&format!("  SHA: {}", sha));
+        // The repository's first commit: there is nothing to reset to.
+        let Some(before) = before else {
+            return ExitCode::from(EXIT_GIT_ERROR);
+        };
+        if !self.confirm {
+            self.ui.println(&format!(
+                "  To undo it and keep the changes staged: git reset --soft {}",
+                before
+            ));
+            return ExitCode::from(EXIT_GIT_ERROR);
+        }
+        if let Ok(true) = self
+            .ui
+            .confirm_or("Undo the commit, keeping the changes staged?", false)
+        {
+            match self.executor.reset_soft(before) {
+                Ok(()) => self.ui.println(&format!(
+                    "{} Commit undone; the changes are still staged.",
+                    self.symbols.ok
+                )),
+                Err(e) => self.ui.println(&format!(
+                    "{} Could not undo the commit: {}",
+                    self.symbols.error, e
+                )),
+            }
+        }
+        ExitCode::from(EXIT_GIT_ERROR)
+    }
+
+    /// Co-authors and sign-off are added before the preview so the user
+    /// sees the trailers. No identity means no commit — never silently
+    /// drop the sign-off.
+    fn signed(&self, message: CommitMessage) -> Result<CommitMessage, ExitCode> {
+        let message = self
+            .co_authors
+            .iter()
+            .fold(message, |m, identity| m.with_co_author(identity.clone()));
+        if !self.signoff {
+            return Ok(message);
+        }
+        match self.executor.identity() {
+            Ok(identity) => Ok(message.with_signoff(identity)),
+            Err(e) => {
+                self.ui.println(&format!("Error: {}", e));
+                Err(ExitCode::from(EXIT_GIT_ERROR))
+            }
+        }
+    }
+
+    /// Ask what to do after a failed commit. Enter, EOF or anything
+    /// unrecognised aborts; edit is only offered if the source can revise.
+    fn recovery(&self) -> Recovery {
+        let mut options = vec![];
+        if self.source.can_revise() {
+            options.push((Recovery::Edit, "edit", "Edit the message, then retry"));
+        }
+        options.push((Recovery::Retry, "retry", "Retry with the same message"));
+        options.push((Recovery::DryRun, "dry-run", "Dry-run to diagnose"));
+        options.push((Recovery::Abort, "abort", "Give up"));
+
+        let menu: Vec<(&str, &str)> = options.iter().map(|&(_, v, d)| (v, d)).collect();
+        match self.ui.select("What now?", &menu, Some(menu.len() - 1)) {
+            Ok(Some(i)) => return options.get(i).map_or(Recovery::Abort, |o| o.0),
+            Ok(None) => {}
+            Err(_) => return Recovery::Abort,
+        }
+
+        let label: Vec<String> = options
+            .iter()
+            .map(|(_, v, _)| format!("({}){}", &v[..1], &v[1..]))
+            .collect();
+        let input = match self.ui.prompt(&format!("{}: ", label.join(" / "))) {
+            Ok(input) => input.to_lowercase(),
+            Err(_) => return Recovery::Abort,
+        };
+        options
+            .iter()
+            .find(|(_, v, _)| !input.is_empty() && v.starts_with(input.as_str()))
+            .map_or(Recovery::Abort, |o| o.0)
+    }
+}
+
+/// The new HEAD if a commit attempt moved it. An attempt that failed
+/// outright leaves HEAD where it was.
+fn head_moved<'a>(before: Option<&str>, after: Option<&'a str>) -> Option<&'a str> {
+    after.filter(|&after| Some(after) != before)
+}
+
+/// Assembles an AppController one port at a time.
+///
+/// Each setter swaps in a new adapter type, so only the ports that differ
+/// from the defaults need naming. `AppControllerBuilder::default()` (see
+/// cli.rs, the only place that names adapters) starts from git, the
+/// terminal and the editor.
+pub struct AppControllerBuilder<S, M, U, E> {
+    staging: S,
+    source: M,
+    ui: U,
+    executor: E,
+}
+
+impl<S, M, U, E> AppControllerBuilder<S, M, U, E> {
+    pub fn new(staging: S, source: M, ui: U, executor: E) -> Self {
+        Self {
+            staging,
+            source,
+            ui,
+            executor,
+        }
+    }
+
+    pub fn staging<S2: StagingChecker>(self, staging: S2) -> AppControllerBuilder<S2, M, U, E> {
+        AppControllerBuilder::new(staging, self.source, self.ui, self.executor)
+    }
+
+    /// Where the message comes from.
+    pub fn input<M2: CommitMessageSource>(self, source: M2) -> AppControllerBuilder<S, M2, U, E> {
+        AppControllerBuilder::new(self.staging, source, self.ui, self.executor)
+    }
+
+    pub fn ui<U2: Ui>(self, ui: U2) -> AppControllerBuilder<S, M, U2, E> {
+        AppControllerBuilder::new(self.staging, self.source, ui, self.executor)
+    }
+
+    pub fn executor<E2: CommitExecutor + DryRunner>(
+        self,
+        executor: E2,
+    ) -> AppControllerBuilder<S, M, U, E2> {
+        AppControllerBuilder::new(self.staging, self.source, self.ui, executor)
+    }
+}
+
+impl<S, M, U, E> AppControllerBuilder<S, M, U, E>
+where
+    S: StagingChecker,
+    S::Error: std::fmt::Display,
+    M: CommitMessageSource,
+    M::Error: std::fmt::Display,
+    U: Ui,
+    E: CommitExecutor + DryRunner,
+    <E as CommitExecutor>::Error: std::fmt::Display,
+    <E as DryRunner>::Error: std::fmt::Display,
+{
+    /// The controller with default settings; chain its `with_*` methods
+    /// for the rest.
+    pub fn build(self) -> AppController<S, M, U, E> {
+        AppController::new(self.staging, self.source, self.ui, self.executor)
+    }
+}
+
+/// The choices offered after `git commit` fails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Recovery {
+    Edit,
+    Retry,
+    DryRun,
+    Abort,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{CommitMessage, CommitType};
+    use crate::domain::{CommitMessage, CommitType, ValidationConfig};
     use crate::ports::{
         executor::{CommitExecutor, CommitResult, DryRunner},
         input::CommitMessageSource,
-        staging::StagingChecker,
+        staging::{DiffStat, FileStat, StagedFile, StagingChecker},
         ui::{Ui, UiError},
     };
     use std::cell::RefCell;
@@ -131,9 +749,124 @@ mod tests {
     struct MockStaging(bool);
     impl StagingChecker for MockStaging {
         type Error = String;
+        fn in_repository(&self) -> Result<bool, String> {
+            Ok(true)
+        }
         fn has_staged_changes(&self) -> Result<bool, String> {
             Ok(self.0)
         }
+        fn staged_files(&self) -> Result<Vec<StagedFile>, String> {
+            Ok(if self.0 {
+                vec![staged('M', "src/x.rs"), staged('A', "src/y.rs")]
+            } else {
+                vec![]
+            })
+        }
+        fn staged_diffstat(&self) -> Result<DiffStat, String> {
+            Ok(DiffStat {
+                files: vec![FileStat {
+                    path: "src/x.rs".into(),
+                    changes: "2 +-".into(),
+                }],
+                summary: "1 file changed, 1 insertion(+), 1 deletion(-)".into(),
+            })
+        }
+        fn is_staged(&self, pathspec: &str) -> Result<bool, String> {
+            Ok(self
+                .staged_files()?
+                .iter()
+                .any(|f| f.path == pathspec || f.path.starts_with(&format!("{}/", pathspec))))
+        }
+        fn has_unstaged_changes(&self, _: &str) -> Result<bool, String> {
+            Ok(false)
+        }
+    }
+
+    /// Everything staged, but `src/y.rs` also changed again afterwards
+    /// (e.g. only some hunks went in with `git add -p`).
+    struct PartlyStaged;
+    impl StagingChecker for PartlyStaged {
+        type Error = String;
+        fn in_repository(&self) -> Result<bool, String> {
+            Ok(true)
+        }
+        fn has_staged_changes(&self) -> Result<bool, String> {
+            MockStaging(true).has_staged_changes()
+        }
+        fn staged_files(&self) -> Result<Vec<StagedFile>, String> {
+            MockStaging(true).staged_files()
+        }
+        fn staged_diffstat(&self) -> Result<DiffStat, String> {
+            MockStaging(true).staged_diffstat()
+        }
+        fn is_staged(&self, pathspec: &str) -> Result<bool, String> {
+            MockStaging(true).is_staged(pathspec)
+        }
+        fn has_unstaged_changes(&self, pathspec: &str) -> Result<bool, String> {
+            Ok(pathspec == "src/y.rs")
+        }
+    }
+
+    fn staged(status: char, path: &str) -> StagedFile {
+        StagedFile {
+            status,
+            path: path.into(),
+            from: None,
+        }
+    }
+
+    /// Staged for the first `n` checks, then empty: another shell or a
+    /// hook unstaged everything after step 1.
+    struct EmptiedIndex(RefCell<usize>);
+    impl StagingChecker for EmptiedIndex {
+        type Error = String;
+        fn in_repository(&self) -> Result<bool, String> {
+            Ok(true)
+        }
+        fn has_staged_changes(&self) -> Result<bool, String> {
+            let mut left = self.0.borrow_mut();
+            if *left == 0 {
+                return Ok(false);
+            }
+            *left -= 1;
+            Ok(true)
+        }
+        fn staged_files(&self) -> Result<Vec<StagedFile>, String> {
+            MockStaging(true).staged_files()
+        }
+        fn staged_diffstat(&self) -> Result<DiffStat, String> {
+            MockStaging(true).staged_diffstat()
+        }
+        fn is_staged(&self, pathspec: &str) -> Result<bool, String> {
+            MockStaging(true).is_staged(pathspec)
+        }
+        fn has_unstaged_changes(&self, pathspec: &str) -> Result<bool, String> {
+            MockStaging(true).has_unstaged_changes(pathspec)
+        }
+    }
+
+    /// Stands in for running git outside a work tree.
+    struct OutsideRepository;
+    impl StagingChecker for OutsideRepository {
+        type Error = String;
+        fn in_repository(&self) -> Result<bool, String> {
+            Ok(false)
+        }
+        fn has_staged_changes(&self) -> Result<bool, String> {
+            Err("fatal: not a git repository".into())
+        }
+        fn staged_files(&self) -> Result<Vec<StagedFile>, String> {
+            Err("fatal: not a git repository".into())
+        }
+        fn staged_diffstat(&self) -> Result<DiffStat, String> {
+            Err("fatal: not a git repository".into())
+        }
+        fn is_staged(&self, _: &str) -> Result<bool, String> {
+            Err("fatal: not a git repository".into())
+        }
+        fn has_unstaged_changes(&self, _: &str) -> Result<bool, String> {
+            Err("fatal: not a git repository".into())
+        }
     }
 
     struct MockSource(Result<CommitMessage, String>);
@@ -144,50 +877,180 @@ mod tests {
         }
     }
 
+    /// Stands in for re-opening the editor: appends a Refs footer.
+    struct RevisingSource;
+    impl CommitMessageSource for RevisingSource {
+        type Error = String;
+        fn resolve(&self) -> Result<CommitMessage, String> {
+            ok_source().0
+        }
+        fn can_revise(&self) -> bool {
+            true
+        }
+        fn revise(&self, current: &CommitMessage) -> Result<CommitMessage, String> {
+            assert_eq!(current.to_conventional_commit(), "feat: add feature");
+            CommitMessage::new(
+                CommitType::Feat,
+                None,
+                "add feature".into(),
+                None,
+                None,
+                vec![("Refs".into(), "#42".into())],
+                &ValidationConfig::default(),
+            )
+            .map_err(|e| e.to_string())
+        }
+    }
+
+    /// Fails the test if the controller asks for a message.
+    struct UnreachableSource;
+    impl CommitMessageSource for UnreachableSource {
+        type Error = String;
+        fn resolve(&self) -> Result<CommitMessage, String> {
+            panic!("message requested outside a repository")
+        }
+    }
+
     struct MockUi {
-        confirmed: bool,
+        /// None answers every question by pressing Enter.
+        confirmed: Option<bool>,
+        /// Answers to prompt(), in order; Enter once they run out.
+        answers: RefCell<Vec<&'static str>>,
         output: RefCell<Vec<String>>,
     }
     impl MockUi {
         fn new(confirmed: bool) -> Self {
             Self {
-                confirmed,
+                confirmed: Some(confirmed),
+                answers: RefCell::new(vec![]),
                 output: RefCell::new(vec![]),
             }
         }
+
+        fn pressing_enter() -> Self {
+            Self {
+                confirmed: None,
+                ..Self::new(false)
+            }
+        }
+
+        fn answering(mut answers: Vec<&'static str>) -> Self {
+            answers.reverse();
+            Self {
+                answers: RefCell::new(answers),
+                ..Self::new(true)
+            }
+        }
     }
     impl Ui for MockUi {
-        fn prompt(&self, _: &str) -> Result<String, UiError> {
-            Ok(String::new())
+        fn prompt(&self, label: &str) -> Result<String, UiError> {
+            self.output.borrow_mut().push(format!("prompt: {}", label));
+            Ok(self.answers.borrow_mut().pop().unwrap_or("").to_string())
+        }
+        fn show_preview(&self, content: &str) {
+            self.output
+                .borrow_mut()
+                .push(format!("preview: {}", content));
         }
-        fn show_preview(&self, _: &str) {}
-        fn confirm(&self, _: &str) -> Result<bool, UiError> {
-            Ok(self.confirmed)
+        fn confirm(&self, msg: &str) -> Result<bool, UiError> {
+            self.confirm_or(msg, false)
+        }
+        fn confirm_or(&self, _: &str, default: bool) -> Result<bool, UiError> {
+            Ok(self.confirmed.unwrap_or(default))
         }
         fn println(&self, msg: &str) {
             self.output.borrow_mut().push(msg.to_string());
         }
+        fn with_progress<T>(&self, label: &str, f: impl FnOnce() -> T) -> T {
+            self.output
+                .borrow_mut()
+                .push(format!("progress: {}", label));
+            f()
+        }
     }
 
     struct MockExecutor {
         succeeds: bool,
+        /// Attempts that fail before `succeeds` applies, like a hook
+        /// that rejects the first message.
+        failures: RefCell<usize>,
+        identity: Option<&'static str>,
+        committed: RefCell<Option<String>>,
+        dry_runs: RefCell<usize>,
+        /// The commit is made, then a hook fails: HEAD moves anyway.
+        commits_then_fails: bool,
+        head: RefCell<Option<String>>,
+        reset_to: RefCell<Option<String>>,
+    }
+    impl MockExecutor {
+        fn new(succeeds: bool) -> Self {
+            Self {
+                succeeds,
+                failures: RefCell::new(0),
+                identity: Some("Jane Doe <jane@example.com>"),
+                committed: RefCell::new(None),
+                dry_runs: RefCell::new(0),
+                commits_then_fails: false,
+                head: RefCell::new(Some("0ld5ha".into())),
+                reset_to: RefCell::new(None),
+            }
+        }
+
+        fn committing_then_failing() -> Self {
+            Self {
+                commits_then_fails: true,
+                ..Self::new(false)
+            }
+        }
+
+        fn failing_once() -> Self {
+            Self {
+                failures: RefCell::new(1),
+                ..Self::new(true)
+            }
+        }
     }
     impl CommitExecutor for MockExecutor {
         type Error = String;
         fn execute(&self, msg: &str) -> Result<CommitResult, String> {
+            *self.committed.borrow_mut() = Some(msg.to_string());
+            let mut failures = self.failures.borrow_mut();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err("hook rejected the message".into());
+            }
+            if self.commits_then_fails {
+                *self.head.borrow_mut() = Some("abc1234f00d".into());
+                return Err("post-commit hook exited with 1".into());
+            }
             if self.succeeds {
                 Ok(CommitResult {
-                    sha: "abc123".into(),
+                    sha: "abc1234f00d".into(),
                     summary: msg.lines().next().unwrap_or("").into(),
+                    files_changed: Some(2),
                 })
             } else {
                 Err("git process failed".into())
             }
         }
+        fn identity(&self) -> Result<String, String> {
+            self.identity
+                .map(str::to_string)
+                .ok_or_else(|| "user.name is not set".to_string())
+        }
+        fn head(&self) -> Result<Option<String>, String> {
+            Ok(self.head.borrow().clone())
+        }
+        fn reset_soft(&self, sha: &str) -> Result<(), String> {
+            *self.reset_to.borrow_mut() = Some(sha.to_string());
+            *self.head.borrow_mut() = Some(sha.to_string());
+            Ok(())
+        }
     }
     impl DryRunner for MockExecutor {
         type Error = String;
         fn dry_run(&self, _: &str) -> Result<(), String> {
+            *self.dry_runs.borrow_mut() += 1;
             Ok(())
         }
     }
@@ -200,6 +1063,7 @@ mod tests {
             None,
             None,
             vec![],
+            &ValidationConfig::default(),
         )
         .unwrap()))
     }
@@ -213,9 +1077,7 @@ mod tests {
             MockStaging(staged),
             ok_source(),
             MockUi::new(confirmed),
-            MockExecutor {
-                succeeds: executor_ok,
-            },
+            MockExecutor::new(executor_ok),
         )
     }
 
@@ -226,19 +1088,660 @@ mod tests {
         assert_eq!(make_app(true, true, true).run(), ExitCode::SUCCESS);
     }
 
+    #[test]
+    fn fails_early_outside_a_repository() {
+        let app = AppController::new(
+            OutsideRepository,
+            UnreachableSource,
+            MockUi::new(true),
+            MockExecutor::new(true),
+        );
+        assert_eq!(app.run(), ExitCode::from(EXIT_GIT_ERROR));
+        assert_eq!(
+            app.ui.output.borrow().as_slice(),
+            ["✗ Not a git repository — run `git init` or cd into one."]
+        );
+        assert!(app.executor.committed.borrow().is_none());
+    }
+
+    #[test]
+    fn lists_staged_files_before_prompting() {
+        let app = make_app(true, true, true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(
+            app.ui
+                .output
+                .borrow()
+                .contains(&"✓ 2 files staged: M src/x.rs, A src/y.rs\n".to_string())
+        );
+    }
+
+    #[test]
+    fn ascii_symbols_keep_every_line_ascii() {
+        let ascii = |ui: &MockUi| {
+            let output = ui.output.borrow();
+            assert!(output.iter().all(|l| l.is_ascii()), "{:?}", output);
+        };
+
+        let app = make_app(true, true, true).with_symbols(Symbols::ASCII);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(
+            app.ui
+                .output
+                .borrow()
+                .contains(&"[OK] Committed abc1234 (2 files)".to_string())
+        );
+        ascii(&app.ui);
+
+        let app = make_app(true, true, false)
+            .with_confirmation(false)
+            .with_symbols(Symbols::ASCII);
+        assert_eq!(app.run(), ExitCode::from(EXIT_GIT_ERROR));
+        ascii(&app.ui);
+
+        let app = make_app(false, true, true).with_symbols(Symbols::ASCII);
+        assert_eq!(app.run(), ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        ascii(&app.ui);
+
+        let app = make_app(false, true, true)
+            .with_amend(true)
+            .with_symbols(Symbols::ASCII);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        ascii(&app.ui);
+
+        let app = AppController::new(
+            OutsideRepository,
+            UnreachableSource,
+            MockUi::new(true),
+            MockExecutor::new(true),
+        )
+        .with_symbols(Symbols::ASCII);
+        assert_eq!(app.run(), ExitCode::from(EXIT_GIT_ERROR));
+        ascii(&app.ui);
+    }
+
+    #[test]
+    fn index_emptied_before_the_commit_stops_it() {
+        let app = AppController::new(
+            EmptiedIndex(RefCell::new(1)),
+            ok_source(),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        );
+        assert_eq!(app.run(), ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        let output = app.ui.output.borrow();
+        assert!(output.contains(&"✗ Nothing is staged any more — not committing.\n".to_string()));
+        assert!(output.contains(&"  git status\n".to_string()));
+        assert!(app.executor.committed.borrow().is_none());
+    }
+
+    #[test]
+    fn index_emptied_by_a_hook_is_named() {
+        let app = AppController::new(
+            EmptiedIndex(RefCell::new(2)),
+            ok_source(),
+            MockUi::new(true),
+            MockExecutor::new(false),
+        );
+        assert_eq!(app.run(), ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        let output = app.ui.output.borrow();
+        assert!(output.contains(&"✗ Commit failed: git process failed".to_string()));
+        assert!(output.contains(&"✗ A hook unstaged every change — not committing.\n".to_string()));
+        // Retrying cannot help: no recovery menu.
+        assert!(!output.iter().any(|line| line.starts_with("prompt:")));
+    }
+
+    #[test]
+    fn amending_does_not_need_the_index() {
+        let app = AppController::new(
+            EmptiedIndex(RefCell::new(1)),
+            ok_source(),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        )
+        .with_amend(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn staged_paths_are_committed_alone() {
+        let app = make_app(true, true, true).with_paths(vec!["src/x.rs".into(), "src".into()]);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(
+            app.ui
+                .output
+                .borrow()
+                .contains(&"Committing only: src/x.rs, src\n".to_string())
+        );
+        assert!(app.executor.committed.borrow().is_some());
+    }
+
+    #[test]
+    fn unstaged_path_stops_before_the_message() {
+        let app = make_app(true, true, true).with_paths(vec![
+            "src/x.rs".into(),
+            "README.md".into(),
+            "docs".into(),
+        ]);
+        assert_eq!(app.run(), ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        let output = app.ui.output.borrow();
+        assert!(output.contains(&"✗ Nothing staged in: README.md, docs\n".to_string()));
+        assert!(output.contains(&"  git add README.md docs\n".to_string()));
+        assert!(app.executor.committed.borrow().is_none());
+    }
+
+    #[test]
+    fn path_with_unstaged_changes_stops_before_the_message() {
+        let app = AppController::new(
+            PartlyStaged,
+            ok_source(),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        )
+        .with_paths(vec!["src/x.rs".into(), "src/y.rs".into()]);
+        assert_eq!(app.run(), ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        let output = app.ui.output.borrow();
+        assert!(output.contains(
+            &"✗ Unstaged changes in: src/y.rs — git would commit them too.\n".to_string()
+        ));
+        assert!(!output.iter().any(|line| line.starts_with("prompt:")));
+        assert!(app.executor.committed.borrow().is_none());
+    }
+
+    #[test]
+    fn staged_summary_counts_files_past_the_limit() {
+        let files: Vec<StagedFile> = (0..7).map(|i| staged('M', &format!("f{}", i))).collect();
+        assert_eq!(
+            staged_summary(&files),
+            "7 files staged: M f0, M f1, M f2, M f3, M f4, and 2 more"
+        );
+        assert_eq!(staged_summary(&files[..1]), "1 file staged: M f0");
+    }
+
+    fn previewed(app: &AppController<MockStaging, MockSource, MockUi, MockExecutor>) -> bool {
+        app.ui
+            .output
+            .borrow()
+            .iter()
+            .any(|line| line.starts_with("preview: "))
+    }
+
+    #[test]
+    fn show_diff_prints_the_stat_before_the_preview() {
+        let app = make_app(true, true, true).with_show_diff(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        let output = app.ui.output.borrow();
+        let stat = output
+            .iter()
+            .position(|l| l == "\n src/x.rs | 2 +-\n 1 file changed, 1 insertion(+), 1 deletion(-)")
+            .expect("diffstat shown");
+        let preview = output
+            .iter()
+            .position(|l| l.starts_with("preview: "))
+            .unwrap();
+        assert!(stat < preview);
+    }
+
+    #[test]
+    fn long_header_is_flagged_after_the_preview() {
+        let config = ValidationConfig {
+            max_header_length: Some(10),
+            ..Default::default()
+        };
+        let message = CommitMessage::new(
+            CommitType::Feat,
+            None,
+            "add feature".into(),
+            None,
+            None,
+            vec![],
+            &config,
+        )
+        .unwrap();
+        let app = AppController::new(
+            MockStaging(true),
+            MockSource(Ok(message)),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        );
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        let output = app.ui.output.borrow();
+        let preview = output
+            .iter()
+            .position(|l| l.starts_with("preview: "))
+            .unwrap();
+        assert!(output[preview + 1].starts_with("⚠ Header is 17 characters"));
+
+        let app = make_app(true, true, true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(!app.ui.output.borrow().iter().any(|l| l.starts_with('⚠')));
+    }
+
+    fn described(description: &str) -> MockSource {
+        MockSource(
+            CommitMessage::new(
+                CommitType::Feat,
+                None,
+                description.into(),
+                None,
+                None,
+                vec![],
+                &ValidationConfig::default(),
+            )
+            .map_err(|e| e.to_string()),
+        )
+    }
+
+    #[test]
+    fn warnings_are_shown_but_do_not_block() {
+        let app = AppController::new(
+            MockStaging(true),
+            described("added feature."),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        );
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        let output = app.ui.output.borrow();
+        assert!(output.contains(&"⚠ Use 'add' instead of 'added'.".to_string()));
+        assert!(output.contains(&"⚠ Description ends with a period.".to_string()));
+    }
+
+    #[test]
+    fn strict_refuses_a_message_with_warnings() {
+        let app = AppController::new(
+            MockStaging(true),
+            described("added feature"),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        )
+        .with_strict(true);
+        assert_eq!(app.run(), ExitCode::from(EXIT_INVALID_MESSAGE));
+        let output = app.ui.output.borrow();
+        assert!(output.contains(&"✗ Use 'add' instead of 'added'.".to_string()));
+        assert!(!output.iter().any(|l| l.starts_with('⚠')));
+        assert!(app.executor.committed.borrow().is_none());
+
+        let app = make_app(true, true, true).with_strict(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn unconfirmed_commit_can_be_declined() {
+        let app = make_app(true, true, true)
+            .with_confirmation(false)
+            .with_decline_unconfirmed(true);
+        assert_eq!(app.run(), ExitCode::from(EXIT_CANCELLED));
+        assert!(app.executor.committed.borrow().is_none());
+        assert!(previewed(&app));
+
+        // Declining only replaces the skipped prompt; asking still works.
+        let app = make_app(true, true, true).with_decline_unconfirmed(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn diff_is_not_shown_by_default() {
+        let app = make_app(true, true, true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(!app.ui.output.borrow().iter().any(|l| l.contains(" | ")));
+    }
+
+    #[test]
+    fn commits_without_asking_when_confirmation_is_off() {
+        // The UI would decline, but it is never asked.
+        let app = make_app(true, false, true)
+            .with_confirmation(false)
+            .with_preview(false);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(app.executor.committed.borrow().is_some());
+        assert!(!previewed(&app));
+    }
+
+    #[test]
+    fn preview_can_be_shown_without_confirmation() {
+        let app = make_app(true, false, true).with_confirmation(false);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(previewed(&app));
+    }
+
     #[test]
     fn fails_when_no_staged_changes() {
-        assert_eq!(make_app(false, true, true).run(), ExitCode::FAILURE);
+        assert_eq!(
+            make_app(false, true, true).run(),
+            ExitCode::from(EXIT_NO_STAGED_CHANGES)
+        );
     }
 
     #[test]
     fn fails_when_user_aborts_at_confirm() {
-        assert_eq!(make_app(true, false, true).run(), ExitCode::FAILURE);
+        assert_eq!(
+            make_app(true, false, true).run(),
+            ExitCode::from(EXIT_CANCELLED)
+        );
+    }
+
+    #[test]
+    fn enter_at_confirm_cancels_by_default() {
+        let app = AppController::new(
+            MockStaging(true),
+            ok_source(),
+            MockUi::pressing_enter(),
+            MockExecutor::new(true),
+        );
+        assert_eq!(app.run(), ExitCode::from(EXIT_CANCELLED));
+    }
+
+    #[test]
+    fn enter_at_confirm_commits_when_default_is_yes() {
+        let app = AppController::new(
+            MockStaging(true),
+            ok_source(),
+            MockUi::pressing_enter(),
+            MockExecutor::new(true),
+        )
+        .with_confirm_default(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
     }
 
     #[test]
     fn fails_when_executor_fails() {
-        assert_eq!(make_app(true, true, false).run(), ExitCode::FAILURE);
+        assert_eq!(
+            make_app(true, true, false).run(),
+            ExitCode::from(EXIT_GIT_ERROR)
+        );
+    }
+
+    #[test]
+    fn edit_after_failed_commit_retries_with_revised_message() {
+        let app = AppController::new(
+            MockStaging(true),
+            RevisingSource,
+            MockUi::answering(vec!["e"]),
+            MockExecutor::failing_once(),
+        );
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(
+            app.executor.committed.borrow().as_deref(),
+            Some("feat: add feature\n\nRefs: #42")
+        );
+        let output = app.ui.output.borrow();
+        assert!(output.contains(&"✗ Commit failed: hook rejected the message".to_string()));
+        assert!(output.contains(&"prompt: (e)dit / (r)etry / (d)ry-run / (a)bort: ".to_string()));
+        assert!(output.contains(&"preview: feat: add feature\n\nRefs: #42".to_string()));
+    }
+
+    /// Re-opening the editor turns a clean message into one with a warning.
+    struct WarnedRevision;
+    impl CommitMessageSource for WarnedRevision {
+        type Error = String;
+        fn resolve(&self) -> Result<CommitMessage, String> {
+            ok_source().0
+        }
+        fn can_revise(&self) -> bool {
+            true
+        }
+        fn revise(&self, _: &CommitMessage) -> Result<CommitMessage, String> {
+            described("added feature").0
+        }
+    }
+
+    #[test]
+    fn strict_checks_a_message_edited_after_a_failed_commit() {
+        let app = AppController::new(
+            MockStaging(true),
+            WarnedRevision,
+            MockUi::answering(vec!["e"]),
+            MockExecutor::failing_once(),
+        )
+        .with_strict(true);
+        assert_eq!(app.run(), ExitCode::from(EXIT_INVALID_MESSAGE));
+        // Only the first, failed attempt reached git.
+        assert_eq!(
+            app.executor.committed.borrow().as_deref(),
+            Some("feat: add feature")
+        );
+        assert!(
+            app.ui
+                .output
+                .borrow()
+                .contains(&"\nNot committing: --strict treats warnings as errors.".to_string())
+        );
+    }
+
+    #[test]
+    fn retry_after_failed_commit_keeps_the_message() {
+        let app = AppController::new(
+            MockStaging(true),
+            ok_source(),
+            MockUi::answering(vec!["d", "retry"]),
+            MockExecutor::failing_once(),
+        );
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(*app.executor.dry_runs.borrow(), 1);
+        assert_eq!(
+            app.executor.committed.borrow().as_deref(),
+            Some("feat: add feature")
+        );
+        // Sources that cannot revise are not offered an edit.
+        assert!(
+            app.ui
+                .output
+                .borrow()
+                .contains(&"prompt: (r)etry / (d)ry-run / (a)bort: ".to_string())
+        );
+    }
+
+    #[test]
+    fn head_moved_only_when_it_changed() {
+        assert_eq!(head_moved(Some("a1"), Some("b2")), Some("b2"));
+        assert_eq!(head_moved(None, Some("b2")), Some("b2"));
+        assert_eq!(head_moved(Some("a1"), Some("a1")), None);
+        assert_eq!(head_moved(None, None), None);
+    }
+
+    #[test]
+    fn committed_line_counts_files_when_known() {
+        let result = |files_changed| CommitResult {
+            sha: "3f2a9c1e5b7d".into(),
+            summary: "feat: x".into(),
+            files_changed,
+        };
+        assert_eq!(
+            committed_line(&result(Some(3)), Symbols::UNICODE),
+            "✓ Committed 3f2a9c1 (3 files)"
+        );
+        assert_eq!(
+            committed_line(&result(Some(1)), Symbols::UNICODE),
+            "✓ Committed 3f2a9c1 (1 file)"
+        );
+        assert_eq!(
+            committed_line(&result(None), Symbols::ASCII),
+            "[OK] Committed 3f2a9c1"
+        );
+    }
+
+    #[test]
+    fn hook_failure_after_the_commit_offers_to_undo_it() {
+        let app = AppController::new(
+            MockStaging(true),
+            ok_source(),
+            MockUi::new(true),
+            MockExecutor::committing_then_failing(),
+        );
+        assert_eq!(app.run(), ExitCode::from(EXIT_GIT_ERROR));
+        let output = app.ui.output.borrow();
+        assert!(output.contains(
+            &"⚠ Commit created, but a hook failed: post-commit hook exited with 1".to_string()
+        ));
+        assert!(output.contains(&"  SHA: abc1234f00d".to_string()));
+        assert!(!output.iter().any(|l| l.contains("Commit failed")));
+        assert_eq!(app.executor.reset_to.borrow().as_deref(), Some("0ld5ha"));
+    }
+
+    #[test]
+    fn hook_failure_after_the_commit_unattended_only_explains_the_undo() {
+        let app = AppController::new(
+            MockStaging(true),
+            ok_source(),
+            MockUi::new(true),
+            MockExecutor::committing_then_failing(),
+        )
+        .with_confirmation(false);
+        assert_eq!(app.run(), ExitCode::from(EXIT_GIT_ERROR));
+        assert!(app.ui.output.borrow().contains(
+            &"  To undo it and keep the changes staged: git reset --soft 0ld5ha".to_string()
+        ));
+        assert!(app.executor.reset_to.borrow().is_none());
+    }
+
+    #[test]
+    fn failed_commit_is_not_retried_without_confirmation() {
+        let app = AppController::new(
+            MockStaging(true),
+            RevisingSource,
+            MockUi::answering(vec!["e"]),
+            MockExecutor::failing_once(),
+        )
+        .with_confirmation(false);
+        assert_eq!(app.run(), ExitCode::from(EXIT_GIT_ERROR));
+        assert!(
+            !app.ui
+                .output
+                .borrow()
+                .iter()
+                .any(|l| l.starts_with("prompt: "))
+        );
+    }
+
+    #[test]
+    fn shows_progress_while_committing() {
+        let app = make_app(true, true, true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(
+            app.ui
+                .output
+                .borrow()
+                .contains(&"progress: Running git commit".to_string())
+        );
+
+        let app = make_app(true, true, true).with_spinner(false);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert!(
+            !app.ui
+                .output
+                .borrow()
+                .iter()
+                .any(|l| l.starts_with("progress: "))
+        );
+    }
+
+    #[test]
+    fn quiet_prints_only_the_sha() {
+        let app = make_app(true, true, true)
+            .with_quiet(true)
+            .with_preview(false);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(app.ui.output.borrow().as_slice(), ["abc1234f00d"]);
+    }
+
+    #[test]
+    fn quiet_still_previews_and_reports_errors() {
+        let app = make_app(true, true, true).with_quiet(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(
+            app.ui.output.borrow().as_slice(),
+            ["preview: feat: add feature", "abc1234f00d"]
+        );
+
+        let app = make_app(false, true, true).with_quiet(true);
+        assert_eq!(app.run(), ExitCode::from(EXIT_NO_STAGED_CHANGES));
+        assert_eq!(app.ui.output.borrow()[0], "✗ No staged changes found.\n");
+    }
+
+    #[test]
+    fn amend_proceeds_without_staged_changes() {
+        let app = make_app(false, true, true).with_amend(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn signoff_appends_trailer_to_committed_message() {
+        let app = make_app(true, true, true).with_signoff(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        let committed = app.executor.committed.borrow().clone().unwrap();
+        assert!(committed.ends_with("\n\nSigned-off-by: Jane Doe <jane@example.com>"));
+    }
+
+    #[test]
+    fn co_authors_precede_signoff() {
+        let app = make_app(true, true, true)
+            .with_co_authors(vec!["A <a@x>".into(), "B <b@x>".into()])
+            .with_signoff(true);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(
+            app.executor.committed.borrow().as_deref(),
+            Some(
+                "feat: add feature\n\n\
+                 Co-authored-by: A <a@x>\n\
+                 Co-authored-by: B <b@x>\n\
+                 Signed-off-by: Jane Doe <jane@example.com>"
+            )
+        );
+    }
+
+    #[test]
+    fn signoff_fails_without_identity() {
+        let mut executor = MockExecutor::new(true);
+        executor.identity = None;
+        let app = AppController::new(MockStaging(true), ok_source(), MockUi::new(true), executor)
+            .with_signoff(true);
+        assert_eq!(app.run(), ExitCode::from(EXIT_GIT_ERROR));
+        assert!(app.executor.committed.borrow().is_none());
+    }
+
+    fn long_body_source() -> MockSource {
+        MockSource(Ok(CommitMessage::new(
+            CommitType::Docs,
+            None,
+            "document wrapping".into(),
+            Some("word ".repeat(30).trim_end().to_string()),
+            None,
+            vec![],
+            &ValidationConfig::default(),
+        )
+        .unwrap()))
+    }
+
+    #[test]
+    fn commits_body_wrapped_at_default_width() {
+        let app = AppController::new(
+            MockStaging(true),
+            long_body_source(),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        );
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        let committed = app.executor.committed.borrow().clone().unwrap();
+        assert!(
+            committed
+                .lines()
+                .all(|l| l.chars().count() <= DEFAULT_WRAP_WIDTH)
+        );
+        assert!(committed.lines().count() > 3);
+    }
+
+    #[test]
+    fn wrapping_can_be_disabled() {
+        let app = AppController::new(
+            MockStaging(true),
+            long_body_source(),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        )
+        .with_wrap_width(None);
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        let committed = app.executor.committed.borrow().clone().unwrap();
+        assert_eq!(committed.lines().count(), 3);
     }
 
     #[test]
@@ -247,8 +1750,117 @@ mod tests {
             MockStaging(true),
             MockSource(Err("editor closed without saving".into())),
             MockUi::new(true),
-            MockExecutor { succeeds: true },
+            MockExecutor::new(true),
+        );
+        assert_eq!(app.run(), ExitCode::from(EXIT_INVALID_MESSAGE));
+    }
+
+    /// A writer the test can read back after run() has borrowed it.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(std::rc::Rc<RefCell<Vec<u8>>>);
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    impl SharedBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn output_writes_the_message_instead_of_committing() {
+        let buffer = SharedBuffer::default();
+        // Nothing staged and nobody to confirm: neither is needed.
+        let app = make_app(false, false, true)
+            .with_co_authors(vec!["Jane Doe <jane@example.com>".into()])
+            .with_output(Some(Box::new(buffer.clone())));
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(
+            buffer.contents(),
+            "feat: add feature\n\nCo-authored-by: Jane Doe <jane@example.com>\n"
+        );
+        assert!(app.executor.committed.borrow().is_none());
+        assert!(app.ui.output.borrow().is_empty());
+    }
+
+    #[test]
+    fn output_writes_to_a_file() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let app = make_app(true, true, true).with_output(Some(Box::new(file.reopen().unwrap())));
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(
+            std::fs::read_to_string(file.path()).unwrap(),
+            "feat: add feature\n"
+        );
+    }
+
+    #[test]
+    fn output_writes_nothing_for_an_invalid_message() {
+        let buffer = SharedBuffer::default();
+        let app = AppController::new(
+            MockStaging(true),
+            MockSource(Err("Invalid commit type: 'feature'".into())),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        )
+        .with_output(Some(Box::new(buffer.clone())));
+        assert_eq!(app.run(), ExitCode::from(EXIT_INVALID_MESSAGE));
+        assert!(buffer.contents().is_empty());
+
+        let app = AppController::new(
+            MockStaging(true),
+            described("added search"),
+            MockUi::new(true),
+            MockExecutor::new(true),
+        )
+        .with_strict(true)
+        .with_output(Some(Box::new(buffer.clone())));
+        assert_eq!(app.run(), ExitCode::from(EXIT_INVALID_MESSAGE));
+        assert!(buffer.contents().is_empty());
+    }
+
+    #[test]
+    fn exit_codes_are_distinct_and_non_zero() {
+        let codes = [
+            EXIT_INVALID_MESSAGE,
+            EXIT_NO_STAGED_CHANGES,
+            EXIT_GIT_ERROR,
+            EXIT_CANCELLED,
+        ];
+        assert!(codes.iter().all(|&c| c != 0));
+        for (i, a) in codes.iter().enumerate() {
+            assert!(codes[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn builder_swaps_in_one_port_at_a_time() {
+        // Each step only builds: the default git adapters must not run here.
+        let builder = AppControllerBuilder::default();
+        let builder = builder.staging(MockStaging(true));
+        let _ = builder.build();
+        let builder = AppControllerBuilder::default().input(ok_source());
+        let _ = builder.build();
+        let builder = AppControllerBuilder::default().ui(MockUi::new(true));
+        let _ = builder.build();
+        let builder = AppControllerBuilder::default().executor(MockExecutor::new(true));
+        let _ = builder.build();
+
+        let app = AppControllerBuilder::default()
+            .staging(MockStaging(true))
+            .input(ok_source())
+            .ui(MockUi::new(true))
+            .executor(MockExecutor::new(true))
+            .build();
+        assert_eq!(app.run(), ExitCode::SUCCESS);
+        assert_eq!(
+            app.executor.committed.borrow().as_deref(),
+            Some("feat: add feature")
         );
-        assert_eq!(app.run(), ExitCode::FAILURE);
     }
 }
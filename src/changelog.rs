@@ -0,0 +1,213 @@
+/// Changelog fragments — `commando --changelog <rev-range>`.
+///
+/// Every message in the range goes through the same compiler + domain
+/// validation as a commit would. Valid ones are grouped by type, breaking
+/// changes are repeated in their own section first, and anything else
+/// (merges, non-conventional subjects) is listed under "Other".
+///
+/// Output is markdown with `###` headings so it can be pasted under a
+/// release heading. Empty sections are omitted.
+use crate::compiler::{CommitAst, CompilerPipeline};
+use crate::domain::{CommitMessage, CommitType, ValidationConfig, is_breaking_change_key};
+
+/// Render the fragment for `messages`, newest first as `git log` lists them.
+pub fn render(messages: &[String], config: &ValidationConfig) -> String {
+    let mut breaking = Vec::new();
+    let mut groups: Vec<(CommitType, Vec<String>)> = Vec::new();
+    let mut other = Vec::new();
+
+    for raw in messages {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            continue;
+        }
+        let Some((commit_type, ast)) = parse(raw, config) else {
+            other.push(format!("- {}", raw.lines().next().unwrap_or("")));
+            continue;
+        };
+
+        if let Some(note) = breaking_note(&ast) {
+            breaking.push(entry(&ast, &note));
+        }
+        let line = entry(&ast, &ast.header.description);
+        match groups.iter_mut().find(|(t, _)| *t == commit_type) {
+            Some((_, entries)) => entries.push(line),
+            None => groups.push((commit_type, vec![line])),
+        }
+    }
+
+    // Built-in types in a fixed order, custom types as first seen.
+    groups.sort_by_key(|(t, _)| {
+        let all = CommitType::all();
+        all.iter().position(|b| b == t).unwrap_or(all.len())
+    });
+
+    let mut sections = Vec::new();
+    if !breaking.is_empty() {
+        sections.push(section("Breaking Changes", &breaking));
+    }
+    for (commit_type, entries) in &groups {
+        sections.push(section(&heading(commit_type), entries));
+    }
+    if !other.is_empty() {
+        sections.push(section("Other", &other));
+    }
+    sections.join("\n")
+}
+
+/// The AST of a valid message and its parsed type, or None for "Other".
+fn parse(raw: &str, config: &ValidationConfig) -> Option<(CommitType, CommitAst)> {
+    let ast = CompilerPipeline::new()
+        .with_gitmoji(config.gitmoji)
+        .with_normalize_unicode(config.normalize_unicode)
+        .compile(raw)
+        .ok()?;
+    CommitMessage::from_ast(ast.clone(), config).ok()?;
+    let commit_type =
+        CommitType::from_str_with_custom(&ast.header.commit_type, &config.custom_types).ok()?;
+    Some((commit_type, ast))
+}
+
+fn entry(ast: &CommitAst, text: &str) -> String {
+    match ast.header.scope {
+        Some(ref scope) => format!("- **{}:** {}", scope, text),
+        None => format!("- {}", text),
+    }
+}
+
+/// The BREAKING CHANGE footer, or the description for a bare '!' header.
+fn breaking_note(ast: &CommitAst) -> Option<String> {
+    ast.footers
+        .iter()
+        .find(|f| is_breaking_change_key(&f.key))
+        .map(|f| f.value.clone())
+        .or_else(|| ast.header.breaking.then(|| ast.header.description.clone()))
+}
+
+fn heading(commit_type: &CommitType) -> String {
+    match commit_type {
+        CommitType::Feat => "Features".into(),
+        CommitType::Fix => "Bug Fixes".into(),
+        CommitType::Docs => "Documentation".into(),
+        CommitType::Style => "Styles".into(),
+        CommitType::Refactor => "Refactoring".into(),
+        CommitType::Perf => "Performance".into(),
+        CommitType::Test => "Tests".into(),
+        CommitType::Build => "Build System".into(),
+        CommitType::Ci => "Continuous Integration".into(),
+        CommitType::Chore => "Chores".into(),
+        CommitType::Revert => "Reverts".into(),
+        CommitType::Custom(name) => {
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+    }
+}
+
+fn section(title: &str, entries: &[String]) -> String {
+    format!("### {}\n\n{}\n", title, entries.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_all(messages: &[&str]) -> String {
+        let messages: Vec<String> = messages.iter().map(|m| m.to_string()).collect();
+        render(&messages, &ValidationConfig::default())
+    }
+
+    #[test]
+    fn groups_by_type_in_fixed_order() {
+        let out = render_all(&[
+            "fix(parser): handle tabs",
+            "feat(api): add search",
+            "docs: update readme",
+            "feat: add login",
+        ]);
+        assert_eq!(
+            out,
+            "\
+### Features
+
+- **api:** add search
+- add login
+
+### Bug Fixes
+
+- **parser:** handle tabs
+
+### Documentation
+
+- update readme
+"
+        );
+    }
+
+    #[test]
+    fn breaking_changes_get_their_own_section_first() {
+        let out = render_all(&[
+            "feat(api)!: drop v1\n\nBREAKING CHANGE: v1 endpoints are gone",
+            "refactor!: rename config keys",
+        ]);
+        assert_eq!(
+            out,
+            "\
+### Breaking Changes
+
+- **api:** v1 endpoints are gone
+- rename config keys
+
+### Features
+
+- **api:** drop v1
+
+### Refactoring
+
+- rename config keys
+"
+        );
+    }
+
+    #[test]
+    fn non_conventional_and_invalid_go_under_other() {
+        let out = render_all(&[
+            "Merge branch 'main' into topic\n\nConflicts resolved.",
+            "feature: misspelled type",
+            "fix: real fix",
+        ]);
+        assert_eq!(
+            out,
+            "\
+### Bug Fixes
+
+- real fix
+
+### Other
+
+- Merge branch 'main' into topic
+- feature: misspelled type
+"
+        );
+    }
+
+    #[test]
+    fn custom_types_follow_builtins() {
+        let messages = vec!["deps: bump clap".to_string(), "fix: x".to_string()];
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        let out = render(&messages, &config);
+        assert!(out.find("### Bug Fixes").unwrap() < out.find("### Deps").unwrap());
+    }
+
+    #[test]
+    fn empty_range_is_empty_output() {
+        assert_eq!(render_all(&[]), "");
+        assert_eq!(render_all(&["", "\n"]), "");
+    }
+}
@@ -4,8 +4,43 @@
 //! Mode selection happens here. AppController never knows which mode ran.
 //!
 //! Default (no flags):    editor mode   — opens $EDITOR with template
-//! -m / --message <MSG>:  direct mode   — inline string, no editor
+//! -m / --message <MSG>:  direct mode   — inline string, no editor; without a `type:`
+//!                                        prefix, a terminal gets the type picker
 //! -i / --interactive:    interactive   — guided field-by-field prompts
+//! --paste:               paste mode    — read the whole message from stdin until EOF
+//! -F / --file <PATH>:    file mode     — read the message from a file ('#' lines stripped)
+//! --json-input <JSON>:  json mode     — build the message from a JSON object (scripts, release tools)
+//! --autobody:          editor / -i   — start the body as a bullet list of the staged changes
+//! --template <PATH>:     editor        — start from PATH instead of the built-in template
+//!                                        (defaults to git's commit.template)
+//! --revert <REV>:        revert mode   — stage the inverse of REV, commit as "revert: <subject>"
+//! --fixup / --squash <REV>:          — commit as "fixup! <subject>" for `git rebase --autosquash`
+//! -e / --edit:           with -m       — open the editor on the -m message before committing
+//! --amend:               any mode      — rewrite HEAD; editor/interactive start from its message
+//! --redo:                editor / -i   — revise HEAD's message (--amend, seeded from HEAD)
+//! -s / --signoff:        any mode      — append Signed-off-by from git user.name/user.email
+//! --co-author <ID>:     any mode      — add a Co-authored-by trailer ("Name <email>"), repeatable
+//! -n / --no-verify:     any mode      — skip the pre-commit and commit-msg hooks
+//! -S / --gpg-sign[=KEY]: any mode      — have git sign the commit (GPG or SSH, per git config)
+//! -q / --quiet:         any mode      — print only errors and the new commit's SHA
+//! --show-diff:          any mode      — show `git diff --cached --stat` before confirming
+//! --stream:             any mode      — show git and hook output live while committing
+//! --ascii:              any mode      — ASCII status marks ([OK], [X], [!]) instead of ✓ ✗ ⚠
+//! --wrap <COLS>:         any mode      — wrap body lines at COLS (0 disables)
+//! --validate [--json]:   -m/-F/--paste — check the message and exit; nothing is committed
+//! --output <FILE>:     any mode      — write the formatted message to FILE ('-': stdout), no commit
+//! --strict:             any mode      — warnings (mood, trailing period, long header) fail like errors
+//! --changelog <RANGE>:  standalone    — print a markdown changelog fragment for RANGE
+//! --hook prepare-commit-msg <FILE> [SOURCE [SHA]]: — run as a git hook: format FILE in place, no prompts
+//! --completions <SHELL>: standalone    — print a bash/zsh/fish/... completion script
+//! -- <PATHS>:           any mode      — commit only PATHS (each must be staged, with nothing unstaged)
+//!
+//! Without a terminal on stdin, or with COMMANDO_NONINTERACTIVE=1 (CI), the
+//! editor and -i are refused, and the confirmation is answered by the
+//! `unattended_commit` setting.
+//!
+//! Project defaults come from the nearest `.commando.toml` (see config/mod.rs),
+//! or from `--config-path <FILE>` which skips the search. Flags override the file.
 //!
 //! Multi-line messages with -m:
 //!   commando -m $'feat(auth): add OAuth\n\nBody text here.'
@@ -13,14 +48,34 @@
 //!
 //! Body text here."
 
+use std::io::{IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
-use clap::{ArgGroup, Parser};
+use clap::builder::PossibleValuesParser;
+use clap::{ArgGroup, CommandFactory, Parser};
+use clap_complete::Shell;
 
-use crate::adapters::{GitCommitExecutor, GitStagingChecker, TerminalUI};
-use crate::app::AppController;
+use crate::adapters::{
+    GitCommitExecutor, GitConfig, GitHistory, GitRevert, GitStagingChecker, TerminalUI,
+};
+use crate::app::{AppControllerBuilder, EXIT_GIT_ERROR, EXIT_INVALID_MESSAGE};
+use crate::changelog;
 use crate::compiler::CompilerPipeline;
-use crate::input::{DirectSource, EditorSource, InteractiveSource};
+use crate::config::Config;
+use crate::domain::{Autosquash, CommitMessage, CommitType, ValidationConfig};
+use crate::hook;
+use crate::input::editor::{DEFAULT_COMMENT_CHAR, strip_comments};
+use crate::input::{
+    AutosquashSource, DirectSource, EditorSource, FieldsSource, FileSource, Guided,
+    InteractiveSource, JsonSource, PasteSource, Prefill, RevertSource, STDIN_PATH,
+    body_from_changes, infer_from_branch, infer_scope,
+};
+use crate::ports::StagingChecker;
+use crate::ports::input::CommitMessageSource;
+use crate::ports::ui::Ui;
+use crate::symbols::Symbols;
+use crate::validate::ValidateOutput;
 
 #[derive(Parser)]
 #[command(
@@ -28,37 +83,976 @@ use crate::input::{DirectSource, EditorSource, InteractiveSource};
     about = "Conventional commit helper",
     long_about = None,
 )]
-#[command(group(ArgGroup::new("mode").args(["message", "interactive"])))]
+#[command(group(ArgGroup::new("mode").args(["message", "interactive", "paste", "file", "commit_type", "json_input", "revert", "fixup", "squash"])))]
 struct Cli {
     /// Inline commit message — skips the editor.
     /// Supports multi-line: use $'...\n...' or a quoted newline in your shell.
     #[arg(short = 'm', long = "message", value_name = "MSG")]
     message: Option<String>,
 
+    /// With -m: open the editor on the message instead of committing it as is.
+    #[arg(short = 'e', long = "edit", requires = "message", conflicts_with_all = ["validate", "hook"])]
+    edit: bool,
+
     /// Open field-by-field interactive prompts instead of the editor.
     #[arg(short = 'i', long = "interactive")]
     interactive: bool,
+
+    /// Start the body with one bullet per staged file ("- Modified src/x.rs").
+    /// Editor and -i only; the bullets are there to be edited.
+    #[arg(long = "autobody", conflicts_with_all = ["message", "paste", "file", "commit_type", "json_input", "revert", "fixup", "squash", "amend", "redo", "validate"])]
+    autobody: bool,
+
+    /// Read the full commit message from stdin (paste, then Ctrl-D).
+    #[arg(long = "paste")]
+    paste: bool,
+
+    /// Read the commit message from a file. Lines starting with '#' are ignored.
+    /// `-F -` reads stdin and commits without prompting (for scripts).
+    #[arg(short = 'F', long = "file", value_name = "PATH")]
+    file: Option<PathBuf>,
+
+    /// With -F -, --type or --json-input: show the message before committing.
+    #[arg(long = "preview")]
+    preview: bool,
+
+    /// Build the message from flags instead: the commit type (needs --description).
+    #[arg(long = "type", value_name = "TYPE", requires = "description")]
+    commit_type: Option<String>,
+
+    /// With --type: the scope.
+    #[arg(long = "scope", value_name = "SCOPE", requires = "commit_type")]
+    scope: Option<String>,
+
+    /// With --type: the description.
+    #[arg(long = "description", value_name = "TEXT", requires = "commit_type")]
+    description: Option<String>,
+
+    /// With --type: the body.
+    #[arg(long = "body", value_name = "TEXT", requires = "commit_type")]
+    body: Option<String>,
+
+    /// With --type: a BREAKING CHANGE footer (also adds '!' to the header).
+    #[arg(long = "breaking", value_name = "TEXT", requires = "commit_type")]
+    breaking: Option<String>,
+
+    /// With --type: a Refs footer, e.g. "#42".
+    #[arg(long = "refs", value_name = "REFS", requires = "commit_type")]
+    refs: Option<String>,
+
+    /// Build the message from a JSON object, e.g.
+    /// '{"type":"feat","scope":"api","description":"add search"}'.
+    #[arg(long = "json-input", value_name = "JSON")]
+    json_input: Option<String>,
+
+    /// Revert a commit: stage its inverse and commit it as
+    /// `revert: <subject>` with "This reverts commit <sha>." as the body.
+    #[arg(long = "revert", value_name = "REV", conflicts_with_all = ["amend", "redo"])]
+    revert: Option<String>,
+
+    /// Commit as `fixup! <subject of REV>`, for `git rebase -i --autosquash`.
+    /// Not a conventional commit, so the message is not validated.
+    #[arg(long = "fixup", value_name = "REV", conflicts_with_all = ["amend", "redo"])]
+    fixup: Option<String>,
+
+    /// Like --fixup, but `squash! <subject>`: the messages are combined.
+    #[arg(long = "squash", value_name = "REV", conflicts_with_all = ["amend", "redo"])]
+    squash: Option<String>,
+
+    /// Editor template to start from (default: git config commit.template).
+    #[arg(long = "template", value_name = "PATH", conflicts_with_all = ["mode"])]
+    template: Option<PathBuf>,
+
+    /// Load settings from this file instead of the nearest .commando.toml.
+    #[arg(long = "config-path", value_name = "FILE")]
+    config_path: Option<PathBuf>,
+
+    /// Amend the previous commit instead of creating a new one.
+    #[arg(long = "amend")]
+    amend: bool,
+
+    /// Revise the last commit's message in the editor (or with -i) and amend it.
+    #[arg(long = "redo", conflicts_with_all = ["message", "paste", "file", "commit_type", "revert", "fixup", "squash"])]
+    redo: bool,
+
+    /// Append a Signed-off-by trailer using git user.name and user.email.
+    #[arg(short = 's', long = "signoff")]
+    signoff: bool,
+
+    /// Add a Co-authored-by trailer, e.g. --co-author "Jane Doe <jane@example.com>".
+    /// Repeatable.
+    #[arg(long = "co-author", value_name = "AUTHOR", value_parser = co_author)]
+    co_author: Vec<String>,
+
+    /// Skip the pre-commit and commit-msg hooks (git commit --no-verify).
+    #[arg(short = 'n', long = "no-verify")]
+    no_verify: bool,
+
+    /// Sign the commit, optionally with a specific key id (--gpg-sign=KEYID).
+    #[arg(
+        short = 'S',
+        long = "gpg-sign",
+        value_name = "KEYID",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = ""
+    )]
+    gpg_sign: Option<String>,
+
+    /// Mark status lines with [OK]/[X]/[!] instead of Unicode symbols.
+    #[arg(long = "ascii")]
+    ascii: bool,
+
+    /// Print only errors and the new commit's SHA.
+    #[arg(short = 'q', long = "quiet")]
+    quiet: bool,
+
+    /// Show what is staged (git diff --cached --stat) before the preview.
+    #[arg(long = "show-diff")]
+    show_diff: bool,
+
+    /// Show git's output (e.g. pre-commit hooks) live instead of after it exits.
+    #[arg(long = "stream")]
+    stream: bool,
+
+    /// Wrap body lines at this column (default 72). 0 keeps the body exactly as typed.
+    #[arg(long = "wrap", value_name = "COLS")]
+    wrap: Option<usize>,
+
+    /// Write the formatted message to FILE ('-' for stdout) instead of committing.
+    #[arg(
+        long = "output",
+        value_name = "FILE",
+        conflicts_with_all = ["validate", "changelog", "hook", "paths", "amend", "redo", "revert"]
+    )]
+    output: Option<PathBuf>,
+
+    /// Only check the message (from -m, -F or --paste) and exit 0 if valid.
+    #[arg(long = "validate")]
+    validate: bool,
+
+    /// Print the lexer tokens and parsed AST to stderr (with -m or --validate).
+    #[arg(long = "verbose", visible_alias = "debug-compile")]
+    verbose: bool,
+
+    /// Treat warnings (mood, trailing period, long header) as errors.
+    #[arg(long = "strict")]
+    strict: bool,
+
+    /// With --validate: print a JSON report instead of text.
+    #[arg(long = "json", requires = "validate")]
+    json: bool,
+
+    /// Print a markdown changelog fragment for a revision range (e.g. v1.0..HEAD).
+    #[arg(
+        long = "changelog",
+        value_name = "RANGE",
+        conflicts_with_all = ["mode", "validate", "redo", "hook"]
+    )]
+    changelog: Option<String>,
+
+    /// Run as a git hook, e.g. `--hook prepare-commit-msg "$@"`: the message
+    /// file, then git's optional message source and commit.
+    #[arg(
+        long = "hook",
+        num_args = 2..=4,
+        value_names = ["HOOK", "FILE", "SOURCE", "SHA"],
+        conflicts_with_all = ["mode", "validate", "redo"]
+    )]
+    hook: Option<Vec<String>>,
+
+    /// Print a shell completion script, e.g. `commando --completions zsh > _commando`.
+    #[arg(
+        long = "completions",
+        value_name = "SHELL",
+        conflicts_with_all = ["mode", "validate", "changelog", "hook", "redo"]
+    )]
+    completions: Option<Shell>,
+
+    /// Commit only these paths, e.g. `commando -m "fix: x" -- src/a.rs`.
+    /// Each must have staged changes and no unstaged ones: git commits
+    /// them as they are in the working tree.
+    #[arg(last = true, value_name = "PATHS", conflicts_with_all = ["validate", "changelog", "hook"])]
+    paths: Vec<String>,
+}
+
+/// Where the message comes from — exactly one per run (see ArgGroup "mode").
+enum Mode {
+    Direct(String),
+    File(PathBuf),
+    /// `-F -`: the whole message from stdin, no prompts.
+    Stdin,
+    /// --type/--scope/--description/...: no text to compile.
+    Fields,
+    /// --json-input: the same fields as a JSON object.
+    Json(String),
+    /// --revert: the message is derived from the reverted commit.
+    Revert(String),
+    /// --fixup / --squash: the message is derived from the target commit.
+    Autosquash(Autosquash, String),
+    Paste,
+    Interactive,
+    Editor,
+}
+
+impl Mode {
+    /// Modes that need someone at the terminal.
+    fn prompts(&self) -> bool {
+        matches!(self, Mode::Interactive | Mode::Editor)
+    }
+}
+
+impl Cli {
+    /// --redo is --amend restricted to the prompting modes.
+    fn amend(&self) -> bool {
+        self.amend || self.redo
+    }
+
+    /// --ascii, or `ascii_output` in the config.
+    fn symbols(&self, settings: &Config) -> Symbols {
+        Symbols::new(self.ascii || settings.ascii_output)
+    }
+
+    fn reads_stdin(&self) -> bool {
+        self.file.as_deref() == Some(Path::new(STDIN_PATH))
+    }
+
+    fn mode(&self) -> Mode {
+        if self.edit {
+            Mode::Editor
+        } else if let Some(ref message) = self.message {
+            Mode::Direct(message.clone())
+        } else if self.commit_type.is_some() {
+            Mode::Fields
+        } else if let Some(ref json) = self.json_input {
+            Mode::Json(json.clone())
+        } else if let Some(ref rev) = self.revert {
+            Mode::Revert(rev.clone())
+        } else if let Some(ref rev) = self.fixup {
+            Mode::Autosquash(Autosquash::Fixup, rev.clone())
+        } else if let Some(ref rev) = self.squash {
+            Mode::Autosquash(Autosquash::Squash, rev.clone())
+        } else if self.reads_stdin() {
+            Mode::Stdin
+        } else if let Some(ref path) = self.file {
+            Mode::File(path.clone())
+        } else if self.paste {
+            Mode::Paste
+        } else if self.interactive {
+            Mode::Interactive
+        } else {
+            Mode::Editor
+        }
+    }
 }
 
 pub fn run() -> ExitCode {
     let cli = Cli::parse();
 
-    let staging = GitStagingChecker;
-    let executor = GitCommitExecutor;
     let ui = TerminalUI;
 
-    match (cli.message, cli.interactive) {
-        (Some(msg), _) => {
-            let source = DirectSource::new(msg, CompilerPipeline::new());
-            AppController::new(staging, source, ui, executor).run()
+    let settings = match &cli.config_path {
+        Some(path) => Config::from_file(path).map_err(|e| e.to_string()),
+        None => std::env::current_dir()
+            .map_err(|e| e.to_string())
+            .and_then(|cwd| Config::load(&cwd).map_err(|e| e.to_string())),
+    };
+    let settings = match settings {
+        Ok(settings) => settings,
+        Err(e) => {
+            ui.println(&format!("Error loading configuration: {}", e));
+            return ExitCode::FAILURE;
+        }
+    };
+    let config = settings.validation();
+    let wrap_width = match cli.wrap {
+        Some(0) => None,
+        Some(width) => Some(width),
+        None => settings.wrap_body,
+    };
+
+    let mode = cli.mode();
+    let (gitmoji, normalize_unicode) = (config.gitmoji, config.normalize_unicode);
+    let compiler = || {
+        CompilerPipeline::new()
+            .with_gitmoji(gitmoji)
+            .with_normalize_unicode(normalize_unicode)
+    };
+
+    if let Some(shell) = cli.completions {
+        // Best effort, like the interactive suggestions.
+        let scopes = GitHistory.recent_scopes().unwrap_or_default();
+        print!("{}", completions(shell, &config, &scopes));
+        return ExitCode::SUCCESS;
+    }
+
+    let comment_char = comment_char(&settings);
+
+    if cli.validate {
+        return validate_only(
+            &mode,
+            &config,
+            comment_char,
+            cli.symbols(&settings),
+            cli.strict,
+            cli.json,
+            cli.verbose,
+        );
+    }
+
+    if let Some(ref range) = cli.changelog {
+        return match GitHistory.messages(range) {
+            Ok(messages) => {
+                ui.println(changelog::render(&messages, &config).trim_end());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                ui.println(&format!("Error reading {}: {}", range, e));
+                ExitCode::from(EXIT_GIT_ERROR)
+            }
+        };
+    }
+
+    if let Some([name, file, rest @ ..]) = cli.hook.as_deref() {
+        return run_hook(
+            name,
+            Path::new(file),
+            rest.first().map(String::as_str),
+            &config,
+            cli.symbols(&settings),
+            wrap_width,
+            comment_char,
+        );
+    }
+
+    if mode.prompts() && unattended() {
+        ui.println(
+            "Error: no terminal to prompt on (stdin is not a TTY or COMMANDO_NONINTERACTIVE is set).\n\
+             Pass the message with -m or -F instead.",
+        );
+        return ExitCode::from(EXIT_INVALID_MESSAGE);
+    }
+
+    // When amending in a prompting mode, the prompts start from HEAD's
+    // message; with --edit, the editor starts from -m's instead.
+    let previous = if cli.edit {
+        cli.message.clone()
+    } else if cli.amend() && matches!(mode, Mode::Interactive | Mode::Editor) {
+        match GitHistory.last_message() {
+            Ok(message) => Some(message),
+            Err(e) => {
+                ui.println(&format!("Error reading the commit to amend: {}", e));
+                return ExitCode::from(EXIT_GIT_ERROR);
+            }
+        }
+    } else {
+        None
+    };
+
+    match mode {
+        Mode::Direct(msg) => {
+            if cli.verbose {
+                print_compile_stages(&msg, &compiler());
+            }
+            let source = DirectSource::new(msg.clone(), compiler(), config.clone());
+            // "add login" at a terminal: pick the type rather than fail.
+            if source.needs_type() && !unattended() {
+                let source = InteractiveSource::new(TerminalUI, config)
+                    .with_prefill(Prefill::from_raw(&msg))
+                    .with_type_only(true)
+                    .with_symbols(cli.symbols(&settings));
+                return run_with(source, &cli, &settings, wrap_width);
+            }
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::File(path) => {
+            let source = FileSource::new(path, compiler(), config).with_comment_char(comment_char);
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::Fields => {
+            let source = FieldsSource::new(
+                cli.commit_type.clone().unwrap_or_default(),
+                cli.description.clone().unwrap_or_default(),
+                config,
+            )
+            .with_scope(cli.scope.clone())
+            .with_body(cli.body.clone())
+            .with_breaking_change(cli.breaking.clone())
+            .with_refs(cli.refs.clone());
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::Json(json) => {
+            let source = JsonSource::new(json, config);
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::Revert(rev) => {
+            let (sha, subject) = match GitHistory.commit_subject(&rev) {
+                Ok(target) => target,
+                Err(e) => {
+                    ui.println(&format!("Error reading {}: {}", rev, e));
+                    return ExitCode::from(EXIT_GIT_ERROR);
+                }
+            };
+            if let Err(e) = GitRevert.stage(&sha) {
+                ui.println(&format!("Error reverting {}: {}", rev, e));
+                return ExitCode::from(EXIT_GIT_ERROR);
+            }
+            let source = RevertSource::new(sha, subject, config);
+            let code = run_with(source, &cli, &settings, wrap_width);
+            if code != ExitCode::SUCCESS {
+                ui.println("The revert is still staged; `git revert --abort` undoes it.");
+            }
+            code
+        }
+        Mode::Autosquash(kind, rev) => {
+            let subject = match GitHistory.commit_subject(&rev) {
+                Ok((_, subject)) => subject,
+                Err(e) => {
+                    ui.println(&format!("Error reading {}: {}", rev, e));
+                    return ExitCode::from(EXIT_GIT_ERROR);
+                }
+            };
+            let source = AutosquashSource::new(kind, subject, config);
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::Stdin => {
+            let source = FileSource::from_reader(std::io::stdin(), compiler(), config)
+                .with_comment_char(comment_char);
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::Paste => {
+            if std::io::stdin().is_terminal() && !cli.quiet {
+                ui.println("Paste the commit message, then press Ctrl-D:");
+            }
+            let source = PasteSource::new(std::io::stdin(), compiler(), config);
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::Interactive => {
+            let mut prefill = Prefill::default();
+            if let Some(ref message) = previous {
+                prefill = match compiler().compile(message) {
+                    Ok(ast) => Prefill::from_ast(ast, &config),
+                    Err(e) => {
+                        ui.println(&format!(
+                            "HEAD is not a conventional commit ({}); starting from its subject.",
+                            e
+                        ));
+                        Prefill::from_raw(message)
+                    }
+                };
+            }
+            // A fresh commit starts from the branch name (when enabled), else
+            // the staged files' directory. Detached or unnamed: no guess.
+            if previous.is_none() {
+                let branch = settings
+                    .infer_from_branch
+                    .then(|| GitHistory.current_branch().ok().flatten())
+                    .flatten();
+                let (branch_type, branch_scope) = branch
+                    .map(|branch| infer_from_branch(&branch, &config))
+                    .unwrap_or_default();
+                let files = GitStagingChecker.staged_files().unwrap_or_default();
+                prefill.commit_type = branch_type;
+                prefill.scope = branch_scope
+                    .or_else(|| infer_scope(&files))
+                    .filter(|scope| CommitMessage::validate_scope(scope, &config).is_ok());
+                if cli.autobody {
+                    prefill.body = body_from_changes(&files);
+                }
+            }
+            // Best effort: a repo without history just gets no suggestions.
+            let scopes = GitHistory.recent_scopes().unwrap_or_default();
+            let source = InteractiveSource::new(TerminalUI, config)
+                .with_prefill(prefill)
+                .with_scope_suggestions(scopes)
+                .with_symbols(cli.symbols(&settings));
+            run_with(source, &cli, &settings, wrap_width)
+        }
+        Mode::Editor => {
+            let template = cli
+                .template
+                .clone()
+                .or_else(|| GitConfig.get_path("commit.template"));
+            // The way out of an editor round trip that keeps failing.
+            let guided_config = config.clone();
+            let symbols = cli.symbols(&settings);
+            let guided: Guided = Box::new(move |prefill| {
+                let scopes = GitHistory.recent_scopes().unwrap_or_default();
+                InteractiveSource::new(TerminalUI, guided_config.clone())
+                    .with_prefill(prefill)
+                    .with_scope_suggestions(scopes)
+                    .with_symbols(symbols)
+                    .resolve()
+                    .map_err(|e| e.to_string())
+            });
+            let mut source = EditorSource::new(compiler(), config)
+                .with_comment_char(comment_char)
+                .with_default_editor(settings.default_editor.clone())
+                .with_template(template)
+                .with_guided(guided);
+            if let Some(message) = previous {
+                source = source.with_initial_message(message);
+            } else if cli.autobody {
+                let files = GitStagingChecker.staged_files().unwrap_or_default();
+                source = source.with_initial_body(body_from_changes(&files));
+            }
+            run_with(source, &cli, &settings, wrap_width)
+        }
+    }
+}
+
+/// The completion script for `shell`. --type completes the built-in and
+/// configured types and --scope the given scopes; both still accept
+/// anything when parsing; the values exist only in the script.
+fn completions(shell: Shell, config: &ValidationConfig, scopes: &[String]) -> String {
+    let types = CommitType::all_as_str()
+        .iter()
+        .map(|t| t.to_string())
+        .chain(config.custom_types.iter().cloned());
+    let mut command = Cli::command()
+        .mut_arg("commit_type", |arg| {
+            arg.value_parser(PossibleValuesParser::new(types))
+        })
+        .mut_arg("scope", |arg| {
+            if scopes.is_empty() {
+                arg
+            } else {
+                arg.value_parser(PossibleValuesParser::new(scopes.iter().cloned()))
+            }
+        });
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command, "commando", &mut script);
+    String::from_utf8_lossy(&script).into_owned()
+}
+
+/// `--output -` writes to stdout.
+const STDOUT_PATH: &str = "-";
+
+/// The --output file, created (truncating it) on the first write. A run
+/// that fails before writing the message leaves an existing file as it was.
+struct LazyFile {
+    path: PathBuf,
+    file: Option<std::fs::File>,
+}
+
+impl LazyFile {
+    fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            file: None,
+        }
+    }
+}
+
+impl Write for LazyFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => self
+                .file
+                .insert(std::fs::File::create(&self.path).map_err(|e| {
+                    std::io::Error::new(
+                        e.kind(),
+                        format!("cannot create {}: {}", self.path.display(), e),
+                    )
+                })?),
+        };
+        file.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.as_mut().map_or(Ok(()), Write::flush)
+    }
+}
+
+/// Set to force non-interactive behaviour even at a terminal.
+const NONINTERACTIVE_VAR: &str = "COMMANDO_NONINTERACTIVE";
+
+/// Whether nobody can answer prompts: see is_unattended.
+fn unattended() -> bool {
+    is_unattended(
+        |key| std::env::var(key).ok(),
+        std::io::stdin().is_terminal(),
+    )
+}
+
+/// No terminal on stdin, or COMMANDO_NONINTERACTIVE set to anything but
+/// empty, "0" or "false".
+fn is_unattended(env: impl Fn(&str) -> Option<String>, stdin_is_terminal: bool) -> bool {
+    let forced = env(NONINTERACTIVE_VAR).is_some_and(|value| {
+        let value = value.trim();
+        !(value.is_empty() || value == "0" || value.eq_ignore_ascii_case("false"))
+    });
+    forced || !stdin_is_terminal
+}
+
+/// git for staging and committing, the terminal for prompts, and the
+/// editor with the default rules for the message.
+impl Default
+    for AppControllerBuilder<GitStagingChecker, EditorSource, TerminalUI, GitCommitExecutor>
+{
+    fn default() -> Self {
+        AppControllerBuilder::new(
+            GitStagingChecker,
+            EditorSource::new(CompilerPipeline::new(), ValidationConfig::default()),
+            TerminalUI,
+            GitCommitExecutor::default(),
+        )
+    }
+}
+
+/// `comment_char` from the config, else git's core.commentChar, else '#'.
+fn comment_char(settings: &Config) -> char {
+    settings
+        .comment_char
+        .or_else(|| {
+            GitConfig
+                .get("core.commentChar")
+                .and_then(|v| single_char(&v))
+        })
+        .unwrap_or(DEFAULT_COMMENT_CHAR)
+}
+
+/// A core.commentChar value commando can use: one character. git's "auto"
+/// (pick one the message does not use) is not supported and means '#'.
+fn single_char(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if !c.is_whitespace() => Some(c),
+        _ => None,
+    }
+}
+
+/// clap value parser for --co-author: reject malformed entries up front.
+fn co_author(value: &str) -> Result<String, String> {
+    CommitMessage::parse_co_author(value).map_err(|e| e.to_string())
+}
+
+/// Report on the message without touching git. Prompting modes have
+/// nothing to validate up front, so they are rejected.
+fn validate_only(
+    mode: &Mode,
+    config: &ValidationConfig,
+    comment_char: char,
+    symbols: Symbols,
+    strict: bool,
+    json: bool,
+    verbose: bool,
+) -> ExitCode {
+    let raw = match mode {
+        Mode::Direct(message) => Ok(message.clone()),
+        Mode::File(path) => std::fs::read_to_string(path)
+            .map(|raw| strip_comments(&raw, comment_char))
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e)),
+        Mode::Paste => {
+            let mut raw = String::new();
+            std::io::stdin()
+                .read_to_string(&mut raw)
+                .map(|_| raw)
+                .map_err(|e| format!("Failed to read stdin: {}", e))
+        }
+        Mode::Stdin => {
+            let mut raw = String::new();
+            std::io::stdin()
+                .read_to_string(&mut raw)
+                .map(|_| strip_comments(&raw, comment_char))
+                .map_err(|e| format!("Failed to read stdin: {}", e))
+        }
+        Mode::Fields
+        | Mode::Json(_)
+        | Mode::Revert(_)
+        | Mode::Autosquash(..)
+        | Mode::Interactive
+        | Mode::Editor => Err("--validate needs -m, -F or --paste".to_string()),
+    };
+
+    let raw = match raw {
+        Ok(raw) => raw,
+        Err(e) => {
+            TerminalUI.println(&format!("Error: {}", e));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if verbose {
+        let compiler = CompilerPipeline::new()
+            .with_gitmoji(config.gitmoji)
+            .with_normalize_unicode(config.normalize_unicode);
+        print_compile_stages(raw.trim_end(), &compiler);
+    }
+    let report = ValidateOutput::new(raw.trim_end(), config, strict);
+    if json {
+        TerminalUI.println(&report.to_json());
+    } else {
+        TerminalUI.println(&report.to_text(symbols));
+    }
+
+    if report.valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_INVALID_MESSAGE)
+    }
+}
+
+/// --verbose: each compiler stage, on stderr so --json output stays clean.
+/// Tokens are shown even when the parser then rejects them.
+fn print_compile_stages(raw: &str, compiler: &CompilerPipeline) {
+    eprintln!("--- tokens ---");
+    match compiler.tokenize(raw) {
+        Ok(tokens) => {
+            for t in &tokens {
+                eprintln!("{:>3}:{:<3} {}", t.line, t.column, t.token);
+            }
         }
-        (None, true) => {
-            let source = InteractiveSource::new(TerminalUI);
-            AppController::new(staging, source, ui, executor).run()
+        Err(e) => {
+            eprintln!("lexer error: {}", e);
+            return;
         }
-        (None, false) => {
-            let source = EditorSource::new(CompilerPipeline::new());
-            AppController::new(staging, source, ui, executor).run()
+    }
+    eprintln!("--- ast ---");
+    match compiler.compile_debug(raw) {
+        Ok((_, ast)) => eprintln!("{:#?}", ast),
+        Err(e) => eprintln!("parser error: {}", e),
+    }
+    eprintln!();
+}
+
+fn run_hook(
+    name: &str,
+    file: &Path,
+    source: Option<&str>,
+    config: &ValidationConfig,
+    symbols: Symbols,
+    wrap_width: Option<usize>,
+    comment_char: char,
+) -> ExitCode {
+    if name != hook::PREPARE_COMMIT_MSG {
+        TerminalUI.println(&format!(
+            "Error: unsupported hook '{}' (only {} is available)",
+            name,
+            hook::PREPARE_COMMIT_MSG
+        ));
+        return ExitCode::FAILURE;
+    }
+
+    match hook::prepare_commit_msg(file, source, config, wrap_width, comment_char) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            TerminalUI.println(&format!("{} commando: {}", symbols.error, e));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Wire the chosen source to the production adapters and run.
+fn run_with<M>(source: M, cli: &Cli, settings: &Config, wrap_width: Option<usize>) -> ExitCode
+where
+    M: CommitMessageSource,
+{
+    let output: Option<Box<dyn Write>> = match cli.output.as_deref() {
+        None => None,
+        Some(path) if path == Path::new(STDOUT_PATH) => Some(Box::new(std::io::stdout())),
+        Some(path) => Some(Box::new(LazyFile::new(path))),
+    };
+    let streaming = cli.stream || settings.stream_output;
+    // gpg may ask for the passphrase on this terminal (pinentry-tty/curses).
+    let signing = cli.gpg_sign.is_some() || GitConfig.get_bool("commit.gpgsign") == Some(true);
+    let executor = GitCommitExecutor::default()
+        .with_amend(cli.amend())
+        .with_no_verify(cli.no_verify)
+        .with_gpg_sign(cli.gpg_sign.clone())
+        .with_streaming(streaming)
+        .with_paths(cli.paths.clone());
+    // Scripted input or no terminal: nobody is there to confirm.
+    let scripted = cli.reads_stdin() || cli.commit_type.is_some() || cli.json_input.is_some();
+    let unattended = unattended();
+    let symbols = cli.symbols(settings);
+    AppControllerBuilder::default()
+        .input(source)
+        .executor(executor)
+        .build()
+        .with_preview(!scripted || cli.preview)
+        .with_show_diff(cli.show_diff)
+        .with_confirmation(!scripted && !unattended)
+        .with_decline_unconfirmed(unattended && !settings.unattended_commit)
+        .with_confirm_default(settings.confirm_default)
+        // The spinner's frames are Braille, as likely to show up as boxes.
+        .with_spinner(!streaming && !signing && symbols != Symbols::ASCII)
+        .with_quiet(cli.quiet)
+        .with_symbols(symbols)
+        .with_amend(cli.amend())
+        .with_signoff(cli.signoff)
+        .with_co_authors(cli.co_author.clone())
+        .with_wrap_width(wrap_width)
+        .with_paths(cli.paths.clone())
+        .with_strict(cli.strict)
+        .with_output(output)
+        .run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(value: Option<&str>) -> impl Fn(&str) -> Option<String> {
+        move |key| {
+            assert_eq!(key, NONINTERACTIVE_VAR);
+            value.map(String::from)
+        }
+    }
+
+    #[test]
+    fn terminal_without_override_is_attended() {
+        assert!(!is_unattended(env(None), true));
+    }
+
+    #[test]
+    fn no_terminal_is_unattended() {
+        assert!(is_unattended(env(None), false));
+        // The override cannot bring back a terminal that isn't there.
+        assert!(is_unattended(env(Some("0")), false));
+    }
+
+    #[test]
+    fn override_forces_unattended_at_a_terminal() {
+        for value in ["1", "true", "yes"] {
+            assert!(is_unattended(env(Some(value)), true), "{}", value);
+        }
+        for value in ["", "0", "false", "FALSE"] {
+            assert!(!is_unattended(env(Some(value)), true), "{}", value);
         }
     }
+
+    #[test]
+    fn only_editor_and_interactive_modes_prompt() {
+        assert!(Mode::Editor.prompts());
+        assert!(Mode::Interactive.prompts());
+        assert!(!Mode::Direct("feat: x".into()).prompts());
+        assert!(!Mode::File(PathBuf::from("msg.txt")).prompts());
+        assert!(!Mode::Paste.prompts());
+    }
+
+    #[test]
+    fn paths_follow_the_separator() {
+        let cli =
+            Cli::try_parse_from(["commando", "-m", "fix: x", "--", "src/a.rs", "docs"]).unwrap();
+        assert_eq!(cli.paths, ["src/a.rs", "docs"]);
+        assert!(matches!(cli.mode(), Mode::Direct(_)));
+        assert!(
+            Cli::try_parse_from(["commando", "-m", "fix: x", "--validate", "--", "a"]).is_err()
+        );
+    }
+
+    #[test]
+    fn hook_takes_gits_optional_arguments() {
+        let hook = |args: &[&str]| {
+            let mut argv = vec!["commando", "--hook"];
+            argv.extend(args);
+            Cli::try_parse_from(argv).map(|cli| cli.hook.unwrap())
+        };
+        assert_eq!(
+            hook(&["prepare-commit-msg", ".git/COMMIT_EDITMSG"]).unwrap(),
+            ["prepare-commit-msg", ".git/COMMIT_EDITMSG"]
+        );
+        assert_eq!(
+            hook(&[
+                "prepare-commit-msg",
+                ".git/COMMIT_EDITMSG",
+                "commit",
+                "HEAD"
+            ])
+            .unwrap(),
+            [
+                "prepare-commit-msg",
+                ".git/COMMIT_EDITMSG",
+                "commit",
+                "HEAD"
+            ]
+        );
+        assert!(hook(&["prepare-commit-msg"]).is_err());
+    }
+
+    #[test]
+    fn autobody_is_for_the_prompting_modes() {
+        assert!(Cli::try_parse_from(["commando", "--autobody"]).is_ok());
+        assert!(Cli::try_parse_from(["commando", "-i", "--autobody"]).is_ok());
+        assert!(Cli::try_parse_from(["commando", "-m", "fix: x", "--autobody"]).is_err());
+        assert!(Cli::try_parse_from(["commando", "--amend", "--autobody"]).is_err());
+    }
+
+    #[test]
+    fn failing_output_run_leaves_the_file_untouched() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "previous\n").unwrap();
+        let path = file.path().to_str().unwrap();
+        let run = |message: &str, strict: bool| {
+            let mut argv = vec!["commando", "-m", message, "--output", path];
+            if strict {
+                argv.push("--strict");
+            }
+            let cli = Cli::try_parse_from(argv).unwrap();
+            let config = ValidationConfig::default();
+            let source = DirectSource::new(message.into(), CompilerPipeline::new(), config);
+            run_with(source, &cli, &Config::default(), None)
+        };
+
+        assert_eq!(
+            run("feature: x", false),
+            ExitCode::from(EXIT_INVALID_MESSAGE)
+        );
+        assert_eq!(
+            run("feat: added search", true),
+            ExitCode::from(EXIT_INVALID_MESSAGE)
+        );
+        assert_eq!(std::fs::read_to_string(file.path()).unwrap(), "previous\n");
+
+        assert_eq!(run("feat: add search", true), ExitCode::SUCCESS);
+        assert_eq!(
+            std::fs::read_to_string(file.path()).unwrap(),
+            "feat: add search\n"
+        );
+    }
+
+    #[test]
+    fn output_combines_with_any_source_but_not_with_committing_flags() {
+        let cli = Cli::try_parse_from(["commando", "-F", "msg.txt", "--output", "-"]).unwrap();
+        assert_eq!(cli.output.as_deref(), Some(Path::new(STDOUT_PATH)));
+        assert!(Cli::try_parse_from(["commando", "-i", "--output", "out.txt"]).is_ok());
+        for flag in ["--amend", "--validate"] {
+            assert!(
+                Cli::try_parse_from(["commando", "-m", "fix: x", "--output", "-", flag]).is_err(),
+                "{}",
+                flag
+            );
+        }
+    }
+
+    #[test]
+    fn completions_mention_the_type_flag_for_every_shell() {
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = completions(shell, &config, &["api".into()]);
+            assert!(!script.is_empty(), "{}", shell);
+            assert!(
+                script.contains("--type") || script.contains("-l type"),
+                "{}",
+                shell
+            );
+        }
+        let bash = completions(Shell::Bash, &config, &["api".into()]);
+        assert!(bash.contains("feat fix docs"), "{}", bash);
+        assert!(bash.contains("deps"));
+        assert!(bash.contains("api"));
+    }
+
+    #[test]
+    fn comment_char_is_a_single_character() {
+        assert_eq!(single_char(";"), Some(';'));
+        assert_eq!(single_char("auto"), None);
+        assert_eq!(single_char(" "), None);
+        assert_eq!(single_char(""), None);
+    }
 }
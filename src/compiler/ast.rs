@@ -3,7 +3,7 @@
 /// The parser produces this. It holds raw strings.
 /// CommitMessage::try_from(ast) enforces domain invariants:
 ///   - commit_type string must be a known CommitType variant
-///   - description must be ≤ 72 chars
+///   - description must fit the configured max length (default 72)
 ///   - scope must be alphanumeric + hyphens/underscores
 ///
 /// Keeping CommitAst clean of domain types means compiler/ never
@@ -22,7 +22,8 @@ pub struct CommitAst {
 /// a known variant. The parser's job is just to extract it.
 #[derive(Debug, Clone, PartialEq)]
 pub struct HeaderNode {
-    pub commit_type: String, // raw — "feat", "fix", "unknown-type", etc.
+    pub gitmoji: Option<String>, // raw — "✨", ":sparkles:", any emoji
+    pub commit_type: String,     // raw — "feat", "fix", "unknown-type", etc.
     pub scope: Option<String>,
     pub breaking: bool, // was '!' present in the header?
     pub description: String,
@@ -39,8 +40,13 @@ pub struct BodyNode {
 /// Examples: { key: "BREAKING CHANGE", value: "old API removed" }
 ///           { key: "Refs", value: "#123" }
 ///           { key: "Co-authored-by", value: "Name <email>" }
+///
+/// For issue-reference keys (Refs, Closes, Fixes) the value is also split
+/// into individual references: "#12, #34" → ["#12", "#34"]. Empty for
+/// every other key.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FooterNode {
     pub key: String,
     pub value: String,
+    pub issue_refs: Vec<String>,
 }
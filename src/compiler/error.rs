@@ -1,19 +1,65 @@
 use crate::compiler::token::Token;
 
+/// The lexer's message for a header with no `type:` prefix.
+pub(crate) const MISSING_COLON: &str = "Missing ':' in header";
+
 /// Errors produced by the compiler pipeline.
 ///
 /// Lexer errors: malformed input structure (missing ':', unclosed parens, empty header).
 /// Parse errors: token stream doesn't match the grammar.
 ///
+/// Both carry the 1-based line and column in the compiled text.
+///
 /// Neither error type carries DomainError — semantic validation
-/// (valid type string, description length, scope charset) is the domain's job.
+/// (valid type string, description length, scope charset, repeated
+/// footers, issue-ref format, '!' without BREAKING CHANGE) is the domain's
+/// job, in CommitMessage::from_ast_all; there is no second pass here.
 #[derive(Debug, Clone, PartialEq)]
 pub enum CompileError {
     /// Error during lexical analysis.
-    Lex(String),
+    Lex {
+        message: String,
+        line: usize,
+        column: usize,
+    },
 
     /// Error during parsing (token stream doesn't match grammar).
-    Parse(ParseError),
+    Parse {
+        error: ParseError,
+        line: usize,
+        column: usize,
+    },
+}
+
+impl CompileError {
+    /// Line in the compiled input where the error was detected.
+    pub fn line(&self) -> usize {
+        self.position().0
+    }
+
+    /// (line, column) where the error was detected.
+    pub fn position(&self) -> (usize, usize) {
+        match self {
+            CompileError::Lex { line, column, .. } | CompileError::Parse { line, column, .. } => {
+                (*line, *column)
+            }
+        }
+    }
+
+    /// The error without its position, for callers that point at the line
+    /// themselves (e.g. the editor annotating the offending line in place).
+    pub fn message(&self) -> String {
+        match self {
+            CompileError::Lex { message, .. } => message.clone(),
+            CompileError::Parse { error, .. } => error.to_string(),
+        }
+    }
+
+    /// True when the header has no `type:` prefix at all (e.g. "add login"),
+    /// as opposed to a prefix that is present but malformed.
+    pub fn is_missing_type(&self) -> bool {
+        matches!(self, CompileError::Lex { message, line: 1, .. } if message == MISSING_COLON)
+    }
 }
 
 /// Specific parse failures.
@@ -24,13 +70,38 @@ pub enum ParseError {
 
     /// Footer line exists but is syntactically invalid.
     InvalidFooter(String),
+
+    /// `BREAKING CHANGE:` (or `BREAKING-CHANGE:`) with nothing after it.
+    /// Reported on its own so the breaking change is not mistaken for
+    /// just another bad footer.
+    EmptyBreakingChange(String),
+
+    /// An issue-reference footer holds something that isn't `#<digits>`,
+    /// `GH-<digits>` or a URL.
+    InvalidIssueReference { key: String, reference: String },
 }
 
 impl std::fmt::Display for CompileError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CompileError::Lex(msg) => write!(f, "Lexer error: {}", msg),
-            CompileError::Parse(err) => write!(f, "Parse error: {}", err),
+            CompileError::Lex {
+                message,
+                line,
+                column,
+            } => write!(
+                f,
+                "Lexer error at line {}, column {}: {}",
+                line, column, message
+            ),
+            CompileError::Parse {
+                error,
+                line,
+                column,
+            } => write!(
+                f,
+                "Parse error at line {}, column {}: {}",
+                line, column, error
+            ),
         }
     }
 }
@@ -44,6 +115,16 @@ impl std::fmt::Display for ParseError {
             ParseError::InvalidFooter(raw) => {
                 write!(f, "invalid footer syntax: '{}'", raw)
             }
+            ParseError::EmptyBreakingChange(key) => write!(
+                f,
+                "{} footer has no description — say what breaks and how to adapt",
+                key
+            ),
+            ParseError::InvalidIssueReference { key, reference } => write!(
+                f,
+                "invalid issue reference '{}' in {} footer (use #123, GH-123 or a URL)",
+                reference, key
+            ),
         }
     }
 }
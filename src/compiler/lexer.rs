@@ -1,5 +1,6 @@
-use super::error::CompileError;
-use super::token::Token;
+use super::error::{CompileError, MISSING_COLON};
+use super::token::{SpannedToken, Token};
+use crate::domain::is_footer_key;
 
 /// Lexer — converts raw commit message text into a token stream.
 ///
@@ -9,26 +10,42 @@ use super::token::Token;
 #[derive(Debug)]
 pub struct Lexer {
     input: String,
+    gitmoji: bool,
 }
 
 impl Lexer {
+    /// Line endings are normalized up front: "\r\n" and lone "\r" both
+    /// become "\n", so messages authored on Windows lex identically.
     pub fn new(input: impl Into<String>) -> Self {
         Self {
-            input: input.into(),
+            input: input.into().replace("\r\n", "\n").replace('\r', "\n"),
+            gitmoji: false,
         }
     }
 
-    pub fn tokenize(&self) -> Result<Vec<Token>, CompileError> {
+    /// Recognize a leading emoji or `:shortcode:` before the type.
+    pub fn with_gitmoji(mut self, gitmoji: bool) -> Self {
+        self.gitmoji = gitmoji;
+        self
+    }
+
+    /// Tokens carry the 1-based line and column where they start, so later
+    /// stages can report errors against the text the user actually wrote.
+    pub fn tokenize(&self) -> Result<Vec<SpannedToken>, CompileError> {
         let mut tokens = Vec::new();
         let lines: Vec<&str> = self.input.lines().collect();
 
         if lines.is_empty() {
-            return Err(CompileError::Lex("Empty input".to_string()));
+            return Err(lex_error("Empty input", 1, 1));
         }
 
         let header_tokens = self.tokenize_header(lines[0])?;
         tokens.extend(header_tokens);
-        tokens.push(Token::Newline);
+        tokens.push(SpannedToken::new(
+            Token::Newline,
+            1,
+            column(lines[0], lines[0].len()),
+        ));
 
         let mut i = 1;
         while i < lines.len() && lines[i].trim().is_empty() {
@@ -36,7 +53,7 @@ impl Lexer {
         }
 
         if i >= lines.len() {
-            tokens.push(Token::Eof);
+            tokens.push(SpannedToken::new(Token::Eof, lines.len(), 1));
             return Ok(tokens);
         }
 
@@ -44,114 +61,204 @@ impl Lexer {
         let (body_lines, footer_lines) = self.split_body_and_footer(&remaining);
 
         if !body_lines.is_empty() {
-            let trimmed = body_lines.join("\n").trim().to_string();
+            // Whitespace-only lines are paragraph breaks — normalize them to
+            // empty so the blank line between paragraphs is preserved cleanly.
+            let trimmed = body_lines
+                .iter()
+                .map(|l| if l.trim().is_empty() { "" } else { *l })
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim()
+                .to_string();
             if !trimmed.is_empty() {
-                tokens.push(Token::Body(trimmed));
-                tokens.push(Token::Newline);
+                let first = body_lines[0];
+                let line = i + 1;
+                tokens.push(SpannedToken::new(
+                    Token::Body(trimmed),
+                    line,
+                    column(first, indent(first)),
+                ));
+                tokens.push(SpannedToken::new(Token::Newline, line, 1));
             }
         }
 
-        for line in footer_lines {
+        let footer_offset = i + body_lines.len();
+        for (n, line) in footer_lines.iter().enumerate() {
             let trimmed = line.trim();
             if !trimmed.is_empty() {
-                tokens.push(Token::Footer(trimmed.to_string()));
-                tokens.push(Token::Newline);
+                let line_no = footer_offset + n + 1;
+                tokens.push(SpannedToken::new(
+                    Token::Footer(trimmed.to_string()),
+                    line_no,
+                    column(line, indent(line)),
+                ));
+                tokens.push(SpannedToken::new(Token::Newline, line_no, 1));
             }
         }
 
-        tokens.push(Token::Eof);
+        tokens.push(SpannedToken::new(Token::Eof, lines.len(), 1));
         Ok(tokens)
     }
 
-    fn tokenize_header(&self, header: &str) -> Result<Vec<Token>, CompileError> {
+    fn tokenize_header(&self, header: &str) -> Result<Vec<SpannedToken>, CompileError> {
         let mut tokens = Vec::new();
-        let header = header.trim();
+        let mut start = indent(header);
+        let trimmed = header.trim_end();
 
-        if header.is_empty() {
-            return Err(CompileError::Lex("Empty header line".to_string()));
+        if trimmed.trim_start().is_empty() {
+            return Err(lex_error("Empty header line", 1, 1));
         }
 
-        let colon_pos = header
-            .find(':')
-            .ok_or_else(|| CompileError::Lex("Missing ':' in header".to_string()))?;
+        if self.gitmoji
+            && let Some((emoji, len)) = leading_gitmoji(&trimmed[start..])
+        {
+            tokens.push(SpannedToken::new(
+                Token::Gitmoji(emoji.to_string()),
+                1,
+                column(header, start),
+            ));
+            start += len;
+        }
 
-        let before_colon = &header[..colon_pos];
-        let after_colon = header[colon_pos + 1..].trim();
+        let colon_pos = header_colon(&trimmed[start..])
+            .map(|p| p + start)
+            .ok_or_else(|| lex_error(MISSING_COLON, 1, column(header, trimmed.len())))?;
 
-        if after_colon.is_empty() {
-            return Err(CompileError::Lex("Empty description".to_string()));
-        }
+        let after_colon = &trimmed[colon_pos + 1..];
+        let description_at = colon_pos + 1 + indent(after_colon);
+        let description = after_colon.trim();
 
-        let (commit_type, scope, breaking) = self.parse_type_scope_breaking(before_colon)?;
+        if description.is_empty() {
+            return Err(lex_error(
+                "Empty description",
+                1,
+                column(header, colon_pos + 1),
+            ));
+        }
 
-        tokens.push(Token::Type(commit_type));
-        if let Some(s) = scope {
-            tokens.push(Token::Scope(s));
+        let HeaderPrefix {
+            commit_type: (commit_type, type_at),
+            scope,
+            breaking,
+        } = self.parse_type_scope_breaking(header, start, colon_pos)?;
+
+        tokens.push(SpannedToken::new(
+            Token::Type(commit_type),
+            1,
+            column(header, type_at),
+        ));
+        if let Some((s, at)) = scope {
+            tokens.push(SpannedToken::new(Token::Scope(s), 1, column(header, at)));
         }
-        if breaking {
-            tokens.push(Token::Breaking);
+        if let Some(at) = breaking {
+            tokens.push(SpannedToken::new(Token::Breaking, 1, column(header, at)));
         }
-        tokens.push(Token::Description(after_colon.to_string()));
+        tokens.push(SpannedToken::new(
+            Token::Description(description.to_string()),
+            1,
+            column(header, description_at),
+        ));
 
         Ok(tokens)
     }
 
+    /// Parse `header[from..to]` — everything before the ':' — into type,
+    /// optional scope and the '!' marker, each paired with its byte offset.
     fn parse_type_scope_breaking(
         &self,
-        part: &str,
-    ) -> Result<(String, Option<String>, bool), CompileError> {
-        let part = part.trim();
+        header: &str,
+        from: usize,
+        to: usize,
+    ) -> Result<HeaderPrefix, CompileError> {
+        let err = |message: &str, at: usize| lex_error(message, 1, column(header, at));
+        let part = header[from..to].trim_end();
 
         // Use strip_suffix to satisfy clippy::manual_strip
         let (part, breaking) = if let Some(stripped) = part.strip_suffix('!') {
-            (stripped, true)
+            (stripped, Some(from + stripped.len()))
         } else {
-            (part, false)
+            (part, None)
         };
 
         if let Some(open) = part.find('(') {
             let close = part
                 .rfind(')')
-                .ok_or_else(|| CompileError::Lex("Unclosed '(' in scope".to_string()))?;
+                .ok_or_else(|| err("Unclosed '(' in scope", from + open))?;
 
             if close < open {
-                return Err(CompileError::Lex("Malformed scope parentheses".to_string()));
+                return Err(err("Malformed scope parentheses", from + close));
             }
 
             let commit_type = part[..open].trim().to_string();
-            let scope = part[open + 1..close].trim().to_string();
+            let scope_raw = &part[open + 1..close];
+            let scope = scope_raw.trim().to_string();
             let after_close = part[close + 1..].trim();
 
             if commit_type.is_empty() {
-                return Err(CompileError::Lex("Empty commit type".to_string()));
+                return Err(err("Empty commit type", from));
             }
             if scope.is_empty() {
-                return Err(CompileError::Lex("Empty scope".to_string()));
+                return Err(err("Empty scope", from + open + 1));
             }
             if !after_close.is_empty() {
-                return Err(CompileError::Lex(
-                    "Unexpected content after scope ')'".to_string(),
+                return Err(err(
+                    "Unexpected content after scope ')'",
+                    from + close + 1 + indent(&part[close + 1..]),
                 ));
             }
 
-            Ok((commit_type, Some(scope), breaking))
+            let scope_at = from + open + 1 + indent(scope_raw);
+            Ok(HeaderPrefix {
+                commit_type: (commit_type, from),
+                scope: Some((scope, scope_at)),
+                breaking,
+            })
         } else {
             let commit_type = part.trim().to_string();
             if commit_type.is_empty() {
-                return Err(CompileError::Lex("Empty commit type".to_string()));
+                return Err(err("Empty commit type", from));
             }
-            Ok((commit_type, None, breaking))
+            Ok(HeaderPrefix {
+                commit_type: (commit_type, from),
+                scope: None,
+                breaking,
+            })
         }
     }
 
+    /// Split the lines after the header into body and footer sections.
+    ///
+    /// The footer section is the trailing run of footer lines: it starts at
+    /// the earliest footer line after which every non-blank line is also a
+    /// footer. A footer-looking line mid-body ("Note: ...") followed by more
+    /// prose stays in the body, so paragraphs survive intact. If no such run
+    /// exists but the last paragraph opens like a footer, it is still treated
+    /// as one so malformed trailers surface as parse errors, not body text.
     fn split_body_and_footer<'a>(&self, lines: &'a [&'a str]) -> (Vec<&'a str>, Vec<&'a str>) {
-        let mut footer_start = None;
-        for (i, line) in lines.iter().enumerate() {
-            if self.is_footer_line(line) {
-                footer_start = Some(i);
-                break;
-            }
-        }
+        let end = lines
+            .iter()
+            .rposition(|l| !l.trim().is_empty())
+            .map_or(0, |p| p + 1);
+        let lines = &lines[..end];
+
+        let footer_start = (0..lines.len())
+            .find(|&i| {
+                self.is_footer_line(lines[i])
+                    && lines[i..]
+                        .iter()
+                        .all(|l| l.trim().is_empty() || self.is_footer_line(l))
+            })
+            .or_else(|| {
+                let last_paragraph = lines
+                    .iter()
+                    .rposition(|l| l.trim().is_empty())
+                    .map_or(0, |p| p + 1);
+                lines
+                    .get(last_paragraph)
+                    .filter(|l| self.is_footer_line(l))
+                    .map(|_| last_paragraph)
+            });
+
         match footer_start {
             Some(idx) => (lines[..idx].to_vec(), lines[idx..].to_vec()),
             None => (lines.to_vec(), Vec::new()),
@@ -174,19 +281,76 @@ impl Lexer {
             return false;
         };
         let key = key.trim();
-        if key.is_empty() {
-            return false;
-        }
-        if key.contains(' ') {
-            key.chars()
-                .all(|c| c.is_uppercase() || c == ' ' || c == '-')
-        } else {
-            key.chars()
-                .next()
-                .map(|c| c.is_uppercase())
-                .unwrap_or(false)
+        !key.is_empty() && is_footer_key(key)
+    }
+}
+
+/// A gitmoji at the start of `s` and the byte length it spans including the
+/// whitespace after it. Either a `:shortcode:` or a run of non-ASCII symbols;
+/// it must be followed by whitespace so "✨feat" is left as a (bad) type.
+fn leading_gitmoji(s: &str) -> Option<(&str, usize)> {
+    let end = s.find(char::is_whitespace)?;
+    let candidate = &s[..end];
+
+    let is_shortcode = candidate
+        .strip_prefix(':')
+        .and_then(|c| c.strip_suffix(':'))
+        .is_some_and(|name| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_+-".contains(c))
+        });
+    let is_emoji = candidate
+        .chars()
+        .all(|c| !c.is_ascii() && !c.is_alphanumeric());
+
+    (is_shortcode || is_emoji).then(|| (candidate, end + indent(&s[end..])))
+}
+
+/// Byte offset of the ':' that ends the header prefix: the first one outside
+/// the scope's parentheses, so `feat(api:v2): x` keeps `api:v2` whole. With
+/// an unclosed '(' it is simply the first ':', and the scope parser reports
+/// the missing ')'.
+fn header_colon(header: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in header.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => return Some(i),
+            _ => {}
         }
     }
+    header.find(':')
+}
+
+/// A value paired with the byte offset in the header where it starts.
+type At<T> = (T, usize);
+
+/// Everything before the header's ':' — type, optional scope, '!' marker.
+struct HeaderPrefix {
+    commit_type: At<String>,
+    scope: Option<At<String>>,
+    breaking: Option<usize>,
+}
+
+/// Byte length of the leading whitespace of `line`.
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// 1-based character column of byte offset `at` within `line`.
+fn column(line: &str, at: usize) -> usize {
+    line[..at].chars().count() + 1
+}
+
+fn lex_error(message: &str, line: usize, column: usize) -> CompileError {
+    CompileError::Lex {
+        message: message.to_string(),
+        line,
+        column,
+    }
 }
 
 #[cfg(test)]
@@ -194,9 +358,20 @@ mod tests {
     use super::*;
 
     fn lex(input: &str) -> Vec<Token> {
+        spanned(input).into_iter().map(|t| t.token).collect()
+    }
+
+    fn spanned(input: &str) -> Vec<SpannedToken> {
         Lexer::new(input).tokenize().expect("tokenize failed")
     }
 
+    fn error_position(input: &str) -> (usize, usize) {
+        match Lexer::new(input).tokenize().unwrap_err() {
+            CompileError::Lex { line, column, .. } => (line, column),
+            e => panic!("expected a lexer error, got {:?}", e),
+        }
+    }
+
     #[test]
     fn minimal_commit() {
         let tokens = lex("feat: add login");
@@ -214,6 +389,25 @@ mod tests {
         assert_eq!(tokens[2], Token::Description("correct token expiry".into()));
     }
 
+    #[test]
+    fn colon_inside_scope_stays_in_scope() {
+        let tokens = lex("feat(api:v2): x");
+        assert_eq!(tokens[0], Token::Type("feat".into()));
+        assert_eq!(tokens[1], Token::Scope("api:v2".into()));
+        assert_eq!(tokens[2], Token::Description("x".into()));
+    }
+
+    #[test]
+    fn colon_in_description_stays_in_description() {
+        let tokens = lex("feat: a:b");
+        assert_eq!(tokens[0], Token::Type("feat".into()));
+        assert_eq!(tokens[1], Token::Description("a:b".into()));
+
+        let tokens = lex("fix(parser)!: handle f(a:b)");
+        assert_eq!(tokens[1], Token::Scope("parser".into()));
+        assert_eq!(tokens[3], Token::Description("handle f(a:b)".into()));
+    }
+
     #[test]
     fn commit_with_breaking_marker() {
         let tokens = lex("feat(api)!: remove v1 endpoints");
@@ -248,10 +442,38 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn body_preserves_paragraph_breaks() {
+        let tokens = lex("feat: x\n\nPara one.\n\nPara two.");
+        assert!(tokens.contains(&Token::Body("Para one.\n\nPara two.".into())));
+    }
+
+    #[test]
+    fn footer_like_line_mid_body_stays_in_body() {
+        let input = "feat: x\n\nPara one.\n\nNote: this matters.\n\nPara two.\n\nRefs: #1";
+        let tokens = lex(input);
+        assert!(tokens.contains(&Token::Body(
+            "Para one.\n\nNote: this matters.\n\nPara two.".into()
+        )));
+        assert!(tokens.contains(&Token::Footer("Refs: #1".into())));
+    }
+
+    #[test]
+    fn whitespace_only_line_is_a_paragraph_break() {
+        let tokens = lex("feat: x\n\nPara one.\n   \nPara two.");
+        assert!(tokens.contains(&Token::Body("Para one.\n\nPara two.".into())));
+    }
+
+    #[test]
+    fn malformed_trailing_footer_block_is_still_footer() {
+        let tokens = lex("feat: x\n\nBody.\n\nBREAKING CHANGE: a\n  continued");
+        assert!(tokens.contains(&Token::Footer("continued".into())));
+    }
+
     #[test]
     fn missing_colon_is_error() {
         let result = Lexer::new("feat add login").tokenize();
-        assert!(matches!(result.unwrap_err(), CompileError::Lex(_)));
+        assert!(matches!(result.unwrap_err(), CompileError::Lex { .. }));
     }
 
     #[test]
@@ -264,6 +486,63 @@ mod tests {
         assert!(Lexer::new("feat(auth: fix thing").tokenize().is_err());
     }
 
+    // ── spans ─────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn unclosed_scope_reports_line_one_at_the_paren() {
+        assert_eq!(error_position("feat(auth: fix thing"), (1, 5));
+    }
+
+    #[test]
+    fn missing_colon_reports_end_of_header() {
+        assert_eq!(error_position("feat add login"), (1, 15));
+    }
+
+    #[test]
+    fn empty_scope_column_counts_characters_not_bytes() {
+        assert_eq!(error_position("  ✨feat(): x"), (1, 9));
+    }
+
+    #[test]
+    fn header_tokens_carry_columns() {
+        let tokens = spanned("fix(auth)!: correct expiry");
+        let columns: Vec<(usize, usize)> = tokens[..4].iter().map(|t| (t.line, t.column)).collect();
+        assert_eq!(columns, vec![(1, 1), (1, 5), (1, 10), (1, 13)]);
+    }
+
+    #[test]
+    fn body_and_footer_tokens_carry_their_lines() {
+        let tokens = spanned("feat: x\n\n\nBody line.\nMore.\n\nRefs: #1\n  Closes #2");
+        let line_of = |token: &Token| tokens.iter().find(|t| &t.token == token).unwrap().line;
+        assert_eq!(line_of(&Token::Body("Body line.\nMore.".into())), 4);
+        assert_eq!(line_of(&Token::Footer("Refs: #1".into())), 7);
+        let closes = tokens
+            .iter()
+            .find(|t| t.token == Token::Footer("Closes #2".into()))
+            .unwrap();
+        assert_eq!((closes.line, closes.column), (8, 3));
+    }
+
+    #[test]
+    fn crlf_breaking_change_footer() {
+        let tokens = lex("feat: x\r\n\r\nBREAKING CHANGE: y\r\n");
+        assert_eq!(tokens[1], Token::Description("x".into()));
+        assert!(tokens.contains(&Token::Footer("BREAKING CHANGE: y".into())));
+    }
+
+    #[test]
+    fn crlf_body_and_footer() {
+        let tokens = lex("fix: patch\r\n\r\nLine one.\r\nLine two.\r\n\r\nRefs: #42\r\n");
+        assert!(tokens.contains(&Token::Body("Line one.\nLine two.".into())));
+        assert!(tokens.contains(&Token::Footer("Refs: #42".into())));
+    }
+
+    #[test]
+    fn lone_cr_is_treated_as_newline() {
+        let tokens = lex("fix: patch\r\rRefs: #42");
+        assert!(tokens.contains(&Token::Footer("Refs: #42".into())));
+    }
+
     #[test]
     fn unknown_type_is_not_a_lex_error() {
         let tokens = lex("unknown-type: do something");
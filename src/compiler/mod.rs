@@ -1,13 +1,16 @@
 mod ast;
 mod error;
 mod lexer;
+mod normalize;
 mod parser;
 mod token;
 
 pub use ast::CommitAst;
 pub use error::CompileError;
+pub use token::{SpannedToken, Token};
 
 use lexer::Lexer;
+use normalize::normalize_header;
 use parser::Parser;
 
 /// CompilerPipeline — the public API for the compiler module.
@@ -20,11 +23,28 @@ use parser::Parser;
 ///
 /// CommitAst then flows to CommitMessage::try_from(ast) in the domain layer.
 #[derive(Debug, Default)]
-pub struct CompilerPipeline;
+pub struct CompilerPipeline {
+    gitmoji: bool,
+    normalize_unicode: bool,
+}
 
 impl CompilerPipeline {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Accept a leading gitmoji ("✨ feat: ..." or ":sparkles: feat: ...")
+    /// and keep it on the header.
+    pub fn with_gitmoji(mut self, gitmoji: bool) -> Self {
+        self.gitmoji = gitmoji;
+        self
+    }
+
+    /// Turn curly quotes, no-break spaces and similar pasted-in characters
+    /// in the header into plain ASCII before lexing. The body is untouched.
+    pub fn with_normalize_unicode(mut self, normalize: bool) -> Self {
+        self.normalize_unicode = normalize;
+        self
     }
 
     /// Compile a raw commit message string into a CommitAst.
@@ -38,8 +58,27 @@ impl CompilerPipeline {
     /// Does NOT return an error for invalid commit types, long descriptions,
     /// or bad scope characters — those are DomainErrors, not CompileErrors.
     pub fn compile(&self, input: &str) -> Result<CommitAst, CompileError> {
-        let tokens = Lexer::new(input).tokenize()?;
-        Parser::new(tokens).parse()
+        Parser::new(self.tokenize(input)?).parse()
+    }
+
+    /// The lexer stage on its own — for debugging a message that won't parse.
+    pub fn tokenize(&self, input: &str) -> Result<Vec<SpannedToken>, CompileError> {
+        let input = if self.normalize_unicode {
+            normalize_header(input)
+        } else {
+            input.into()
+        };
+        Lexer::new(input).with_gitmoji(self.gitmoji).tokenize()
+    }
+
+    /// compile() that also returns the tokens the parser consumed.
+    pub fn compile_debug(
+        &self,
+        input: &str,
+    ) -> Result<(Vec<SpannedToken>, CommitAst), CompileError> {
+        let tokens = self.tokenize(input)?;
+        let ast = Parser::new(tokens.clone()).parse()?;
+        Ok((tokens, ast))
     }
 }
 
@@ -54,6 +93,113 @@ mod tests {
         assert_eq!(ast.header.description, "add login");
     }
 
+    #[test]
+    fn compile_debug_exposes_tokens_and_ast() {
+        let (tokens, ast) = CompilerPipeline::new()
+            .compile_debug("feat(api)!: drop v1")
+            .unwrap();
+        let tokens: Vec<Token> = tokens.into_iter().map(|t| t.token).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Type("feat".into()),
+                Token::Scope("api".into()),
+                Token::Breaking,
+                Token::Description("drop v1".into()),
+                Token::Newline,
+                Token::Eof,
+            ]
+        );
+        assert_eq!(
+            ast,
+            CompilerPipeline::new()
+                .compile("feat(api)!: drop v1")
+                .unwrap()
+        );
+        assert_eq!(ast.header.scope, Some("api".into()));
+        assert!(ast.header.breaking);
+    }
+
+    #[test]
+    fn tokenize_reports_lexer_errors() {
+        assert!(CompilerPipeline::new().tokenize("feat add x").is_err());
+        let tokens = CompilerPipeline::new().tokenize("feat: x").unwrap();
+        assert_eq!(tokens[0].token, Token::Type("feat".into()));
+    }
+
+    #[test]
+    fn normalizes_pasted_header_when_enabled() {
+        let input = "fix(ui):\u{00A0}escape \u{201C}quoted\u{201D} names\n\nA pasted note \u{2014} kept as written.";
+        let ast = CompilerPipeline::new()
+            .with_normalize_unicode(true)
+            .compile(input)
+            .unwrap();
+        assert_eq!(ast.header.scope, Some("ui".into()));
+        assert_eq!(ast.header.description, "escape \"quoted\" names");
+        assert_eq!(
+            ast.body.unwrap().content,
+            "A pasted note \u{2014} kept as written."
+        );
+
+        let ast = CompilerPipeline::new().compile(input).unwrap();
+        assert_eq!(
+            ast.header.description,
+            "escape \u{201C}quoted\u{201D} names"
+        );
+    }
+
+    #[test]
+    fn normalization_fixes_full_width_colon() {
+        let input = "feat\u{FF1A} add x";
+        assert!(CompilerPipeline::new().compile(input).is_err());
+        let ast = CompilerPipeline::new()
+            .with_normalize_unicode(true)
+            .compile(input)
+            .unwrap();
+        assert_eq!(ast.header.commit_type, "feat");
+    }
+
+    #[test]
+    fn compiles_leading_gitmoji_when_enabled() {
+        let pipeline = CompilerPipeline::new().with_gitmoji(true);
+        let ast = pipeline.compile("✨ feat: add x").unwrap();
+        assert_eq!(ast.header.gitmoji, Some("✨".into()));
+        assert_eq!(ast.header.commit_type, "feat");
+        assert_eq!(ast.header.description, "add x");
+
+        let ast = pipeline.compile(":sparkles: feat(api)!: add x").unwrap();
+        assert_eq!(ast.header.gitmoji, Some(":sparkles:".into()));
+        assert_eq!(ast.header.scope, Some("api".into()));
+        assert!(ast.header.breaking);
+    }
+
+    #[test]
+    fn unrecognized_emoji_is_kept_as_is() {
+        let ast = CompilerPipeline::new()
+            .with_gitmoji(true)
+            .compile("🦄 fix: x")
+            .unwrap();
+        assert_eq!(ast.header.gitmoji, Some("🦄".into()));
+        assert_eq!(ast.header.commit_type, "fix");
+    }
+
+    #[test]
+    fn gitmoji_is_part_of_the_type_when_disabled() {
+        let ast = CompilerPipeline::new().compile("✨ feat: add x").unwrap();
+        assert_eq!(ast.header.gitmoji, None);
+        assert_eq!(ast.header.commit_type, "✨ feat");
+    }
+
+    #[test]
+    fn header_without_gitmoji_is_unchanged_when_enabled() {
+        let ast = CompilerPipeline::new()
+            .with_gitmoji(true)
+            .compile("feat: add x")
+            .unwrap();
+        assert_eq!(ast.header.gitmoji, None);
+        assert_eq!(ast.header.commit_type, "feat");
+    }
+
     #[test]
     fn compiles_full() {
         let input = "feat(auth)!: migrate to OAuth\n\n\
@@ -68,10 +214,17 @@ mod tests {
         assert_eq!(ast.footers.len(), 2);
     }
 
+    #[test]
+    fn multi_paragraph_body_round_trips() {
+        let input = "feat: x\n\nPara one.\n\nPara two.";
+        let ast = CompilerPipeline::new().compile(input).unwrap();
+        assert_eq!(ast.body.unwrap().content, "Para one.\n\nPara two.");
+    }
+
     #[test]
     fn compile_error_on_missing_colon() {
         let result = CompilerPipeline::new().compile("feat add something");
-        assert!(matches!(result, Err(CompileError::Lex(_))));
+        assert!(matches!(result, Err(CompileError::Lex { line: 1, .. })));
     }
 
     #[test]
@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+/// Replace typographic characters in the header line with their ASCII
+/// equivalents — what chat apps and word processors substitute when a
+/// message is pasted through them.
+///
+/// Only the first line is touched: the header is what the lexer scans for
+/// ':' and '(' and what ends up in one-line logs. The body and footers may
+/// legitimately be non-ASCII ("naïve", "—", "„quotes“") and pass through
+/// unchanged.
+pub fn normalize_header(input: &str) -> Cow<'_, str> {
+    let end = input.find('\n').unwrap_or(input.len());
+    let (header, rest) = input.split_at(end);
+    if !header.chars().any(|c| ascii_equivalent(c).is_some()) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = String::with_capacity(input.len());
+    for c in header.chars() {
+        match ascii_equivalent(c) {
+            Some(ascii) => normalized.push_str(ascii),
+            None => normalized.push(c),
+        }
+    }
+    normalized.push_str(rest);
+    Cow::Owned(normalized)
+}
+
+fn ascii_equivalent(c: char) -> Option<&'static str> {
+    match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{2032}' => Some("'"),
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{2033}' => Some("\""),
+        // No-break, narrow no-break, thin and figure spaces.
+        '\u{00A0}' | '\u{202F}' | '\u{2009}' | '\u{2007}' => Some(" "),
+        '\u{2010}' | '\u{2011}' | '\u{2013}' | '\u{2014}' => Some("-"),
+        '\u{2026}' => Some("..."),
+        // Full-width punctuation from CJK input methods.
+        '\u{FF1A}' => Some(":"),
+        '\u{FF08}' => Some("("),
+        '\u{FF09}' => Some(")"),
+        '\u{FF01}' => Some("!"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_typographic_header_characters() {
+        assert_eq!(
+            normalize_header("fix(ui):\u{00A0}handle \u{201C}quoted\u{201D} names\u{2026}"),
+            "fix(ui): handle \"quoted\" names..."
+        );
+        assert_eq!(
+            normalize_header("feat\u{FF08}api\u{FF09}\u{FF1A} add x"),
+            "feat(api): add x"
+        );
+    }
+
+    #[test]
+    fn leaves_body_and_footers_alone() {
+        let input = "fix: it\u{2019}s fixed\n\nWorks \u{2014} mostly, for \u{201C}naïve\u{201D} input.\n\nRefs: #1";
+        assert_eq!(
+            normalize_header(input),
+            "fix: it's fixed\n\nWorks \u{2014} mostly, for \u{201C}naïve\u{201D} input.\n\nRefs: #1"
+        );
+    }
+
+    #[test]
+    fn ascii_input_is_borrowed() {
+        assert!(matches!(
+            normalize_header("feat: add x\n\nbody — with dash"),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::compiler::ast::{BodyNode, CommitAst, FooterNode, HeaderNode};
 use crate::compiler::error::{CompileError, ParseError};
-use crate::compiler::token::Token;
+use crate::compiler::token::{SpannedToken, Token};
+use crate::domain::{is_issue_key, is_issue_reference, split_issue_refs};
 
 /// Parser — converts a token stream into a CommitAst.
 ///
@@ -11,12 +12,12 @@ use crate::compiler::token::Token;
 /// Specifically: the parser does NOT call CommitType::from_str().
 /// commit_type stays as a raw String in HeaderNode. The domain validates it.
 pub struct Parser {
-    tokens: Vec<Token>,
+    tokens: Vec<SpannedToken>,
     pos: usize,
 }
 
 impl Parser {
-    pub fn new(tokens: Vec<Token>) -> Self {
+    pub fn new(tokens: Vec<SpannedToken>) -> Self {
         Self { tokens, pos: 0 }
     }
 
@@ -37,6 +38,13 @@ impl Parser {
     }
 
     fn parse_header(&mut self) -> Result<HeaderNode, CompileError> {
+        let gitmoji = if let Token::Gitmoji(s) = self.peek() {
+            self.advance();
+            Some(s)
+        } else {
+            None
+        };
+
         // commit_type: raw string — NOT validated against CommitType enum here
         let commit_type = match self.next() {
             Token::Type(s) => s,
@@ -63,6 +71,7 @@ impl Parser {
         };
 
         Ok(HeaderNode {
+            gitmoji,
             commit_type,
             scope,
             breaking,
@@ -84,12 +93,35 @@ impl Parser {
         let mut footers = Vec::new();
 
         while let Token::Footer(raw) = self.peek() {
+            let at = self.pos;
             self.advance();
 
-            let (key, value) = split_footer(&raw)
-                .ok_or_else(|| CompileError::Parse(ParseError::InvalidFooter(raw.clone())))?;
+            if let Some(key) = empty_breaking_change(&raw) {
+                return Err(self.error_at(at, ParseError::EmptyBreakingChange(key.to_string())));
+            }
 
-            footers.push(FooterNode { key, value });
+            let (key, value) = split_footer(&raw)
+                .ok_or_else(|| self.error_at(at, ParseError::InvalidFooter(raw.clone())))?;
+
+            let issue_refs = if is_issue_key(&key) {
+                checked_issue_refs(&value).map_err(|reference| {
+                    self.error_at(
+                        at,
+                        ParseError::InvalidIssueReference {
+                            key: key.clone(),
+                            reference,
+                        },
+                    )
+                })?
+            } else {
+                Vec::new()
+            };
+
+            footers.push(FooterNode {
+                key,
+                value,
+                issue_refs,
+            });
             self.consume_newlines();
         }
 
@@ -97,7 +129,10 @@ impl Parser {
     }
 
     fn peek(&self) -> Token {
-        self.tokens.get(self.pos).cloned().unwrap_or(Token::Eof)
+        self.tokens
+            .get(self.pos)
+            .map(|t| t.token.clone())
+            .unwrap_or(Token::Eof)
     }
 
     fn next(&mut self) -> Token {
@@ -120,11 +155,28 @@ impl Parser {
         }
     }
 
+    /// Error for the token just consumed by next().
     fn unexpected(&self, expected: &str, found: Token) -> CompileError {
-        CompileError::Parse(ParseError::UnexpectedToken {
-            expected: expected.to_string(),
-            found,
-        })
+        self.error_at(
+            self.pos.saturating_sub(1),
+            ParseError::UnexpectedToken {
+                expected: expected.to_string(),
+                found,
+            },
+        )
+    }
+
+    fn error_at(&self, index: usize, error: ParseError) -> CompileError {
+        let (line, column) = self
+            .tokens
+            .get(index)
+            .or(self.tokens.last())
+            .map_or((1, 1), |t| (t.line, t.column));
+        CompileError::Parse {
+            error,
+            line,
+            column,
+        }
     }
 }
 
@@ -152,6 +204,25 @@ fn split_footer(raw: &str) -> Option<(String, String)> {
     None
 }
 
+/// The key of a breaking-change footer with no value, e.g. "BREAKING CHANGE:".
+fn empty_breaking_change(raw: &str) -> Option<&str> {
+    let key = raw.trim().strip_suffix(':')?.trim_end();
+    (key == "BREAKING CHANGE" || key == "BREAKING-CHANGE").then_some(key)
+}
+
+/// Split "#12, #34 GH-5" into its references, or return the first bad one.
+fn checked_issue_refs(value: &str) -> Result<Vec<String>, String> {
+    split_issue_refs(value)
+        .map(|r| {
+            if is_issue_reference(r) {
+                Ok(r.to_string())
+            } else {
+                Err(r.to_string())
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +287,50 @@ mod tests {
         assert_eq!(bc.unwrap().value, "all v1 endpoints removed");
     }
 
+    #[test]
+    fn parses_crlf_breaking_change_footer() {
+        let ast = parse("feat: x\r\n\r\nBREAKING CHANGE: y\r\n");
+        assert_eq!(ast.header.description, "x");
+        assert_eq!(ast.footers.len(), 1);
+        assert_eq!(ast.footers[0].key, "BREAKING CHANGE");
+        assert_eq!(ast.footers[0].value, "y");
+    }
+
+    fn parse_err(input: &str) -> CompileError {
+        Parser::new(Lexer::new(input).tokenize().unwrap())
+            .parse()
+            .unwrap_err()
+    }
+
+    #[test]
+    fn breaking_change_with_trailing_spaces_is_an_error() {
+        assert_eq!(
+            parse_err("feat: x\n\nBREAKING CHANGE:   "),
+            CompileError::Parse {
+                error: ParseError::EmptyBreakingChange("BREAKING CHANGE".into()),
+                line: 3,
+                column: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn breaking_change_with_nothing_after_colon_is_an_error() {
+        let err = parse_err("feat: x\n\nBody.\n\nRefs: #1\nBREAKING-CHANGE:");
+        assert_eq!(
+            err,
+            CompileError::Parse {
+                error: ParseError::EmptyBreakingChange("BREAKING-CHANGE".into()),
+                line: 6,
+                column: 1,
+            }
+        );
+        assert_eq!(
+            err.message(),
+            "BREAKING-CHANGE footer has no description — say what breaks and how to adapt"
+        );
+    }
+
     #[test]
     fn unknown_type_parses_successfully() {
         // Parser does not validate type — domain does
@@ -223,6 +338,103 @@ mod tests {
         assert_eq!(ast.header.commit_type, "unknown");
     }
 
+    #[test]
+    fn malformed_footer_reports_its_line() {
+        let tokens = Lexer::new("feat: x\n\nBody.\n\nBREAKING CHANGE: a\n  continued")
+            .tokenize()
+            .unwrap();
+        let err = Parser::new(tokens).parse().unwrap_err();
+        assert_eq!(
+            err,
+            CompileError::Parse {
+                error: ParseError::InvalidFooter("continued".into()),
+                line: 6,
+                column: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn unexpected_token_reports_its_position() {
+        let tokens = vec![
+            SpannedToken::new(Token::Type("feat".into()), 1, 1),
+            SpannedToken::new(Token::Newline, 1, 5),
+        ];
+        let err = Parser::new(tokens).parse().unwrap_err();
+        assert_eq!(
+            (err.line(), err.to_string().contains("column 5")),
+            (1, true)
+        );
+    }
+
+    // ── issue references ──────────────────────────────────────────────────────
+
+    #[test]
+    fn splits_comma_separated_refs() {
+        let ast = parse("fix: x\n\nRefs: #12, #34");
+        assert_eq!(ast.footers[0].value, "#12, #34");
+        assert_eq!(ast.footers[0].issue_refs, vec!["#12", "#34"]);
+    }
+
+    #[test]
+    fn accepts_mix_of_number_and_github_url() {
+        let ast = parse("fix: x\n\nCloses: #12 https://github.com/tgenericx/commando/issues/7");
+        assert_eq!(
+            ast.footers[0].issue_refs,
+            vec!["#12", "https://github.com/tgenericx/commando/issues/7"]
+        );
+    }
+
+    #[test]
+    fn hash_separator_and_gh_prefix() {
+        let ast = parse("fix: x\n\nFixes #3\nRefs: GH-9");
+        assert_eq!(ast.footers[0].issue_refs, vec!["#3"]);
+        assert_eq!(ast.footers[1].issue_refs, vec!["GH-9"]);
+    }
+
+    #[test]
+    fn accepts_cross_repository_refs() {
+        let ast = parse("fix: x\n\nRefs: tgenericx/commando#12");
+        assert_eq!(ast.footers[0].issue_refs, vec!["tgenericx/commando#12"]);
+    }
+
+    #[test]
+    fn other_footers_have_no_issue_refs() {
+        let ast = parse("fix: x\n\nReviewed-by: Jane #1");
+        assert!(ast.footers[0].issue_refs.is_empty());
+    }
+
+    #[test]
+    fn invalid_issue_ref_is_error_on_its_line() {
+        let tokens = Lexer::new("fix: x\n\nRefs: #12\nCloses: #12, soon")
+            .tokenize()
+            .unwrap();
+        let err = Parser::new(tokens).parse().unwrap_err();
+        assert_eq!(
+            err,
+            CompileError::Parse {
+                error: ParseError::InvalidIssueReference {
+                    key: "Closes".into(),
+                    reference: "soon".into(),
+                },
+                line: 4,
+                column: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn issue_ref_formats() {
+        assert!(is_issue_reference("#1"));
+        assert!(is_issue_reference("GH-42"));
+        assert!(is_issue_reference("https://example.com/x"));
+        assert!(!is_issue_reference("#"));
+        assert!(!is_issue_reference("#12a"));
+        assert!(!is_issue_reference("gh-1"));
+        assert!(!is_issue_reference("https://"));
+        assert!(!is_issue_reference("12"));
+    }
+
     #[test]
     fn parses_full_commit() {
         let input = "feat(auth)!: migrate to OAuth\n\n\
@@ -6,6 +6,9 @@ use std::fmt;
 /// Represent structure only — not semantic correctness.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Token {
+    /// A leading gitmoji, e.g. "✨" or ":sparkles:" (only when enabled).
+    Gitmoji(String),
+
     /// Commit type string, e.g. "feat", "fix". Not yet validated.
     Type(String),
 
@@ -31,9 +34,28 @@ pub enum Token {
     Eof,
 }
 
+/// A token plus the 1-based line and column where it starts in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SpannedToken {
+    pub fn new(token: Token, line: usize, column: usize) -> Self {
+        Self {
+            token,
+            line,
+            column,
+        }
+    }
+}
+
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Token::Gitmoji(s) => write!(f, "Gitmoji({})", s),
             Token::Type(s) => write!(f, "Type({})", s),
             Token::Scope(s) => write!(f, "Scope({})", s),
             Token::Breaking => write!(f, "Breaking"),
@@ -46,10 +68,11 @@ impl fmt::Display for Token {
                 }
             }
             Token::Body(s) => {
-                if s.len() > 30 {
-                    write!(f, "Body({}...)", &s[..30])
+                let preview: String = s.chars().take(30).collect();
+                if s.chars().count() > 30 {
+                    write!(f, "Body({}...)", preview)
                 } else {
-                    write!(f, "Body({})", s)
+                    write!(f, "Body({})", preview)
                 }
             }
             Token::Footer(s) => write!(f, "Footer({})", s),
@@ -83,6 +106,16 @@ mod tests {
         assert_eq!(format!("{}", Token::Breaking), "Breaking");
     }
 
+    #[test]
+    fn display_truncates_a_non_ascii_body_by_character() {
+        // Byte 30 falls inside the 'é'.
+        let token = Token::Body(format!("{}é more text here", "A".repeat(29)));
+        assert_eq!(
+            format!("{}", token),
+            format!("Body({}é...)", "A".repeat(29))
+        );
+    }
+
     #[test]
     fn display_truncates_long_description() {
         let token = Token::Description("a".repeat(50));
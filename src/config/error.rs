@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file exists but could not be read.
+    Read { path: PathBuf, reason: String },
+
+    /// The config file is not valid TOML or has unexpected keys/values.
+    Parse { path: PathBuf, reason: String },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Read { path, reason } => {
+                write!(f, "Failed to read {}: {}", path.display(), reason)
+            }
+            ConfigError::Parse { path, reason } => {
+                write!(f, "Invalid config in {}: {}", path.display(), reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,642 @@
+//! Project configuration — `.commando.toml`
+//!
+//! Looked up once at startup by the composition root (cli.rs). The search
+//! starts in the current directory and walks up through its parents,
+//! stopping at the git root (the first directory containing `.git`).
+//! The nearest file wins; no merging across levels. No file means defaults.
+//!
+//! ```toml
+//! max_description_length = 50
+//! min_description_length = 10       # off unless set; "fix: bug" is too short
+//! allowed_types = ["deps", "release"]
+//! require_scope = ["feat", "fix"]   # or true for every type
+//! body_forbidden_types = ["chore", "style"]  # header-only commits
+//! allow_path_scopes = true          # accept scopes like packages/api
+//! scope_case = "kebab"              # "lower" (default), "kebab" or "any"
+//! forbidden_scopes = ["misc", "stuff"]
+//! reject_trailing_period = true     # "add feature." is an error
+//! gitmoji = true                    # "✨ feat: ..." headers
+//! normalize_unicode = true          # curly quotes etc. in the header → ASCII
+//! group_footers = true              # Refs/Closes, then Co-authored-by/Signed-off-by
+//! header_format = "{type}[/{scope}]: {description}"  # render headers differently
+//! max_header_length = 120           # warn past this; false disables (default 100)
+//! strip_whitespace = false          # keep trailing spaces and body tabs (default: strip)
+//! single_footers = ["Refs", "Reviewed-on"]  # may appear once; default ["Refs"]
+//! wrap_body = 80          # column, or false to keep bodies as typed
+//! default_editor = "nano"
+//! comment_char = ";"      # template comment lines (default: git's core.commentChar, else '#')
+//! strict_issue_refs = true          # Refs/Closes/Fixes: #1, GH-1, org/repo#1 or issue URL
+//! strict_type_case = true           # reject "Feat: x" instead of lowercasing it
+//! breaking_requires_footer = true   # "feat!: x" also needs a BREAKING CHANGE footer
+//! max_body_line_length = 100  # off unless set
+//! exempt_unbreakable_lines = true  # lone tokens and URLs may exceed it
+//! max_footers = 20        # reject more trailers than this (default 50)
+//! max_body_bytes = 65536  # reject a larger body (default 1 MiB)
+//! infer_from_branch = true          # -i on feat/api-oauth starts from feat(api)
+//! stream_output = true    # show git/hook output live while committing
+//! confirm_default = true  # Enter at "Proceed with commit?" means yes
+//! unattended_commit = false  # without a terminal (CI), check but never commit
+//! ascii_output = true     # [OK]/[X]/[!] instead of ✓/✗/⚠ (same as --ascii)
+//! ```
+
+mod error;
+
+pub use error::ConfigError;
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::app::DEFAULT_WRAP_WIDTH;
+use crate::domain::{
+    DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_DESCRIPTION_LENGTH, DEFAULT_MAX_FOOTERS,
+    DEFAULT_MAX_HEADER_LENGTH, HeaderFormat, ScopeCase, ScopeRule, ValidationConfig,
+};
+
+pub const CONFIG_FILE_NAME: &str = ".commando.toml";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Maximum number of characters in the header description.
+    pub max_description_length: usize,
+
+    /// Minimum description length; unset means unchecked.
+    pub min_description_length: Option<usize>,
+
+    /// Commit types accepted on top of the built-ins.
+    pub allowed_types: Vec<String>,
+
+    /// Commit types that must carry a scope.
+    #[serde(deserialize_with = "require_scope")]
+    pub require_scope: ScopeRule,
+
+    /// Commit types that may not have a body.
+    pub body_forbidden_types: Vec<String>,
+
+    /// Letter case of scopes: "lower", "kebab" or "any".
+    #[serde(deserialize_with = "scope_case")]
+    pub scope_case: ScopeCase,
+
+    /// Accept path-like scopes such as `packages/api`.
+    pub allow_path_scopes: bool,
+
+    /// Scopes that are never accepted (case-insensitive).
+    pub forbidden_scopes: Vec<String>,
+
+    /// Reject descriptions that end with a period.
+    pub reject_trailing_period: bool,
+
+    /// Accept and keep a leading gitmoji; interactive mode adds the type's.
+    pub gitmoji: bool,
+
+    /// Replace pasted typographic characters in the header with ASCII.
+    pub normalize_unicode: bool,
+
+    /// Render footers grouped by kind instead of as written.
+    pub group_footers: bool,
+
+    /// Header template with {type}, {scope}, {breaking}, {description};
+    /// `[...]` segments are dropped when a placeholder in them is empty.
+    #[serde(deserialize_with = "header_format")]
+    pub header_format: HeaderFormat,
+
+    /// Warn when the whole header line is longer; None disables the warning.
+    #[serde(deserialize_with = "max_header_length")]
+    pub max_header_length: Option<usize>,
+
+    /// Drop trailing whitespace and expand leading body tabs when rendering.
+    pub strip_whitespace: bool,
+
+    /// Footer keys that may appear only once.
+    pub single_footers: Vec<String>,
+
+    /// Body wrap column; None leaves the body as typed.
+    #[serde(deserialize_with = "wrap_body")]
+    pub wrap_body: Option<usize>,
+
+    /// Editor used when GIT_EDITOR, VISUAL and EDITOR are all unset.
+    pub default_editor: Option<String>,
+
+    /// Marks comment lines in the editor and -F files; unset defers to
+    /// git's core.commentChar.
+    pub comment_char: Option<char>,
+
+    /// Reject issue references other than #1, GH-1, org/repo#1 or a
+    /// GitHub issue URL.
+    pub strict_issue_refs: bool,
+
+    /// Require a BREAKING CHANGE footer whenever the header has '!'.
+    pub breaking_requires_footer: bool,
+
+    /// Reject types not typed in lowercase instead of lowercasing them.
+    pub strict_type_case: bool,
+
+    /// Maximum characters per body line; unset means unchecked.
+    pub max_body_line_length: Option<usize>,
+
+    /// Allow single-token and URL lines past max_body_line_length.
+    pub exempt_unbreakable_lines: bool,
+
+    /// Most footers a message may carry.
+    pub max_footers: usize,
+
+    /// Largest body accepted, in bytes.
+    pub max_body_bytes: usize,
+
+    /// Seed the interactive type and scope from the current branch name.
+    pub infer_from_branch: bool,
+
+    /// Show `git commit` output (including hooks) live instead of buffering it.
+    pub stream_output: bool,
+
+    /// Answer for an empty reply to "Proceed with commit?".
+    pub confirm_default: bool,
+
+    /// Without a terminal (or with COMMANDO_NONINTERACTIVE=1), commit
+    /// after the preview; false stops there instead.
+    pub unattended_commit: bool,
+
+    /// ASCII status marks instead of ✓/✗/⚠, for terminals and logs that
+    /// mangle them.
+    pub ascii_output: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_description_length: DEFAULT_MAX_DESCRIPTION_LENGTH,
+            min_description_length: None,
+            allowed_types: Vec::new(),
+            require_scope: ScopeRule::Optional,
+            body_forbidden_types: Vec::new(),
+            scope_case: ScopeCase::Lower,
+            allow_path_scopes: false,
+            forbidden_scopes: Vec::new(),
+            reject_trailing_period: false,
+            gitmoji: false,
+            normalize_unicode: false,
+            group_footers: false,
+            header_format: HeaderFormat::default(),
+            max_header_length: Some(DEFAULT_MAX_HEADER_LENGTH),
+            strip_whitespace: true,
+            single_footers: vec!["Refs".to_string()],
+            wrap_body: Some(DEFAULT_WRAP_WIDTH),
+            default_editor: None,
+            comment_char: None,
+            strict_issue_refs: false,
+            breaking_requires_footer: false,
+            strict_type_case: false,
+            max_body_line_length: None,
+            exempt_unbreakable_lines: true,
+            max_footers: DEFAULT_MAX_FOOTERS,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            infer_from_branch: false,
+            stream_output: false,
+            confirm_default: false,
+            unattended_commit: true,
+            ascii_output: false,
+        }
+    }
+}
+
+/// `wrap_body` accepts a column number, `true` (default column) or `false`.
+fn wrap_body<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum WrapBody {
+        Enabled(bool),
+        Column(usize),
+    }
+
+    Ok(match WrapBody::deserialize(deserializer)? {
+        WrapBody::Enabled(true) => Some(DEFAULT_WRAP_WIDTH),
+        WrapBody::Enabled(false) | WrapBody::Column(0) => None,
+        WrapBody::Column(width) => Some(width),
+    })
+}
+
+/// `max_header_length` accepts a length, or `false` (or 0) to turn the
+/// warning off.
+fn max_header_length<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MaxHeaderLength {
+        Enabled(bool),
+        Length(usize),
+    }
+
+    Ok(match MaxHeaderLength::deserialize(deserializer)? {
+        MaxHeaderLength::Enabled(true) => Some(DEFAULT_MAX_HEADER_LENGTH),
+        MaxHeaderLength::Enabled(false) | MaxHeaderLength::Length(0) => None,
+        MaxHeaderLength::Length(max) => Some(max),
+    })
+}
+
+/// `require_scope` accepts `true`/`false` or a list of commit types.
+fn require_scope<'de, D>(deserializer: D) -> Result<ScopeRule, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RequireScope {
+        All(bool),
+        Types(Vec<String>),
+    }
+
+    Ok(match RequireScope::deserialize(deserializer)? {
+        RequireScope::All(true) => ScopeRule::Always,
+        RequireScope::All(false) => ScopeRule::Optional,
+        RequireScope::Types(types) => ScopeRule::For(types),
+    })
+}
+
+/// `scope_case` is one of "lower", "kebab" or "any".
+fn scope_case<'de, D>(deserializer: D) -> Result<ScopeCase, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Case {
+        Lower,
+        Kebab,
+        Any,
+    }
+
+    Ok(match Case::deserialize(deserializer)? {
+        Case::Lower => ScopeCase::Lower,
+        Case::Kebab => ScopeCase::Kebab,
+        Case::Any => ScopeCase::Any,
+    })
+}
+
+/// `header_format` is a template string, checked when the file is loaded.
+fn header_format<'de, D>(deserializer: D) -> Result<HeaderFormat, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let template = String::deserialize(deserializer)?;
+    HeaderFormat::parse(&template).map_err(serde::de::Error::custom)
+}
+
+/// toml's message, prefixed with the line and key it points at.
+///
+/// For an invalid value the message alone ("invalid value: integer `-5`,
+/// expected usize") does not say which setting was wrong.
+fn describe(error: &toml::de::Error, contents: &str) -> String {
+    let Some(span) = error.span() else {
+        return error.message().to_string();
+    };
+    let start = span.start.min(contents.len());
+    let line = contents[..start].matches('\n').count() + 1;
+    let key = contents
+        .lines()
+        .nth(line - 1)
+        .and_then(|text| text.split_once('='))
+        .map(|(key, _)| key.trim())
+        .filter(|key| !key.is_empty());
+    match key {
+        Some(key) => format!("line {}, `{}`: {}", line, key, error.message()),
+        None => format!("line {}: {}", line, error.message()),
+    }
+}
+
+impl Config {
+    /// Find and load the nearest config file above `start`, or defaults.
+    pub fn load(start: &Path) -> Result<Self, ConfigError> {
+        match Self::discover(start) {
+            Some(path) => Self::from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Walk up from `start` to the git root, returning the first config file.
+    ///
+    /// Outside a repository the walk continues to the filesystem root.
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        for dir in start.ancestors() {
+            let candidate = dir.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+            if dir.join(".git").exists() {
+                return None;
+            }
+        }
+        None
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| ConfigError::Read {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })?;
+        toml::from_str(&contents).map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            reason: describe(&e, &contents),
+        })
+    }
+
+    /// The subset of settings enforced by CommitMessage.
+    pub fn validation(&self) -> ValidationConfig {
+        ValidationConfig {
+            max_description_length: self.max_description_length,
+            min_description_length: self.min_description_length,
+            custom_types: self.allowed_types.clone(),
+            require_scope: self.require_scope.clone(),
+            body_forbidden_types: self.body_forbidden_types.clone(),
+            scope_case: self.scope_case,
+            allow_path_scopes: self.allow_path_scopes,
+            forbidden_scopes: self.forbidden_scopes.clone(),
+            reject_trailing_period: self.reject_trailing_period,
+            gitmoji: self.gitmoji,
+            normalize_unicode: self.normalize_unicode,
+            group_footers: self.group_footers,
+            header_format: self.header_format.clone(),
+            max_header_length: self.max_header_length,
+            strip_whitespace: self.strip_whitespace,
+            single_footers: self.single_footers.clone(),
+            strict_issue_refs: self.strict_issue_refs,
+            breaking_requires_footer: self.breaking_requires_footer,
+            strict_type_case: self.strict_type_case,
+            max_body_line_length: self.max_body_line_length,
+            exempt_unbreakable_lines: self.exempt_unbreakable_lines,
+            max_footers: self.max_footers,
+            max_body_bytes: self.max_body_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// repo/.git, repo/sub/deeper — the shape every discovery test starts from.
+    fn repo() -> TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("repo/.git")).unwrap();
+        fs::create_dir_all(root.path().join("repo/sub/deeper")).unwrap();
+        root
+    }
+
+    fn write(path: &Path, contents: &str) {
+        fs::write(path, contents).unwrap();
+    }
+
+    // ── discovery ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn finds_config_in_start_directory() {
+        let root = repo();
+        let repo = root.path().join("repo");
+        write(&repo.join(CONFIG_FILE_NAME), "");
+        assert_eq!(Config::discover(&repo), Some(repo.join(CONFIG_FILE_NAME)));
+    }
+
+    #[test]
+    fn walks_up_to_git_root() {
+        let root = repo();
+        let repo = root.path().join("repo");
+        write(&repo.join(CONFIG_FILE_NAME), "");
+        assert_eq!(
+            Config::discover(&repo.join("sub/deeper")),
+            Some(repo.join(CONFIG_FILE_NAME))
+        );
+    }
+
+    #[test]
+    fn nearest_config_wins() {
+        let root = repo();
+        let repo = root.path().join("repo");
+        write(&repo.join(CONFIG_FILE_NAME), "");
+        write(&repo.join("sub").join(CONFIG_FILE_NAME), "");
+        assert_eq!(
+            Config::discover(&repo.join("sub/deeper")),
+            Some(repo.join("sub").join(CONFIG_FILE_NAME))
+        );
+    }
+
+    #[test]
+    fn stops_at_git_root() {
+        let root = repo();
+        write(&root.path().join(CONFIG_FILE_NAME), "");
+        assert_eq!(Config::discover(&root.path().join("repo/sub")), None);
+    }
+
+    #[test]
+    fn missing_file_falls_back_to_defaults() {
+        let root = repo();
+        let config = Config::load(&root.path().join("repo/sub")).unwrap();
+        assert_eq!(config, Config::default());
+    }
+
+    // ── parsing ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn loads_all_settings() {
+        let root = repo();
+        let repo = root.path().join("repo");
+        write(
+            &repo.join(CONFIG_FILE_NAME),
+            r#"
+max_description_length = 50
+min_description_length = 10
+allowed_types = ["deps"]
+require_scope = ["feat", "fix"]
+body_forbidden_types = ["chore"]
+scope_case = "kebab"
+allow_path_scopes = true
+forbidden_scopes = ["misc"]
+reject_trailing_period = true
+gitmoji = true
+normalize_unicode = true
+group_footers = true
+header_format = "{type}[/{scope}]: {description}"
+max_header_length = 120
+strip_whitespace = false
+single_footers = ["Refs", "Reviewed-on"]
+wrap_body = 80
+default_editor = "nano"
+comment_char = ";"
+strict_issue_refs = true
+strict_type_case = true
+breaking_requires_footer = true
+max_body_line_length = 100
+exempt_unbreakable_lines = false
+max_footers = 20
+max_body_bytes = 65536
+infer_from_branch = true
+stream_output = true
+confirm_default = true
+unattended_commit = false
+ascii_output = true
+"#,
+        );
+        let config = Config::load(&repo.join("sub")).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                max_description_length: 50,
+                min_description_length: Some(10),
+                allowed_types: vec!["deps".into()],
+                require_scope: ScopeRule::For(vec!["feat".into(), "fix".into()]),
+                body_forbidden_types: vec!["chore".into()],
+                scope_case: ScopeCase::Kebab,
+                allow_path_scopes: true,
+                forbidden_scopes: vec!["misc".into()],
+                reject_trailing_period: true,
+                gitmoji: true,
+                normalize_unicode: true,
+                group_footers: true,
+                header_format: HeaderFormat::parse("{type}[/{scope}]: {description}").unwrap(),
+                max_header_length: Some(120),
+                strip_whitespace: false,
+                single_footers: vec!["Refs".into(), "Reviewed-on".into()],
+                wrap_body: Some(80),
+                default_editor: Some("nano".into()),
+                comment_char: Some(';'),
+                strict_issue_refs: true,
+                breaking_requires_footer: true,
+                strict_type_case: true,
+                max_body_line_length: Some(100),
+                exempt_unbreakable_lines: false,
+                max_footers: 20,
+                max_body_bytes: 65536,
+                infer_from_branch: true,
+                stream_output: true,
+                confirm_default: true,
+                unattended_commit: false,
+                ascii_output: true,
+            }
+        );
+    }
+
+    #[test]
+    fn partial_file_keeps_other_defaults() {
+        let config: Config = toml::from_str("require_scope = true").unwrap();
+        assert_eq!(config.require_scope, ScopeRule::Always);
+        assert_eq!(config.max_description_length, 72);
+        assert_eq!(config.wrap_body, Some(72));
+    }
+
+    #[test]
+    fn wrap_body_accepts_booleans() {
+        let off: Config = toml::from_str("wrap_body = false").unwrap();
+        let on: Config = toml::from_str("wrap_body = true").unwrap();
+        assert_eq!(off.wrap_body, None);
+        assert_eq!(on.wrap_body, Some(72));
+    }
+
+    #[test]
+    fn max_header_length_can_be_turned_off() {
+        let off: Config = toml::from_str("max_header_length = false").unwrap();
+        let zero: Config = toml::from_str("max_header_length = 0").unwrap();
+        assert_eq!(off.max_header_length, None);
+        assert_eq!(zero.max_header_length, None);
+        assert_eq!(Config::default().max_header_length, Some(100));
+    }
+
+    #[test]
+    fn scope_case_rejects_unknown_policies() {
+        let any: Config = toml::from_str("scope_case = \"any\"").unwrap();
+        assert_eq!(any.scope_case, ScopeCase::Any);
+        assert!(toml::from_str::<Config>("scope_case = \"upper\"").is_err());
+    }
+
+    #[test]
+    fn header_format_is_checked_on_load() {
+        let err = toml::from_str::<Config>("header_format = \"{type}: {summary}\"").unwrap_err();
+        assert!(err.message().contains("unknown placeholder '{summary}'"));
+    }
+
+    #[test]
+    fn unknown_key_is_a_parse_error() {
+        let root = repo();
+        let path = root.path().join("repo").join(CONFIG_FILE_NAME);
+        write(&path, "max_length = 50");
+        let err = Config::from_file(&path).unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { .. }));
+        assert!(err.to_string().contains(CONFIG_FILE_NAME));
+        assert!(err.to_string().contains("line 1, `max_length`"), "{}", err);
+    }
+
+    #[test]
+    fn invalid_value_names_the_key() {
+        let root = repo();
+        let path = root.path().join("repo").join(CONFIG_FILE_NAME);
+        write(&path, "gitmoji = true\nmax_description_length = -5\n");
+        let err = Config::from_file(&path).unwrap_err().to_string();
+        assert!(err.contains("line 2, `max_description_length`"), "{}", err);
+        assert!(err.contains("-5"), "{}", err);
+    }
+
+    #[test]
+    fn explicit_file_need_not_be_discoverable() {
+        let root = repo();
+        let path = root.path().join("team-commando.toml");
+        write(&path, "max_description_length = 50");
+        let config = Config::from_file(&path).unwrap();
+        assert_eq!(config.max_description_length, 50);
+    }
+
+    #[test]
+    fn missing_file_is_a_read_error() {
+        let root = repo();
+        let err = Config::from_file(&root.path().join("nope.toml")).unwrap_err();
+        assert!(matches!(err, ConfigError::Read { .. }));
+        assert!(err.to_string().contains("nope.toml"));
+    }
+
+    #[test]
+    fn validation_maps_domain_settings() {
+        let config = Config {
+            max_description_length: 40,
+            min_description_length: Some(10),
+            allowed_types: vec!["deps".into()],
+            require_scope: ScopeRule::Always,
+            body_forbidden_types: vec!["style".into()],
+            allow_path_scopes: true,
+            max_body_line_length: Some(100),
+            max_footers: 20,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.validation(),
+            ValidationConfig {
+                max_description_length: 40,
+                min_description_length: Some(10),
+                custom_types: vec!["deps".into()],
+                require_scope: ScopeRule::Always,
+                body_forbidden_types: vec!["style".into()],
+                scope_case: ScopeCase::Lower,
+                allow_path_scopes: true,
+                forbidden_scopes: vec![],
+                reject_trailing_period: false,
+                gitmoji: false,
+                normalize_unicode: false,
+                group_footers: false,
+                header_format: HeaderFormat::default(),
+                max_header_length: Some(100),
+                strip_whitespace: true,
+                single_footers: vec!["Refs".into()],
+                strict_issue_refs: false,
+                breaking_requires_footer: false,
+                strict_type_case: false,
+                max_body_line_length: Some(100),
+                exempt_unbreakable_lines: true,
+                max_footers: 20,
+                max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            }
+        );
+    }
+}
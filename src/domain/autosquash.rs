@@ -0,0 +1,53 @@
+/// Autosquash markers — git's convention for commits that
+/// `git rebase -i --autosquash` folds into an earlier one.
+///
+/// The subject is the marker followed by the target's subject:
+/// `fixup! feat(api): add search`. Such commits are not conventional
+/// commits and are never validated as one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Autosquash {
+    /// Fold into the target, discarding this commit's message.
+    Fixup,
+    /// Fold into the target, combining both messages.
+    Squash,
+}
+
+impl Autosquash {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Autosquash::Fixup => "fixup",
+            Autosquash::Squash => "squash",
+        }
+    }
+
+    /// `fixup! <target subject>`. A target that is itself a fixup keeps
+    /// its marker, as `git commit --fixup` does.
+    pub fn subject(&self, target: &str) -> String {
+        format!("{}! {}", self.as_str(), target.trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefixes_the_target_subject() {
+        assert_eq!(
+            Autosquash::Fixup.subject("feat(api): add search"),
+            "fixup! feat(api): add search"
+        );
+        assert_eq!(
+            Autosquash::Squash.subject("  Add search endpoint\n"),
+            "squash! Add search endpoint"
+        );
+    }
+
+    #[test]
+    fn keeps_an_existing_marker() {
+        assert_eq!(
+            Autosquash::Fixup.subject("fixup! fix: typo"),
+            "fixup! fixup! fix: typo"
+        );
+    }
+}
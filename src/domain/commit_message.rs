@@ -1,17 +1,40 @@
 /// Commit Message Domain Model
+use crate::compiler::CompilerPipeline;
+use crate::domain::autosquash::Autosquash;
 use crate::domain::commit_type::CommitType;
 use crate::domain::error::DomainError;
+use crate::domain::header_format::{HeaderFields, HeaderFormat};
+use crate::domain::mood;
+use crate::domain::report::ValidationReport;
+use crate::domain::validation::ValidationConfig;
+use crate::error::CommandoError;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CommitMessage {
+    /// Leading emoji or :shortcode:, rendered before the type.
+    gitmoji: Option<String>,
     commit_type: CommitType,
     scope: Option<String>,
     description: String,
     body: Option<String>,
     breaking_change: Option<String>,
+    /// Render the header with '!'. Always set when breaking_change is; also
+    /// set on its own for a header marked '!' without a BREAKING CHANGE footer.
+    breaking: bool,
     /// All footers except BREAKING CHANGE, in order of appearance.
     /// e.g. [("Refs", "#42"), ("Co-authored-by", "Name <email>")]
     footers: Vec<(String, String)>,
+    /// Render footers in footer_group order (see ValidationConfig).
+    group_footers: bool,
+    /// Template for the header line (see ValidationConfig).
+    header_format: HeaderFormat,
+    /// Header length past which check_header_length warns.
+    max_header_length: Option<usize>,
+    /// Render without trailing whitespace or leading body tabs.
+    strip_whitespace: bool,
+    /// A `fixup!`/`squash!` commit: the header is the marker and the
+    /// target's subject (kept in `description`), not `type: description`.
+    autosquash: Option<Autosquash>,
 }
 
 impl CommitMessage {
@@ -22,84 +45,463 @@ impl CommitMessage {
         body: Option<String>,
         breaking_change: Option<String>,
         footers: Vec<(String, String)>,
+        config: &ValidationConfig,
     ) -> Result<Self, DomainError> {
-        Self::validate_description(&description)?;
-
-        if let Some(ref s) = scope {
-            Self::validate_scope(s)?;
+        let fields = Fields {
+            scope: scope.as_deref(),
+            description: &description,
+            body: body.as_deref(),
+            breaking_change: breaking_change.as_deref(),
+            footers: &footers,
+        };
+        if let Some(error) = fields
+            .violations(Some(&commit_type), config)
+            .into_iter()
+            .next()
+        {
+            return Err(error);
         }
 
-        if let Some(ref b) = body
-            && b.trim().is_empty()
+        Ok(Self::assemble(
+            commit_type,
+            scope,
+            description,
+            body,
+            breaking_change,
+            footers,
+            config,
+        ))
+    }
+
+    /// Like `new`, but takes the type as typed and reports every violation
+    /// (type, scope, description, body, breaking change) instead of the first.
+    pub fn validate_all(
+        commit_type: &str,
+        scope: Option<String>,
+        description: String,
+        body: Option<String>,
+        breaking_change: Option<String>,
+        footers: Vec<(String, String)>,
+        config: &ValidationConfig,
+    ) -> Result<Self, Vec<DomainError>> {
+        let parsed = CommitType::from_str_with_custom(commit_type, &config.custom_types);
+        let fields = Fields {
+            scope: scope.as_deref(),
+            description: &description,
+            body: body.as_deref(),
+            breaking_change: breaking_change.as_deref(),
+            footers: &footers,
+        };
+        let mut errors = fields.violations(parsed.as_ref().ok(), config);
+        if let Ok(t) = &parsed
+            && config.strict_type_case
+            && t.as_str() != commit_type
         {
-            return Err(DomainError::EmptyBody);
+            errors.insert(
+                0,
+                DomainError::TypeCase(commit_type.to_string(), t.as_str().to_string()),
+            );
         }
 
-        if let Some(ref bc) = breaking_change
-            && bc.trim().is_empty()
-        {
-            return Err(DomainError::EmptyBreakingChange);
+        match parsed {
+            Ok(commit_type) if errors.is_empty() => Ok(Self::assemble(
+                commit_type,
+                scope,
+                description,
+                body,
+                breaking_change,
+                footers,
+                config,
+            )),
+            Ok(_) => Err(errors),
+            Err(e) => {
+                errors.insert(0, e);
+                Err(errors)
+            }
         }
+    }
 
-        Ok(CommitMessage {
+    fn assemble(
+        commit_type: CommitType,
+        scope: Option<String>,
+        description: String,
+        body: Option<String>,
+        breaking_change: Option<String>,
+        footers: Vec<(String, String)>,
+        config: &ValidationConfig,
+    ) -> Self {
+        CommitMessage {
+            gitmoji: None,
             commit_type,
             scope,
             description,
             body,
+            breaking: breaking_change.is_some(),
             breaking_change,
             footers,
-        })
+            group_footers: config.group_footers,
+            header_format: config.header_format.clone(),
+            max_header_length: config.max_header_length,
+            strip_whitespace: config.strip_whitespace,
+            autosquash: None,
+        }
+    }
+
+    /// A `fixup! <subject>` / `squash! <subject>` message for the commit
+    /// whose subject is `target`. Not a conventional commit, so nothing is
+    /// validated; trailers can still be added.
+    pub fn autosquash(kind: Autosquash, target: &str, config: &ValidationConfig) -> Self {
+        CommitMessage {
+            autosquash: Some(kind),
+            ..Self::assemble(
+                CommitType::Custom(kind.as_str().to_string()),
+                None,
+                target.trim().to_string(),
+                None,
+                None,
+                vec![],
+                config,
+            )
+        }
     }
 
-    fn validate_description(description: &str) -> Result<(), DomainError> {
+    fn description_errors(description: &str, config: &ValidationConfig) -> Vec<DomainError> {
         let trimmed = description.trim();
         if trimmed.is_empty() {
-            return Err(DomainError::EmptyDescription);
+            return vec![DomainError::EmptyDescription];
         }
-        if trimmed.len() > 72 {
-            return Err(DomainError::DescriptionTooLong(trimmed.len()));
+        let mut errors = Vec::new();
+        let len = trimmed.chars().count();
+        if len > config.max_description_length {
+            errors.push(DomainError::DescriptionTooLong {
+                len,
+                max: config.max_description_length,
+            });
         }
-        Ok(())
+        if let Some(min) = config.min_description_length
+            && len < min
+        {
+            errors.push(DomainError::DescriptionTooShort { len, min });
+        }
+        if config.reject_trailing_period && trimmed.ends_with('.') {
+            errors.push(DomainError::SubjectEndsWithPeriod);
+        }
+        errors
+    }
+
+    /// One error per over-long line; line numbers are 1-based within the body.
+    fn body_line_errors(body: &str, config: &ValidationConfig) -> Vec<DomainError> {
+        let Some(max) = config.max_body_line_length else {
+            return Vec::new();
+        };
+        body.lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let len = line.chars().count();
+                let exempt = config.exempt_unbreakable_lines && is_unbreakable(line);
+                (len > max && !exempt).then_some(DomainError::BodyLineTooLong {
+                    line: i + 1,
+                    len,
+                    max,
+                })
+            })
+            .collect()
     }
 
-    pub fn validate_scope(scope: &str) -> Result<(), DomainError> {
+    /// With `allow_path_scopes`, a scope may also be a path of such words
+    /// (`packages/api`); '.' is allowed inside a segment, empty segments are not.
+    /// Each segment must then follow `scope_case`.
+    pub fn validate_scope(scope: &str, config: &ValidationConfig) -> Result<(), DomainError> {
         let trimmed = scope.trim();
-        if trimmed.is_empty() {
+        let is_word = |s: &str| {
+            !s.is_empty()
+                && s.chars().all(|c| {
+                    c.is_alphanumeric()
+                        || c == '-'
+                        || c == '_'
+                        || (config.allow_path_scopes && c == '.')
+                })
+        };
+        let valid = if config.allow_path_scopes {
+            trimmed.split('/').all(is_word)
+        } else {
+            is_word(trimmed)
+        };
+        if !valid {
             return Err(DomainError::InvalidScope(scope.to_string()));
         }
+        if config
+            .forbidden_scopes
+            .iter()
+            .any(|f| f.eq_ignore_ascii_case(trimmed))
+        {
+            return Err(DomainError::ForbiddenScope(trimmed.to_string()));
+        }
         if !trimmed
-            .chars()
-            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+            .split('/')
+            .all(|word| config.scope_case.allows(word))
         {
-            return Err(DomainError::InvalidScope(scope.to_string()));
+            return Err(DomainError::ScopeCase(
+                trimmed.to_string(),
+                config.scope_case,
+            ));
         }
         Ok(())
     }
 
+    /// One issue reference, as `strict_issue_refs` requires it: `#123`,
+    /// `GH-123`, `org/repo#123` or `https://github.com/org/repo/issues/123`.
+    pub fn validate_issue_reference(reference: &str) -> Result<(), DomainError> {
+        let number = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        let name = |s: &str| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        };
+
+        let valid = if let Some(n) = reference.strip_prefix('#') {
+            number(n)
+        } else if let Some(n) = reference.strip_prefix("GH-") {
+            number(n)
+        } else if let Some(path) = reference.strip_prefix("https://github.com/") {
+            let parts: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+            matches!(parts.as_slice(), [org, repo, "issues", n] if name(org) && name(repo) && number(n))
+        } else if let Some((repo, n)) = reference.split_once('#') {
+            repo.split_once('/')
+                .is_some_and(|(org, repo)| name(org) && name(repo))
+                && number(n)
+        } else {
+            false
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(DomainError::InvalidIssueReference(reference.to_string()))
+        }
+    }
+
+    /// One issue reference as `config` checks it: the parser's rule
+    /// (is_issue_reference), or validate_issue_reference under
+    /// strict_issue_refs.
+    pub fn check_issue_reference(
+        reference: &str,
+        config: &ValidationConfig,
+    ) -> Result<(), DomainError> {
+        if config.strict_issue_refs {
+            Self::validate_issue_reference(reference)
+        } else if is_issue_reference(reference) {
+            Ok(())
+        } else {
+            Err(DomainError::InvalidIssueReference(reference.to_string()))
+        }
+    }
+
+    /// Imperative-mood hint for the description, e.g. "Use 'add' instead of
+    /// 'added'". A warning only — the message is valid either way.
+    pub fn check_mood(&self) -> Option<String> {
+        Self::mood_hint(&self.description)
+    }
+
+    /// check_mood for a description on its own, so prompts can warn early.
+    pub fn mood_hint(description: &str) -> Option<String> {
+        mood::suggestion(description)
+    }
+
+    /// Every soft problem: mood, a trailing period (when the config lets
+    /// one through) and an over-long header. None of them blocks a commit.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = description_warnings(&self.description);
+        warnings.extend(self.check_header_length());
+        warnings
+    }
+
+    /// from_ast_all as a report: the errors, plus the warnings the message
+    /// would show. With errors there is no rendered header to measure, so
+    /// only the description's warnings are given.
+    pub fn validate_report(
+        ast: crate::compiler::CommitAst,
+        config: &ValidationConfig,
+    ) -> ValidationReport {
+        let description = ast.header.description.clone();
+        match Self::from_ast_all(ast, config) {
+            Ok(message) => ValidationReport {
+                errors: Vec::new(),
+                warnings: message.warnings(),
+            },
+            Err(errors) => {
+                let mut warnings = description_warnings(&description);
+                // Already an error when rejected.
+                if config.reject_trailing_period {
+                    warnings.retain(|w| w != TRAILING_PERIOD_WARNING);
+                }
+                ValidationReport { errors, warnings }
+            }
+        }
+    }
+
+    /// Prefix the header with a gitmoji; None removes it.
+    pub fn with_gitmoji(mut self, gitmoji: Option<String>) -> Self {
+        self.gitmoji = gitmoji;
+        self
+    }
+
+    /// Mark the header '!' without a BREAKING CHANGE footer, as `feat!: x`
+    /// is. false keeps whatever breaking_change already implies.
+    pub fn with_breaking(mut self, breaking: bool) -> Self {
+        self.breaking |= breaking;
+        self
+    }
+
+    /// Append a `Signed-off-by` trailer for the given "Name <email>" identity.
+    ///
+    /// Added after every other footer so it renders last (with the people
+    /// trailers under `group_footers`). A no-op if the
+    /// exact trailer is already present (e.g. typed by hand in the editor).
+    pub fn with_signoff(self, identity: String) -> Self {
+        self.with_trailer("Signed-off-by", identity)
+    }
+
+    /// Append a `Co-authored-by` trailer; `identity` should come from
+    /// parse_co_author. Like with_signoff, an exact duplicate is skipped.
+    pub fn with_co_author(self, identity: String) -> Self {
+        self.with_trailer("Co-authored-by", identity)
+    }
+
+    fn with_trailer(mut self, key: &str, value: String) -> Self {
+        let present = self.footers.iter().any(|(k, v)| k == key && *v == value);
+        if !present {
+            self.footers.push((key.to_string(), value));
+        }
+        self
+    }
+
+    /// Check a `Name <email>` co-author and normalize its spacing:
+    /// " Jane  Doe<jane@example.com>" → "Jane Doe <jane@example.com>".
+    pub fn parse_co_author(input: &str) -> Result<String, DomainError> {
+        let invalid = || DomainError::InvalidCoAuthor(input.trim().to_string());
+        let (name, rest) = input.split_once('<').ok_or_else(invalid)?;
+        let email = rest
+            .trim_end()
+            .strip_suffix('>')
+            .ok_or_else(invalid)?
+            .trim();
+        let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        let valid_name = !name.is_empty() && !name.contains('>');
+        let valid_email = email.contains('@')
+            && !email.starts_with('@')
+            && !email.ends_with('@')
+            && !email.contains(|c: char| c.is_whitespace() || c == '<' || c == '>');
+        if !valid_name || !valid_email {
+            return Err(invalid());
+        }
+        Ok(format!("{} <{}>", name, email))
+    }
+
     /// Renders the commit message as a conventional commit string.
     ///
     /// Footer ordering: BREAKING CHANGE (if present) first, then all other
-    /// footers in their original order.
+    /// footers in their original order — or, with `group_footers`, issue
+    /// refs, then people trailers, then the rest, each in original order.
     pub fn to_conventional_commit(&self) -> String {
-        let mut result = String::new();
+        self.render(None)
+    }
 
-        // Header
-        result.push_str(self.commit_type.as_str());
-        if let Some(ref scope) = self.scope {
-            result.push('(');
-            result.push_str(scope);
-            result.push(')');
+    /// Same as to_conventional_commit, with body lines word-wrapped at `width`.
+    ///
+    /// Only lines longer than `width` are broken, at whitespace, so existing
+    /// line and paragraph breaks are kept. A single word longer than `width`
+    /// (e.g. a URL) is never split. Header and footers are left untouched.
+    pub fn to_wrapped(&self, width: usize) -> String {
+        self.render(Some(width))
+    }
+
+    /// The first line, exactly as rendered.
+    fn header(&self) -> String {
+        if let Some(kind) = self.autosquash {
+            return kind.subject(&self.description);
         }
-        if self.breaking_change.is_some() {
-            result.push('!');
+        let mut header = String::new();
+        if let Some(ref gitmoji) = self.gitmoji {
+            header.push_str(gitmoji);
+            header.push(' ');
         }
-        result.push_str(": ");
-        result.push_str(&self.description);
+        header.push_str(&self.header_format.render(&HeaderFields {
+            commit_type: self.commit_type.as_str(),
+            scope: self.scope.as_deref(),
+            breaking: self.breaking,
+            description: &self.description,
+        }));
+        header
+    }
+
+    /// A breaking change: marked with '!', a BREAKING CHANGE footer, or both.
+    pub fn is_breaking(&self) -> bool {
+        self.breaking
+    }
+
+    pub fn commit_type(&self) -> &CommitType {
+        &self.commit_type
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// The BREAKING CHANGE footer's text; None for a bare '!'.
+    pub fn breaking_change(&self) -> Option<&str> {
+        self.breaking_change.as_deref()
+    }
+
+    /// The header line as rendered, e.g. `feat(api)!: add search`.
+    pub fn subject(&self) -> String {
+        self.header()
+    }
+
+    /// Characters in the rendered header line (type, scope, '!', gitmoji
+    /// and description together), as opposed to the description alone.
+    pub fn header_length(&self) -> usize {
+        self.header().chars().count()
+    }
+
+    /// A warning when the whole header exceeds max_header_length. Like
+    /// check_mood, the message is valid either way.
+    pub fn check_header_length(&self) -> Option<String> {
+        let (len, max) = (self.header_length(), self.max_header_length?);
+        (len > max).then(|| {
+            format!(
+                "Header is {} characters; GitHub and narrow terminals cut it off after {}",
+                len, max
+            )
+        })
+    }
+
+    fn render(&self, wrap_width: Option<usize>) -> String {
+        let mut result = String::new();
+
+        // Header
+        result.push_str(&self.header());
 
         // Body
         if let Some(ref body) = self.body {
+            let body = if self.strip_whitespace {
+                expand_leading_tabs(body)
+            } else {
+                body.clone()
+            };
             result.push_str("\n\n");
-            result.push_str(body);
+            match wrap_width {
+                Some(width) => result.push_str(&wrap_body(&body, width)),
+                None => result.push_str(&body),
+            }
         }
 
         // Footer section — only open if there is at least one footer
@@ -115,20 +517,159 @@ impl CommitMessage {
                 }
             }
 
-            for (i, (key, value)) in self.footers.iter().enumerate() {
+            let deduped = dedupe_issue_refs(&self.footers);
+            let mut footers: Vec<&(String, String)> = deduped.iter().collect();
+            if self.group_footers {
+                // Stable, so each group keeps the order the footers were written in.
+                footers.sort_by_key(|(key, _)| footer_group(key));
+            }
+            for (i, (key, value)) in footers.iter().enumerate() {
                 result.push_str(key);
                 result.push_str(": ");
                 result.push_str(value);
-                if i < self.footers.len() - 1 {
+                if i < footers.len() - 1 {
                     result.push('\n');
                 }
             }
         }
 
+        if self.strip_whitespace {
+            result = strip_trailing_whitespace(&result);
+        }
         result
     }
 }
 
+const TRAILING_PERIOD_WARNING: &str = "Description ends with a period";
+
+/// The description's share of CommitMessage::warnings.
+fn description_warnings(description: &str) -> Vec<String> {
+    let mut warnings: Vec<String> = mood::suggestion(description).into_iter().collect();
+    if description.trim_end().ends_with('.') {
+        warnings.push(TRAILING_PERIOD_WARNING.to_string());
+    }
+    warnings
+}
+
+/// Spaces a leading tab in the body is expanded to.
+const TAB_WIDTH: usize = 4;
+
+/// Tabs in each line's indentation become spaces; tabs after the first
+/// non-blank character are left alone.
+fn expand_leading_tabs(body: &str) -> String {
+    body.lines()
+        .map(|line| {
+            let text = line.trim_start_matches([' ', '\t']);
+            let indent = &line[..line.len() - text.len()];
+            indent.replace('\t', &" ".repeat(TAB_WIDTH)) + text
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Every line without its trailing whitespace; blank lines stay, empty.
+fn strip_trailing_whitespace(message: &str) -> String {
+    message
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Footer keys whose values are lists of issue references.
+const ISSUE_KEYS: [&str; 3] = ["Refs", "Closes", "Fixes"];
+
+/// Whether a footer's value is a list of issue references (Refs, Closes,
+/// Fixes, in any case). The parser checks those values with the same rule.
+pub fn is_issue_key(key: &str) -> bool {
+    ISSUE_KEYS.iter().any(|k| k.eq_ignore_ascii_case(key))
+}
+
+/// Footers with each issue reference kept only where it first appears,
+/// within one Refs/Closes/Fixes value or across several of them.
+///
+/// A value that lost a duplicate is rejoined with ", "; one left with no
+/// references is dropped. Other footers, and values without duplicates,
+/// are kept exactly as written.
+fn dedupe_issue_refs(footers: &[(String, String)]) -> Vec<(String, String)> {
+    let mut seen: Vec<&str> = Vec::new();
+    let mut deduped = Vec::new();
+    for (key, value) in footers {
+        if !is_issue_key(key) {
+            deduped.push((key.clone(), value.clone()));
+            continue;
+        }
+        let refs: Vec<&str> = split_issue_refs(value).collect();
+        let fresh: Vec<&str> = refs
+            .iter()
+            .copied()
+            .filter(|r| {
+                let new = !seen.contains(r);
+                if new {
+                    seen.push(r);
+                }
+                new
+            })
+            .collect();
+        if fresh.len() == refs.len() {
+            deduped.push((key.clone(), value.clone()));
+        } else if !fresh.is_empty() {
+            deduped.push((key.clone(), fresh.join(", ")));
+        }
+    }
+    deduped
+}
+
+/// Rank of a footer under `group_footers`: issue refs, people, everything else.
+fn footer_group(key: &str) -> u8 {
+    const PEOPLE: [&str; 2] = ["Co-authored-by", "Signed-off-by"];
+    if is_issue_key(key) {
+        0
+    } else if PEOPLE.iter().any(|k| k.eq_ignore_ascii_case(key)) {
+        1
+    } else {
+        2
+    }
+}
+
+/// Word-wrap each body line independently, keeping its leading indentation.
+fn wrap_body(body: &str, width: usize) -> String {
+    body.lines()
+        .flat_map(|line| wrap_line(line, width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    if line.chars().count() <= width {
+        return vec![line.to_string()];
+    }
+
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let indent_len = indent.chars().count();
+
+    let mut lines = Vec::new();
+    let mut current = indent.to_string();
+    let mut current_len = indent_len;
+
+    for word in line.split_whitespace() {
+        let word_len = word.chars().count();
+        if current_len > indent_len && current_len + 1 + word_len > width {
+            lines.push(current);
+            current = indent.to_string();
+            current_len = indent_len;
+        }
+        if current_len > indent_len {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.push_str(word);
+        current_len += word_len;
+    }
+    lines.push(current);
+    lines
+}
+
 impl std::fmt::Display for CommitMessage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_conventional_commit())
@@ -143,29 +684,253 @@ impl TryFrom<crate::compiler::CommitAst> for CommitMessage {
     type Error = DomainError;
 
     fn try_from(ast: crate::compiler::CommitAst) -> Result<Self, DomainError> {
-        let commit_type = CommitType::from_str(&ast.header.commit_type)?;
+        Self::from_ast(ast, &ValidationConfig::default())
+    }
+}
+
+/// The validated fields of a message, borrowed for checking.
+struct Fields<'a> {
+    scope: Option<&'a str>,
+    description: &'a str,
+    body: Option<&'a str>,
+    breaking_change: Option<&'a str>,
+    footers: &'a [(String, String)],
+}
+
+impl Fields<'_> {
+    /// Every rule the fields break, in the order `new` reports them.
+    /// The scope requirement is skipped when the type itself is invalid.
+    fn violations(
+        &self,
+        commit_type: Option<&CommitType>,
+        config: &ValidationConfig,
+    ) -> Vec<DomainError> {
+        let mut errors = Vec::new();
+        if has_control_character(self.description) {
+            errors.push(DomainError::ControlCharacter("description"));
+        }
+        errors.extend(CommitMessage::description_errors(self.description, config));
+
+        match (self.scope, commit_type) {
+            (Some(s), _) if has_control_character(s) => {
+                errors.push(DomainError::ControlCharacter("scope"));
+            }
+            (Some(s), _) => errors.extend(CommitMessage::validate_scope(s, config).err()),
+            (None, Some(t)) if config.require_scope.requires(t) => {
+                errors.push(DomainError::ScopeRequired(t.clone()));
+            }
+            (None, _) => {}
+        }
+
+        if let Some(body) = self.body {
+            if let Some(t) = commit_type.filter(|t| config.forbids_body(t)) {
+                errors.push(DomainError::BodyNotAllowed(t.clone()));
+            } else if body.trim().is_empty() {
+                errors.push(DomainError::EmptyBody);
+            } else if body.len() > config.max_body_bytes {
+                // Not worth checking line by line.
+                errors.push(DomainError::BodyTooLarge {
+                    len: body.len(),
+                    max: config.max_body_bytes,
+                });
+            } else {
+                errors.extend(CommitMessage::body_line_errors(body, config));
+            }
+        }
+
+        if self.breaking_change.is_some_and(|bc| bc.trim().is_empty()) {
+            errors.push(DomainError::EmptyBreakingChange);
+        }
+
+        errors.extend(
+            self.footers
+                .iter()
+                .filter(|(key, value)| !is_footer(key, value))
+                .map(|(key, value)| DomainError::InvalidFooter(key.clone(), value.clone())),
+        );
+
+        if self.footers.len() > config.max_footers {
+            errors.push(DomainError::TooManyFooters {
+                count: self.footers.len(),
+                max: config.max_footers,
+            });
+        }
+
+        // The parser's rule always; strict_issue_refs narrows it further.
+        let references = self
+            .footers
+            .iter()
+            .filter(|(key, _)| is_issue_key(key))
+            .flat_map(|(_, value)| split_issue_refs(value));
+        errors.extend(
+            references.filter_map(|r| CommitMessage::check_issue_reference(r, config).err()),
+        );
+
+        for single in &config.single_footers {
+            let mut matching = self
+                .footers
+                .iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case(single));
+            if let (Some((key, _)), Some(_)) = (matching.next(), matching.next()) {
+                errors.push(DomainError::DuplicateFooter(key.clone()));
+            }
+        }
+
+        errors
+    }
+}
+
+/// "#12, #34 GH-5" → its references, split at commas and whitespace.
+pub fn split_issue_refs(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|r| !r.is_empty())
+}
+
+/// `#<digits>`, `GH-<digits>`, `org/repo#<digits>`, or an http(s) URL: what
+/// the parser accepts in Refs/Closes/Fixes. strict_issue_refs narrows URLs
+/// to GitHub issues (validate_issue_reference).
+pub fn is_issue_reference(reference: &str) -> bool {
+    let digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    if let Some(n) = reference.strip_prefix('#') {
+        return digits(n);
+    }
+    if let Some(n) = reference.strip_prefix("GH-") {
+        return digits(n);
+    }
+    if let Some((repo, n)) = reference.split_once('#')
+        && repo
+            .split_once('/')
+            .is_some_and(|(org, name)| !org.is_empty() && !name.is_empty() && !name.contains('/'))
+    {
+        return digits(n);
+    }
+    ["https://", "http://"].iter().any(|scheme| {
+        reference
+            .strip_prefix(scheme)
+            .is_some_and(|rest| !rest.is_empty())
+    })
+}
+
+/// The shape of a footer key the lexer recognizes: capitalized (`Refs`,
+/// `Co-authored-by`), or all capitals when it has spaces (`BREAKING CHANGE`).
+pub fn is_footer_key(key: &str) -> bool {
+    if key.contains(' ') {
+        key.chars()
+            .all(|c| c.is_uppercase() || c == ' ' || c == '-')
+    } else {
+        key.chars().next().is_some_and(char::is_uppercase)
+    }
+}
+
+/// A footer that renders as one line the parser reads back as the same
+/// key and value.
+fn is_footer(key: &str, value: &str) -> bool {
+    is_footer_key(key)
+        && key.trim() == key
+        && !key.contains(':')
+        && !has_control_character(key)
+        && !value.trim().is_empty()
+        && !has_control_character(value)
+}
+
+/// A header field that would not stay on the header line. Tabs are
+/// allowed; they are only whitespace.
+fn has_control_character(field: &str) -> bool {
+    field.chars().any(|c| c.is_control() && c != '\t')
+}
 
+/// A line wrapping cannot shorten: a single token, or one carrying a URL.
+fn is_unbreakable(line: &str) -> bool {
+    let mut words = line.split_whitespace();
+    words.clone().count() <= 1 || words.any(|w| w.contains("://"))
+}
+
+/// Both spellings the spec allows for the breaking-change footer.
+/// CommitMessage keeps the value separately and always renders "BREAKING CHANGE".
+pub fn is_breaking_change_key(key: &str) -> bool {
+    key == "BREAKING CHANGE" || key == "BREAKING-CHANGE"
+}
+
+impl CommitMessage {
+    /// Compile and validate `input` with the default rules in one step.
+    pub fn parse(input: &str) -> Result<Self, CommandoError> {
+        Self::parse_with(
+            input,
+            &CompilerPipeline::new(),
+            &ValidationConfig::default(),
+        )
+    }
+
+    /// parse with a configured compiler (gitmoji, unicode normalization)
+    /// and rules.
+    pub fn parse_with(
+        input: &str,
+        compiler: &CompilerPipeline,
+        config: &ValidationConfig,
+    ) -> Result<Self, CommandoError> {
+        let ast = compiler.compile(input)?;
+        Ok(Self::from_ast(ast, config)?)
+    }
+
+    /// Same as TryFrom<CommitAst>, validating against the given config.
+    ///
+    /// The only place footer keys are normalized: a `BREAKING-CHANGE` footer
+    /// is treated exactly like `BREAKING CHANGE`, and a '!' header with no
+    /// such footer still renders as breaking.
+    pub fn from_ast(
+        ast: crate::compiler::CommitAst,
+        config: &ValidationConfig,
+    ) -> Result<Self, DomainError> {
+        Self::from_ast_all(ast, config).map_err(|mut errors| errors.remove(0))
+    }
+
+    /// from_ast reporting every violation, see validate_all.
+    pub fn from_ast_all(
+        ast: crate::compiler::CommitAst,
+        config: &ValidationConfig,
+    ) -> Result<Self, Vec<DomainError>> {
         let breaking_change = ast
             .footers
             .iter()
-            .find(|f| f.key == "BREAKING CHANGE" || f.key == "BREAKING-CHANGE")
+            .find(|f| is_breaking_change_key(&f.key))
             .map(|f| f.value.clone());
 
         let footers: Vec<(String, String)> = ast
             .footers
             .into_iter()
-            .filter(|f| f.key != "BREAKING CHANGE" && f.key != "BREAKING-CHANGE")
+            .filter(|f| !is_breaking_change_key(&f.key))
             .map(|f| (f.key, f.value))
             .collect();
 
-        CommitMessage::new(
-            commit_type,
+        // Per the spec `feat!: x` is breaking on its own; some teams want
+        // the footer to say what broke.
+        let missing_footer =
+            config.breaking_requires_footer && ast.header.breaking && breaking_change.is_none();
+
+        let result = CommitMessage::validate_all(
+            &ast.header.commit_type,
             ast.header.scope,
             ast.header.description,
             ast.body.map(|b| b.content),
             breaking_change,
             footers,
-        )
+            config,
+        );
+        match result {
+            Ok(mut message) if !missing_footer => {
+                message.breaking |= ast.header.breaking;
+                message.gitmoji = ast.header.gitmoji;
+                Ok(message)
+            }
+            Ok(_) => Err(vec![DomainError::BreakingChangeFooterRequired]),
+            Err(mut errors) => {
+                if missing_footer {
+                    errors.push(DomainError::BreakingChangeFooterRequired);
+                }
+                Err(errors)
+            }
+        }
     }
 }
 
@@ -174,6 +939,10 @@ mod tests {
     use super::*;
     use crate::domain::commit_type::CommitType;
     use crate::domain::error::DomainError;
+    use crate::domain::header_format::HeaderFormat;
+    use crate::domain::validation::{
+        DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_FOOTERS, ScopeCase, ScopeRule, ValidationConfig,
+    };
 
     fn no_footers() -> Vec<(String, String)> {
         vec![]
@@ -188,7 +957,8 @@ mod tests {
                 "add login".into(),
                 None,
                 None,
-                no_footers()
+                no_footers(),
+                &ValidationConfig::default(),
             )
             .is_ok()
         );
@@ -196,204 +966,1738 @@ mod tests {
 
     #[test]
     fn empty_description_fails() {
-        assert!(matches!(
-            CommitMessage::new(CommitType::Feat, None, "".into(), None, None, no_footers()),
-            Err(DomainError::EmptyDescription)
-        ));
-    }
-
-    #[test]
-    fn description_too_long_fails() {
         assert!(matches!(
             CommitMessage::new(
                 CommitType::Feat,
                 None,
-                "a".repeat(73),
+                "".into(),
                 None,
                 None,
-                no_footers()
+                no_footers(),
+                &ValidationConfig::default(),
             ),
-            Err(DomainError::DescriptionTooLong(_))
+            Err(DomainError::EmptyDescription)
         ));
     }
 
     #[test]
-    fn invalid_scope_fails() {
+    fn description_too_long_fails() {
         assert!(matches!(
             CommitMessage::new(
                 CommitType::Feat,
-                Some("bad scope!".into()),
-                "desc".into(),
+                None,
+                "a".repeat(73),
                 None,
                 None,
-                no_footers()
+                no_footers(),
+                &ValidationConfig::default(),
             ),
-            Err(DomainError::InvalidScope(_))
+            Err(DomainError::DescriptionTooLong { len: 73, max: 72 })
         ));
     }
 
     #[test]
-    fn empty_body_fails() {
-        assert!(matches!(
+    fn description_length_counts_characters_not_bytes() {
+        let config = ValidationConfig {
+            max_description_length: 40,
+            ..Default::default()
+        };
+        let new = |description: String| {
             CommitMessage::new(
                 CommitType::Feat,
                 None,
-                "desc".into(),
-                Some("  ".into()),
+                description,
                 None,
-                no_footers()
-            ),
-            Err(DomainError::EmptyBody)
-        ));
+                None,
+                no_footers(),
+                &config,
+            )
+        };
+        // 40 characters, 80 bytes.
+        assert!(new("é".repeat(40)).is_ok());
+        assert_eq!(
+            new("é".repeat(41)),
+            Err(DomainError::DescriptionTooLong { len: 41, max: 40 })
+        );
     }
 
     #[test]
-    fn empty_breaking_change_fails() {
-        assert!(matches!(
+    fn description_within_custom_limit_passes() {
+        let config = ValidationConfig {
+            max_description_length: 100,
+            ..Default::default()
+        };
+        assert!(
             CommitMessage::new(
                 CommitType::Feat,
                 None,
-                "desc".into(),
+                "a".repeat(90),
                 None,
-                Some("".into()),
-                no_footers()
-            ),
-            Err(DomainError::EmptyBreakingChange)
-        ));
+                None,
+                no_footers(),
+                &config
+            )
+            .is_ok()
+        );
     }
 
+    // ── breaking change normalization ─────────────────────────────────────────
+
     #[test]
-    fn renders_minimal() {
+    fn hyphenated_breaking_change_footer_is_breaking() {
+        let msg = from_ast_with(
+            "feat: drop v1\n\nBREAKING-CHANGE: v1 removed",
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat!: drop v1\n\nBREAKING CHANGE: v1 removed"
+        );
+    }
+
+    #[test]
+    fn hyphenated_footer_with_marker_renders_once() {
+        let msg = from_ast_with(
+            "feat(api)!: drop v1\n\nRefs: #3\nBREAKING-CHANGE: v1 removed",
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(api)!: drop v1\n\nBREAKING CHANGE: v1 removed\nRefs: #3"
+        );
+    }
+
+    #[test]
+    fn marker_without_footer_is_kept() {
+        let msg = from_ast_with("feat!: drop v1", &ValidationConfig::default()).unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat!: drop v1");
+        assert!(msg.is_breaking());
+    }
+
+    #[test]
+    fn marker_without_footer_is_rejected_when_footer_required() {
+        let config = ValidationConfig {
+            breaking_requires_footer: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            from_ast_with("feat!: drop v1", &config).unwrap_err(),
+            DomainError::BreakingChangeFooterRequired
+        );
+        let msg = from_ast_with("feat!: drop v1\n\nBREAKING CHANGE: v1 removed", &config).unwrap();
+        assert!(msg.is_breaking());
+        // The footer alone is still enough.
+        assert!(from_ast_with("feat: drop v1\n\nBREAKING CHANGE: v1 removed", &config).is_ok());
+    }
+
+    #[test]
+    fn breaking_change_key_accepts_both_spellings() {
+        assert!(is_breaking_change_key("BREAKING CHANGE"));
+        assert!(is_breaking_change_key("BREAKING-CHANGE"));
+        assert!(!is_breaking_change_key("Breaking change"));
+    }
+
+    fn body_limit_config(exempt_unbreakable_lines: bool) -> ValidationConfig {
+        ValidationConfig {
+            max_body_line_length: Some(40),
+            exempt_unbreakable_lines,
+            ..Default::default()
+        }
+    }
+
+    fn body_checked(body: &str, config: &ValidationConfig) -> Result<CommitMessage, DomainError> {
+        CommitMessage::new(
+            CommitType::Docs,
+            None,
+            "x".into(),
+            Some(body.into()),
+            None,
+            no_footers(),
+            config,
+        )
+    }
+
+    #[test]
+    fn over_long_prose_body_line_is_rejected() {
+        let body = "Short line.\nThis prose line runs well past the forty column limit.";
+        assert_eq!(
+            body_checked(body, &body_limit_config(true)).unwrap_err(),
+            DomainError::BodyLineTooLong {
+                line: 2,
+                len: 54,
+                max: 40
+            }
+        );
+    }
+
+    #[test]
+    fn long_url_line_passes_when_exempt() {
+        let body = "See https://example.com/a/very/long/path/to/the/design/doc";
+        assert!(body_checked(body, &body_limit_config(true)).is_ok());
+        assert!(matches!(
+            body_checked(body, &body_limit_config(false)),
+            Err(DomainError::BodyLineTooLong { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn body_line_length_is_unchecked_by_default() {
+        let body = "x ".repeat(100);
+        assert!(body_checked(&body, &ValidationConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn body_over_the_byte_limit_is_rejected() {
+        let config = ValidationConfig {
+            max_body_bytes: 16,
+            ..Default::default()
+        };
+        assert!(body_checked("Sixteen bytes..!", &config).is_ok());
+        assert_eq!(
+            body_checked("Seventeen bytes..", &config).unwrap_err(),
+            DomainError::BodyTooLarge { len: 17, max: 16 }
+        );
+    }
+
+    #[test]
+    fn default_body_limit_allows_a_long_body() {
+        let body = "A paragraph of ordinary prose.\n\n".repeat(1000);
+        assert!(body_checked(&body, &ValidationConfig::default()).is_ok());
+        let body = "x".repeat(DEFAULT_MAX_BODY_BYTES + 1);
+        assert!(matches!(
+            body_checked(&body, &ValidationConfig::default()),
+            Err(DomainError::BodyTooLarge { .. })
+        ));
+    }
+
+    fn footer_count_checked(
+        count: usize,
+        max_footers: usize,
+    ) -> Result<CommitMessage, DomainError> {
+        CommitMessage::new(
+            CommitType::Fix,
+            None,
+            "x".into(),
+            None,
+            None,
+            (0..count)
+                .map(|i| {
+                    (
+                        "Co-authored-by".to_string(),
+                        format!("Dev {} <dev{}@x>", i, i),
+                    )
+                })
+                .collect(),
+            &ValidationConfig {
+                max_footers,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn footers_past_the_limit_are_rejected() {
+        assert!(footer_count_checked(3, 3).is_ok());
+        assert_eq!(
+            footer_count_checked(4, 3).unwrap_err(),
+            DomainError::TooManyFooters { count: 4, max: 3 }
+        );
+        assert!(footer_count_checked(DEFAULT_MAX_FOOTERS, DEFAULT_MAX_FOOTERS).is_ok());
+    }
+
+    #[test]
+    fn capitalized_type_is_lowercased_by_default() {
+        let msg = from_ast_with("Feat: add search", &ValidationConfig::default()).unwrap();
+        assert_eq!(msg.commit_type, CommitType::Feat);
+        assert_eq!(msg.to_conventional_commit(), "feat: add search");
+    }
+
+    #[test]
+    fn capitalized_type_is_rejected_when_strict() {
+        let config = ValidationConfig {
+            strict_type_case: true,
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            from_ast_with("Feat: add search", &config).unwrap_err(),
+            DomainError::TypeCase("Feat".into(), "feat".into())
+        );
+        assert_eq!(
+            from_ast_with("DEPS: bump clap", &config).unwrap_err(),
+            DomainError::TypeCase("DEPS".into(), "deps".into())
+        );
+        assert!(from_ast_with("feat: add search", &config).is_ok());
+    }
+
+    fn scope_rule_config() -> ValidationConfig {
+        ValidationConfig {
+            require_scope: ScopeRule::For(vec!["feat".into(), "fix".into()]),
+            ..Default::default()
+        }
+    }
+
+    fn from_ast_with(input: &str, config: &ValidationConfig) -> Result<CommitMessage, DomainError> {
+        use crate::compiler::CompilerPipeline;
+        let pipeline = CompilerPipeline::new().with_gitmoji(config.gitmoji);
+        CommitMessage::from_ast(pipeline.compile(input).unwrap(), config)
+    }
+
+    #[test]
+    fn scope_required_rule_rejects_unscoped_feat() {
+        assert_eq!(
+            from_ast_with("feat: x", &scope_rule_config()).unwrap_err(),
+            DomainError::ScopeRequired(CommitType::Feat)
+        );
+    }
+
+    #[test]
+    fn scope_required_rule_accepts_scoped_feat() {
+        assert!(from_ast_with("feat(api): x", &scope_rule_config()).is_ok());
+    }
+
+    #[test]
+    fn scope_required_rule_ignores_unlisted_types() {
+        assert!(from_ast_with("docs: x", &scope_rule_config()).is_ok());
+    }
+
+    #[test]
+    fn scope_always_required_applies_to_every_type() {
+        let config = ValidationConfig {
+            require_scope: ScopeRule::Always,
+            ..Default::default()
+        };
+        assert_eq!(
+            from_ast_with("chore: x", &config).unwrap_err(),
+            DomainError::ScopeRequired(CommitType::Chore)
+        );
+    }
+
+    fn body_forbidden_config() -> ValidationConfig {
+        ValidationConfig {
+            body_forbidden_types: vec!["chore".into(), "style".into()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn body_forbidden_types_reject_a_body() {
+        assert_eq!(
+            from_ast_with("chore: x\n\nbody", &body_forbidden_config()).unwrap_err(),
+            DomainError::BodyNotAllowed(CommitType::Chore)
+        );
+        assert!(matches!(
+            CommitMessage::new(
+                CommitType::Style,
+                None,
+                "x".into(),
+                Some("body".into()),
+                None,
+                vec![],
+                &body_forbidden_config(),
+            ),
+            Err(DomainError::BodyNotAllowed(CommitType::Style))
+        ));
+    }
+
+    #[test]
+    fn body_forbidden_types_leave_other_commits_alone() {
+        assert!(from_ast_with("feat: x\n\nbody", &body_forbidden_config()).is_ok());
+        assert!(from_ast_with("chore: x\n\nRefs: #1", &body_forbidden_config()).is_ok());
+    }
+
+    #[test]
+    fn description_over_custom_limit_fails() {
+        let config = ValidationConfig {
+            max_description_length: 50,
+            ..Default::default()
+        };
+        assert!(matches!(
+            CommitMessage::new(
+                CommitType::Feat,
+                None,
+                "a".repeat(51),
+                None,
+                None,
+                no_footers(),
+                &config
+            ),
+            Err(DomainError::DescriptionTooLong { len: 51, max: 50 })
+        ));
+    }
+
+    fn with_min_length(description: &str, min: usize) -> Result<CommitMessage, DomainError> {
+        let config = ValidationConfig {
+            min_description_length: Some(min),
+            ..Default::default()
+        };
+        CommitMessage::new(
+            CommitType::Fix,
+            None,
+            description.into(),
+            None,
+            None,
+            no_footers(),
+            &config,
+        )
+    }
+
+    #[test]
+    fn description_under_min_length_fails() {
+        assert_eq!(
+            with_min_length("  bug ", 10).unwrap_err(),
+            DomainError::DescriptionTooShort { len: 3, min: 10 }
+        );
+    }
+
+    #[test]
+    fn description_at_min_length_passes() {
+        assert!(with_min_length("handle empty input", 10).is_ok());
+        assert!(with_min_length("ten chars!", 10).is_ok());
+    }
+
+    #[test]
+    fn min_length_is_off_by_default() {
+        assert!(from_ast_with("fix: bug", &ValidationConfig::default()).is_ok());
+    }
+
+    // ── validate_all ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn validate_all_collects_every_violation() {
+        let config = ValidationConfig {
+            max_description_length: 10,
+            reject_trailing_period: true,
+            ..Default::default()
+        };
+        let errors = CommitMessage::validate_all(
+            "feature",
+            Some("bad scope".into()),
+            "add a much longer description.".into(),
+            Some("  ".into()),
+            Some("".into()),
+            no_footers(),
+            &config,
+        )
+        .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                DomainError::InvalidCommitType("feature".into(), vec![]),
+                DomainError::DescriptionTooLong { len: 30, max: 10 },
+                DomainError::SubjectEndsWithPeriod,
+                DomainError::InvalidScope("bad scope".into()),
+                DomainError::EmptyBody,
+                DomainError::EmptyBreakingChange,
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_all_reports_each_long_body_line() {
+        let config = ValidationConfig {
+            max_body_line_length: Some(10),
+            ..Default::default()
+        };
+        let errors = CommitMessage::validate_all(
+            "docs",
+            None,
+            "x".into(),
+            Some("one two three four\nok\nfive six seven eight".into()),
+            None,
+            no_footers(),
+            &config,
+        )
+        .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                DomainError::BodyLineTooLong {
+                    line: 1,
+                    len: 18,
+                    max: 10
+                },
+                DomainError::BodyLineTooLong {
+                    line: 3,
+                    len: 20,
+                    max: 10
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_all_matches_new_when_valid() {
+        let all = CommitMessage::validate_all(
+            "feat",
+            Some("api".into()),
+            "add search".into(),
+            None,
+            None,
+            no_footers(),
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(all.to_conventional_commit(), "feat(api): add search");
+    }
+
+    #[test]
+    fn scope_requirement_is_skipped_for_invalid_type() {
+        let config = ValidationConfig {
+            require_scope: ScopeRule::Always,
+            ..Default::default()
+        };
+        assert_eq!(
+            CommitMessage::validate_all(
+                "nope",
+                None,
+                "x".into(),
+                None,
+                None,
+                no_footers(),
+                &config
+            )
+            .unwrap_err(),
+            vec![DomainError::InvalidCommitType("nope".into(), vec![])]
+        );
+    }
+
+    #[test]
+    fn from_ast_still_reports_first_error() {
+        let config = ValidationConfig {
+            max_description_length: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            from_ast_with("nope: too long", &config).unwrap_err(),
+            DomainError::InvalidCommitType("nope".into(), vec![])
+        );
+    }
+
+    fn no_trailing_period() -> ValidationConfig {
+        ValidationConfig {
+            reject_trailing_period: true,
+            ..Default::default()
+        }
+    }
+
+    fn with_description(
+        description: &str,
+        config: &ValidationConfig,
+    ) -> Result<CommitMessage, DomainError> {
+        CommitMessage::new(
+            CommitType::Feat,
+            None,
+            description.into(),
+            None,
+            None,
+            no_footers(),
+            config,
+        )
+    }
+
+    #[test]
+    fn trailing_period_is_rejected_when_enabled() {
+        assert_eq!(
+            with_description("add feature.", &no_trailing_period()).unwrap_err(),
+            DomainError::SubjectEndsWithPeriod
+        );
+        assert_eq!(
+            with_description("add feature. ", &no_trailing_period()).unwrap_err(),
+            DomainError::SubjectEndsWithPeriod
+        );
+    }
+
+    #[test]
+    fn description_without_period_passes() {
+        assert!(with_description("add feature", &no_trailing_period()).is_ok());
+        assert!(with_description("bump to v1.2 for node", &no_trailing_period()).is_ok());
+    }
+
+    #[test]
+    fn lone_period_is_caught() {
+        assert_eq!(
+            with_description(".", &no_trailing_period()).unwrap_err(),
+            DomainError::SubjectEndsWithPeriod
+        );
+    }
+
+    #[test]
+    fn trailing_period_is_allowed_by_default() {
+        assert!(with_description("add feature.", &ValidationConfig::default()).is_ok());
+    }
+
+    fn path_scopes() -> ValidationConfig {
+        ValidationConfig {
+            allow_path_scopes: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn path_scopes_are_accepted_when_enabled() {
+        for scope in ["packages/api", "a/b/c", "libs/core.utils"] {
+            assert_eq!(
+                CommitMessage::validate_scope(scope, &path_scopes()),
+                Ok(()),
+                "{}",
+                scope
+            );
+        }
+    }
+
+    #[test]
+    fn path_scopes_reject_empty_segments() {
+        for scope in ["a//b", "/api", "api/"] {
+            assert_eq!(
+                CommitMessage::validate_scope(scope, &path_scopes()),
+                Err(DomainError::InvalidScope(scope.into()))
+            );
+        }
+    }
+
+    #[test]
+    fn path_scopes_are_rejected_by_default() {
+        let config = ValidationConfig::default();
+        assert!(CommitMessage::validate_scope("packages/api", &config).is_err());
+        assert!(CommitMessage::validate_scope("core.utils", &config).is_err());
+    }
+
+    #[test]
+    fn forbidden_scope_is_rejected_case_insensitively() {
+        let config = ValidationConfig {
+            forbidden_scopes: vec!["misc".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            CommitMessage::validate_scope("MISC", &config),
+            Err(DomainError::ForbiddenScope("MISC".into()))
+        );
+        assert_eq!(
+            from_ast_with("fix(misc): x", &config).unwrap_err(),
+            DomainError::ForbiddenScope("misc".into())
+        );
+        assert!(CommitMessage::validate_scope("api", &config).is_ok());
+    }
+
+    fn with_case(scope_case: ScopeCase) -> ValidationConfig {
+        ValidationConfig {
+            scope_case,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn uppercase_scope_under_each_case_policy() {
+        assert_eq!(
+            CommitMessage::validate_scope("Api", &with_case(ScopeCase::Lower)),
+            Err(DomainError::ScopeCase("Api".into(), ScopeCase::Lower))
+        );
+        assert_eq!(
+            CommitMessage::validate_scope("Api", &with_case(ScopeCase::Kebab)),
+            Err(DomainError::ScopeCase("Api".into(), ScopeCase::Kebab))
+        );
+        assert!(CommitMessage::validate_scope("Api", &with_case(ScopeCase::Any)).is_ok());
+        // Lower is the default, on every input path.
+        assert_eq!(
+            from_ast_with("feat(Api): x", &ValidationConfig::default()).unwrap_err(),
+            DomainError::ScopeCase("Api".into(), ScopeCase::Lower)
+        );
+    }
+
+    #[test]
+    fn kebab_case_policy() {
+        let kebab = with_case(ScopeCase::Kebab);
+        assert!(CommitMessage::validate_scope("my-scope", &kebab).is_ok());
+        assert!(CommitMessage::validate_scope("api2", &kebab).is_ok());
+        for scope in ["my_scope", "my--scope", "-scope", "myScope"] {
+            assert!(
+                CommitMessage::validate_scope(scope, &kebab).is_err(),
+                "{}",
+                scope
+            );
+        }
+        // Lower still allows underscores.
+        assert!(CommitMessage::validate_scope("my_scope", &with_case(ScopeCase::Lower)).is_ok());
+    }
+
+    #[test]
+    fn case_policy_applies_to_each_path_segment() {
+        let config = ValidationConfig {
+            allow_path_scopes: true,
+            ..with_case(ScopeCase::Kebab)
+        };
+        assert!(CommitMessage::validate_scope("packages/my-api", &config).is_ok());
+        assert!(CommitMessage::validate_scope("packages/MyApi", &config).is_err());
+    }
+
+    #[test]
+    fn scope_is_allowed_when_not_on_deny_list() {
+        let config = ValidationConfig::default();
+        assert!(CommitMessage::validate_scope("misc", &config).is_ok());
+    }
+
+    #[test]
+    fn charset_is_checked_before_deny_list() {
+        let config = ValidationConfig {
+            forbidden_scopes: vec!["mi sc".into()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            CommitMessage::validate_scope("mi sc", &config),
+            Err(DomainError::InvalidScope(_))
+        ));
+    }
+
+    #[test]
+    fn path_scope_round_trips_through_compiler() {
+        let msg = from_ast_with("feat(packages/api): x", &path_scopes()).unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(packages/api): x");
+    }
+
+    #[test]
+    fn gitmoji_round_trips_through_compiler() {
+        let config = ValidationConfig {
+            gitmoji: true,
+            ..Default::default()
+        };
+        for input in ["✨ feat: add x", ":bug: fix(parser)!: x", "🦄 chore: x"] {
+            let msg = from_ast_with(input, &config).unwrap();
+            assert_eq!(msg.to_conventional_commit(), input);
+        }
+    }
+
+    #[test]
+    fn with_gitmoji_prefixes_header() {
+        let msg = CommitMessage::new(
+            CommitType::Feat,
+            None,
+            "add x".into(),
+            None,
+            None,
+            no_footers(),
+            &ValidationConfig::default(),
+        )
+        .unwrap()
+        .with_gitmoji(Some("✨".into()));
+        assert_eq!(msg.to_conventional_commit(), "✨ feat: add x");
+        assert_eq!(
+            msg.with_gitmoji(None).to_conventional_commit(),
+            "feat: add x"
+        );
+    }
+
+    #[test]
+    fn invalid_scope_fails() {
+        assert!(matches!(
+            CommitMessage::new(
+                CommitType::Feat,
+                Some("bad scope!".into()),
+                "desc".into(),
+                None,
+                None,
+                no_footers(),
+                &ValidationConfig::default(),
+            ),
+            Err(DomainError::InvalidScope(_))
+        ));
+    }
+
+    #[test]
+    fn empty_body_fails() {
+        assert!(matches!(
+            CommitMessage::new(
+                CommitType::Feat,
+                None,
+                "desc".into(),
+                Some("  ".into()),
+                None,
+                no_footers(),
+                &ValidationConfig::default(),
+            ),
+            Err(DomainError::EmptyBody)
+        ));
+    }
+
+    #[test]
+    fn empty_breaking_change_fails() {
+        assert!(matches!(
+            CommitMessage::new(
+                CommitType::Feat,
+                None,
+                "desc".into(),
+                None,
+                Some("".into()),
+                no_footers(),
+                &ValidationConfig::default(),
+            ),
+            Err(DomainError::EmptyBreakingChange)
+        ));
+    }
+
+    #[test]
+    fn renders_minimal() {
+        let msg = CommitMessage::new(
+            CommitType::Feat,
+            None,
+            "add feature".into(),
+            None,
+            None,
+            no_footers(),
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat: add feature");
+    }
+
+    #[test]
+    fn renders_with_scope() {
+        let msg = CommitMessage::new(
+            CommitType::Fix,
+            Some("parser".into()),
+            "fix bug".into(),
+            None,
+            None,
+            no_footers(),
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix(parser): fix bug");
+    }
+
+    #[test]
+    fn renders_with_body() {
+        let msg = CommitMessage::new(
+            CommitType::Feat,
+            None,
+            "add feature".into(),
+            Some("This is the body".into()),
+            None,
+            no_footers(),
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat: add feature\n\nThis is the body"
+        );
+    }
+
+    #[test]
+    fn renders_with_breaking_change_only() {
+        let msg = CommitMessage::new(
+            CommitType::Feat,
+            Some("api".into()),
+            "change endpoint".into(),
+            None,
+            Some("Removes v1 API".into()),
+            no_footers(),
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(api)!: change endpoint\n\nBREAKING CHANGE: Removes v1 API"
+        );
+    }
+
+    #[test]
+    fn renders_refs_footer() {
+        let msg = CommitMessage::new(
+            CommitType::Fix,
+            None,
+            "patch null pointer".into(),
+            None,
+            None,
+            vec![("Refs".into(), "#42".into())],
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "fix: patch null pointer\n\nRefs: #42"
+        );
+    }
+
+    #[test]
+    fn renders_multiple_footers_in_order() {
+        let msg = CommitMessage::new(
+            CommitType::Fix,
+            None,
+            "patch thing".into(),
+            None,
+            None,
+            vec![
+                ("Refs".into(), "#42".into()),
+                ("Closes".into(), "#99".into()),
+            ],
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        let out = msg.to_conventional_commit();
+        assert!(out.find("Refs:").unwrap() < out.find("Closes:").unwrap());
+    }
+
+    #[test]
+    fn duplicate_single_valued_footer_fails() {
+        assert_eq!(
+            from_ast_with("fix: x\n\nRefs: #1\nRefs: #2", &ValidationConfig::default())
+                .unwrap_err(),
+            DomainError::DuplicateFooter("Refs".into())
+        );
+    }
+
+    #[test]
+    fn repeatable_trailers_may_repeat() {
+        let msg = from_ast_with(
+            "fix: x\n\nCo-authored-by: A <a@x>\nCo-authored-by: B <b@x>\nRefs: #1",
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert!(
+            msg.to_conventional_commit()
+                .contains("Co-authored-by: B <b@x>")
+        );
+    }
+
+    #[test]
+    fn strict_issue_reference_forms() {
+        for valid in [
+            "#123",
+            "GH-123",
+            "tgenericx/commando#123",
+            "https://github.com/tgenericx/commando/issues/123",
+        ] {
+            assert_eq!(
+                CommitMessage::validate_issue_reference(valid),
+                Ok(()),
+                "{}",
+                valid
+            );
+        }
+        for invalid in [
+            "tracker",
+            "#",
+            "#12a",
+            "commando#12",
+            "https://github.com/tgenericx/commando/pull/12",
+            "https://gitlab.com/org/repo/issues/12",
+        ] {
+            assert_eq!(
+                CommitMessage::validate_issue_reference(invalid),
+                Err(DomainError::InvalidIssueReference(invalid.into())),
+            );
+        }
+    }
+
+    fn with_refs(refs: &str, strict: bool) -> Result<CommitMessage, DomainError> {
+        let config = ValidationConfig {
+            strict_issue_refs: strict,
+            ..Default::default()
+        };
+        CommitMessage::new(
+            CommitType::Fix,
+            None,
+            "handle timeouts".into(),
+            None,
+            None,
+            vec![("Closes".into(), refs.into())],
+            &config,
+        )
+    }
+
+    #[test]
+    fn issue_refs_reject_bare_text_as_the_parser_does() {
+        for strict in [false, true] {
+            assert_eq!(
+                with_refs("see the tracker", strict),
+                Err(DomainError::InvalidIssueReference("see".into()))
+            );
+            assert!(with_refs("#1, GH-2 org/repo#3", strict).is_ok());
+        }
+    }
+
+    #[test]
+    fn strict_issue_refs_narrow_urls_to_github_issues() {
+        let url = "https://tracker.example.com/browse/API-12";
+        assert!(with_refs(url, false).is_ok());
+        assert_eq!(
+            with_refs(url, true),
+            Err(DomainError::InvalidIssueReference(url.into()))
+        );
+    }
+
+    #[test]
+    fn footers_must_have_the_shape_the_lexer_reads() {
+        let with_footer = |key: &str, value: &str| {
+            CommitMessage::new(
+                CommitType::Feat,
+                None,
+                "x".into(),
+                None,
+                None,
+                vec![(key.into(), value.into())],
+                &ValidationConfig::default(),
+            )
+        };
+        assert!(with_footer("Reviewed-by", "Jane").is_ok());
+        for (key, value) in [
+            ("bad key", "x"),
+            ("reviewed-by", "Jane"),
+            ("Key:", "x"),
+            ("Reviewed-by", " "),
+            ("Reviewed-by", "Jane\nSigned-off-by: Joe"),
+        ] {
+            assert_eq!(
+                with_footer(key, value),
+                Err(DomainError::InvalidFooter(key.into(), value.into())),
+                "{:?}",
+                (key, value)
+            );
+        }
+    }
+
+    #[test]
+    fn header_fields_must_stay_on_one_line() {
+        let new = |scope: Option<&str>, description: &str| {
+            CommitMessage::new(
+                CommitType::Feat,
+                scope.map(Into::into),
+                description.into(),
+                None,
+                None,
+                no_footers(),
+                &ValidationConfig::default(),
+            )
+        };
+        assert_eq!(
+            new(None, "x\nBREAKING CHANGE: y"),
+            Err(DomainError::ControlCharacter("description"))
+        );
+        assert_eq!(
+            new(None, "x\u{7}"),
+            Err(DomainError::ControlCharacter("description"))
+        );
+        assert_eq!(
+            new(Some("api\n"), "x"),
+            Err(DomainError::ControlCharacter("scope"))
+        );
+        assert!(new(None, "align\tcolumns").is_ok());
+    }
+
+    #[test]
+    fn strict_issue_refs_applies_to_compiled_messages() {
+        let config = ValidationConfig {
+            strict_issue_refs: true,
+            ..Default::default()
+        };
+        // The compiler accepts any URL; the strict rule wants a GitHub issue.
+        let ast = crate::compiler::CompilerPipeline::new()
+            .compile("fix: handle timeouts\n\nRefs: https://jira.example.com/PROJ-1")
+            .unwrap();
+        assert_eq!(
+            CommitMessage::from_ast(ast, &config),
+            Err(DomainError::InvalidIssueReference(
+                "https://jira.example.com/PROJ-1".into()
+            ))
+        );
+        let ast = crate::compiler::CompilerPipeline::new()
+            .compile("fix: handle timeouts\n\nRefs: https://github.com/org/repo/issues/9")
+            .unwrap();
+        assert!(CommitMessage::from_ast(ast, &config).is_ok());
+    }
+
+    #[test]
+    fn single_footers_are_configurable() {
+        let config = ValidationConfig {
+            single_footers: vec!["reviewed-on".into()],
+            ..Default::default()
+        };
+        assert!(from_ast_with("fix: x\n\nRefs: #1\nRefs: #2", &config).is_ok());
+        assert_eq!(
+            from_ast_with("fix: x\n\nReviewed-on: a\nReviewed-on: b", &config).unwrap_err(),
+            DomainError::DuplicateFooter("Reviewed-on".into())
+        );
+    }
+
+    fn shuffled_footers() -> Vec<(String, String)> {
+        [
+            ("Signed-off-by", "A <a@x>"),
+            ("Reviewed-on", "https://review/1"),
+            ("Refs", "#1"),
+            ("Co-authored-by", "B <b@x>"),
+            ("Closes", "#2"),
+            ("Acked-by", "C"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+    }
+
+    fn spaced_body(body: &str, config: &ValidationConfig) -> CommitMessage {
+        CommitMessage::new(
+            CommitType::Docs,
+            None,
+            "document setup".into(),
+            Some(body.into()),
+            None,
+            vec![("Refs".into(), "#7".into())],
+            config,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn header_format_templates_the_rendered_header() {
+        let render = |template: &str, scope: Option<&str>| {
+            let config = ValidationConfig {
+                header_format: HeaderFormat::parse(template).unwrap(),
+                ..Default::default()
+            };
+            CommitMessage::new(
+                CommitType::Feat,
+                scope.map(String::from),
+                "add search".into(),
+                None,
+                Some("v1 removed".into()),
+                no_footers(),
+                &config,
+            )
+            .unwrap()
+            .to_conventional_commit()
+        };
+        let footer = "\n\nBREAKING CHANGE: v1 removed";
+
+        let angular = "{type}[({scope})]{breaking}: {description}";
+        assert_eq!(
+            render(angular, Some("api")),
+            format!("feat(api)!: add search{}", footer)
+        );
+        assert_eq!(
+            render(angular, None),
+            format!("feat!: add search{}", footer)
+        );
+
+        let slashed = "{type}[/{scope}]: {description}";
+        assert_eq!(
+            render(slashed, Some("api")),
+            format!("feat/api: add search{}", footer)
+        );
+        assert_eq!(render(slashed, None), format!("feat: add search{}", footer));
+    }
+
+    #[test]
+    fn strips_trailing_whitespace_and_leading_tabs() {
+        let body = "Run it like this:   \n\n\tcargo run\t \n \t\tnested\tcolumn\nDone.";
+        assert_eq!(
+            spaced_body(body, &ValidationConfig::default()).to_conventional_commit(),
+            [
+                "docs: document setup",
+                "",
+                "Run it like this:",
+                "",
+                "    cargo run",
+                "         nested\tcolumn",
+                "Done.",
+                "",
+                "Refs: #7",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn whitespace_is_kept_when_stripping_is_off() {
+        let config = ValidationConfig {
+            strip_whitespace: false,
+            ..Default::default()
+        };
+        let body = "text   \n\tindented";
+        assert_eq!(
+            spaced_body(body, &config).to_conventional_commit(),
+            "docs: document setup\n\ntext   \n\tindented\n\nRefs: #7"
+        );
+    }
+
+    #[test]
+    fn group_footers_renders_canonical_order() {
+        let config = ValidationConfig {
+            group_footers: true,
+            ..Default::default()
+        };
+        let msg = CommitMessage::new(
+            CommitType::Fix,
+            None,
+            "x".into(),
+            None,
+            Some("gone".into()),
+            shuffled_footers(),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "fix!: x\n\n\
+             BREAKING CHANGE: gone\n\
+             Refs: #1\n\
+             Closes: #2\n\
+             Signed-off-by: A <a@x>\n\
+             Co-authored-by: B <b@x>\n\
+             Reviewed-on: https://review/1\n\
+             Acked-by: C"
+        );
+    }
+
+    fn with_footers(footers: &[(&str, &str)]) -> String {
+        CommitMessage::new(
+            CommitType::Fix,
+            None,
+            "x".into(),
+            None,
+            None,
+            footers
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            &ValidationConfig::default(),
+        )
+        .unwrap()
+        .to_conventional_commit()
+    }
+
+    #[test]
+    fn duplicate_ref_within_one_footer_is_dropped() {
+        assert_eq!(
+            with_footers(&[("Closes", "#12, #13, #12")]),
+            "fix: x\n\nCloses: #12, #13"
+        );
+    }
+
+    #[test]
+    fn duplicate_ref_across_footers_keeps_the_first() {
+        assert_eq!(
+            with_footers(&[
+                ("Closes", "#12"),
+                ("Signed-off-by", "A <a@x>"),
+                ("Closes", "#12"),
+                ("Refs", "#12 #14"),
+            ]),
+            "fix: x\n\nCloses: #12\nSigned-off-by: A <a@x>\nRefs: #14"
+        );
+    }
+
+    #[test]
+    fn refs_without_duplicates_are_left_as_written() {
+        assert_eq!(
+            with_footers(&[("Refs", "#1 #2"), ("Fixes", "GH-3")]),
+            "fix: x\n\nRefs: #1 #2\nFixes: GH-3"
+        );
+    }
+
+    #[test]
+    fn footers_keep_input_order_by_default() {
+        let msg = from_ast_with(
+            "fix: x\n\nSigned-off-by: A <a@x>\nRefs: #1",
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        assert!(
+            msg.to_conventional_commit()
+                .ends_with("Signed-off-by: A <a@x>\nRefs: #1")
+        );
+    }
+
+    #[test]
+    fn grouped_signoff_still_precedes_other_footers() {
+        let config = ValidationConfig {
+            group_footers: true,
+            ..Default::default()
+        };
+        let msg = from_ast_with("fix: x\n\nAcked-by: C\nRefs: #1", &config)
+            .unwrap()
+            .with_signoff("A <a@x>".into());
+        assert!(
+            msg.to_conventional_commit()
+                .ends_with("Refs: #1\nSigned-off-by: A <a@x>\nAcked-by: C")
+        );
+    }
+
+    #[test]
+    fn renders_breaking_change_before_other_footers() {
         let msg = CommitMessage::new(
             CommitType::Feat,
+            Some("api".into()),
+            "redesign".into(),
             None,
-            "add feature".into(),
-            None,
-            None,
-            no_footers(),
+            Some("v1 removed".into()),
+            vec![("Refs".into(), "#88".into())],
+            &ValidationConfig::default(),
         )
         .unwrap();
-        assert_eq!(msg.to_conventional_commit(), "feat: add feature");
+        let out = msg.to_conventional_commit();
+        assert!(out.find("BREAKING CHANGE:").unwrap() < out.find("Refs:").unwrap());
     }
 
     #[test]
-    fn renders_with_scope() {
+    fn renders_full_commit() {
         let msg = CommitMessage::new(
-            CommitType::Fix,
-            Some("parser".into()),
-            "fix bug".into(),
+            CommitType::Feat,
+            Some("auth".into()),
+            "implement OAuth".into(),
+            Some("Added OAuth 2.0 support".into()),
+            Some("Old sessions removed".into()),
+            vec![("Refs".into(), "#142".into())],
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        let expected = "feat(auth)!: implement OAuth\n\n\
+                        Added OAuth 2.0 support\n\n\
+                        BREAKING CHANGE: Old sessions removed\n\
+                        Refs: #142";
+        assert_eq!(msg.to_conventional_commit(), expected);
+    }
+
+    // ── body wrapping ─────────────────────────────────────────────────────────
+
+    fn with_body(body: &str) -> CommitMessage {
+        CommitMessage::new(
+            CommitType::Docs,
             None,
+            "explain wrapping".into(),
+            Some(body.into()),
             None,
-            no_footers(),
+            vec![("Refs".into(), "#1".into())],
+            &ValidationConfig::default(),
         )
-        .unwrap();
-        assert_eq!(msg.to_conventional_commit(), "fix(parser): fix bug");
+        .unwrap()
     }
 
     #[test]
-    fn renders_with_body() {
-        let msg = CommitMessage::new(
+    fn wraps_long_single_paragraph_body() {
+        let body = "one two three four five six seven eight nine ten eleven twelve";
+        let out = with_body(body).to_wrapped(20);
+        let wrapped_body: Vec<&str> = out.split("\n\n").nth(1).unwrap().lines().collect();
+        assert_eq!(
+            wrapped_body,
+            vec![
+                "one two three four",
+                "five six seven eight",
+                "nine ten eleven",
+                "twelve"
+            ]
+        );
+        assert!(wrapped_body.iter().all(|l| l.chars().count() <= 20));
+    }
+
+    #[test]
+    fn does_not_break_long_url() {
+        let url = "https://example.com/a/very/long/path/that/exceeds/the/width";
+        let body = format!("See {} for details.", url);
+        let out = with_body(&body).to_wrapped(20);
+        assert!(out.lines().any(|l| l == url));
+    }
+
+    #[test]
+    fn wrapping_preserves_paragraph_breaks_and_short_lines() {
+        let body = "Short line.\n- item one\n\nSecond paragraph here.";
+        let out = with_body(body).to_wrapped(72);
+        assert_eq!(out, with_body(body).to_conventional_commit());
+    }
+
+    #[test]
+    fn wrapping_keeps_indentation_on_continuation_lines() {
+        let out = with_body("  indented words that go past the width").to_wrapped(20);
+        let body_lines: Vec<&str> = out.split("\n\n").nth(1).unwrap().lines().collect();
+        assert!(body_lines.iter().all(|l| l.starts_with("  ")));
+    }
+
+    #[test]
+    fn wrapping_leaves_header_and_footers_untouched() {
+        let out = with_body(&"word ".repeat(40)).to_wrapped(10);
+        assert!(out.starts_with("docs: explain wrapping\n\n"));
+        assert!(out.ends_with("\n\nRefs: #1"));
+    }
+
+    // ── mood ──────────────────────────────────────────────────────────────────
+
+    fn described(description: &str) -> CommitMessage {
+        CommitMessage::new(
             CommitType::Feat,
             None,
-            "add feature".into(),
-            Some("This is the body".into()),
+            description.into(),
+            None,
             None,
             no_footers(),
+            &ValidationConfig::default(),
         )
-        .unwrap();
+        .unwrap()
+    }
+
+    #[test]
+    fn report_keeps_warnings_beside_a_hard_error() {
+        use crate::compiler::CompilerPipeline;
+        let ast = CompilerPipeline::new()
+            .compile("feat(Bad): added login.")
+            .unwrap();
+        let report = CommitMessage::validate_report(ast, &ValidationConfig::default());
+        assert_eq!(
+            report.errors,
+            [DomainError::ScopeCase("Bad".into(), ScopeCase::Lower)]
+        );
+        assert_eq!(
+            report.warnings,
+            [
+                "Use 'add' instead of 'added'",
+                "Description ends with a period"
+            ]
+        );
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn report_does_not_warn_about_a_rejected_period() {
+        use crate::compiler::CompilerPipeline;
+        let config = ValidationConfig {
+            reject_trailing_period: true,
+            ..Default::default()
+        };
+        let ast = CompilerPipeline::new().compile("feat: add login.").unwrap();
+        let report = CommitMessage::validate_report(ast, &config);
+        assert_eq!(report.errors, [DomainError::SubjectEndsWithPeriod]);
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn warnings_include_the_header_length() {
+        let config = ValidationConfig {
+            max_header_length: Some(10),
+            ..Default::default()
+        };
+        let msg = header_of(20, &config);
+        assert_eq!(msg.warnings().len(), 1);
+        assert!(msg.warnings()[0].starts_with("Header is"));
+    }
+
+    #[test]
+    fn check_mood_flags_past_tense() {
+        assert_eq!(
+            described("added login").check_mood(),
+            Some("Use 'add' instead of 'added'".into())
+        );
+    }
+
+    #[test]
+    fn check_mood_flags_third_person() {
+        assert_eq!(
+            described("adds login").check_mood(),
+            Some("Use 'add' instead of 'adds'".into())
+        );
+    }
+
+    #[test]
+    fn check_mood_accepts_imperative() {
+        assert_eq!(described("add login").check_mood(), None);
+    }
+
+    // ── parse ─────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn parse_goes_straight_from_a_string() {
+        let msg = CommitMessage::parse("feat(api): add search\n\nRefs: #1").unwrap();
         assert_eq!(
             msg.to_conventional_commit(),
-            "feat: add feature\n\nThis is the body"
+            "feat(api): add search\n\nRefs: #1"
         );
     }
 
     #[test]
-    fn renders_with_breaking_change_only() {
-        let msg = CommitMessage::new(
+    fn parse_tells_compile_errors_from_domain_errors() {
+        assert!(matches!(
+            CommitMessage::parse("feat add search"),
+            Err(CommandoError::Compile(_))
+        ));
+        assert!(matches!(
+            CommitMessage::parse("feature: add search"),
+            Err(CommandoError::Domain(DomainError::InvalidCommitType(..)))
+        ));
+    }
+
+    #[test]
+    fn parse_with_applies_the_config() {
+        let config = ValidationConfig {
+            max_description_length: 5,
+            ..Default::default()
+        };
+        let err = CommitMessage::parse_with("fix: too long", &CompilerPipeline::new(), &config)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CommandoError::Domain(DomainError::DescriptionTooLong { .. })
+        ));
+        assert!(err.to_string().contains("too long"));
+    }
+
+    // ── header length ─────────────────────────────────────────────────────────
+
+    /// "feat(api): " plus `len - 11` x's: a header of exactly `len` chars.
+    fn header_of(len: usize, config: &ValidationConfig) -> CommitMessage {
+        let config = ValidationConfig {
+            max_description_length: 200,
+            ..config.clone()
+        };
+        CommitMessage::new(
             CommitType::Feat,
             Some("api".into()),
-            "change endpoint".into(),
+            "x".repeat(len - 11),
+            Some("Body lines do not count.".into()),
             None,
-            Some("Removes v1 API".into()),
             no_footers(),
+            &config,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn getters_expose_every_field() {
+        let msg = CommitMessage::new(
+            CommitType::Feat,
+            Some("api".into()),
+            "add search".into(),
+            Some("Served from /v2/search.".into()),
+            Some("v1 search is gone".into()),
+            vec![("Refs".into(), "#42".into())],
+            &ValidationConfig::default(),
         )
         .unwrap();
+        assert_eq!(msg.commit_type(), &CommitType::Feat);
+        assert_eq!(msg.scope(), Some("api"));
+        assert_eq!(msg.description(), "add search");
+        assert_eq!(msg.body(), Some("Served from /v2/search."));
+        assert_eq!(msg.breaking_change(), Some("v1 search is gone"));
+        assert_eq!(msg.subject(), "feat(api)!: add search");
+    }
+
+    #[test]
+    fn getters_of_a_minimal_commit() {
+        let msg = CommitMessage::parse("fix!: drop tabs").unwrap();
+        assert_eq!(msg.scope(), None);
+        assert_eq!(msg.body(), None);
+        assert_eq!(msg.breaking_change(), None);
+        assert!(msg.is_breaking());
+        assert_eq!(msg.subject(), "fix!: drop tabs");
+    }
+
+    #[test]
+    fn header_length_counts_the_whole_line() {
+        let msg = header_of(40, &ValidationConfig::default());
+        assert_eq!(msg.header_length(), 40);
+        let msg = msg.with_gitmoji(Some("✨".into()));
+        assert_eq!(msg.header_length(), 42);
+    }
+
+    #[test]
+    fn header_at_the_limit_is_not_flagged() {
+        let msg = header_of(100, &ValidationConfig::default());
+        assert_eq!(msg.check_header_length(), None);
+    }
+
+    #[test]
+    fn header_over_the_limit_is_flagged() {
+        let msg = header_of(101, &ValidationConfig::default());
         assert_eq!(
-            msg.to_conventional_commit(),
-            "feat(api)!: change endpoint\n\nBREAKING CHANGE: Removes v1 API"
+            msg.check_header_length(),
+            Some(
+                "Header is 101 characters; GitHub and narrow terminals cut it off after 100".into()
+            )
         );
     }
 
     #[test]
-    fn renders_refs_footer() {
+    fn header_length_warning_can_be_disabled() {
+        let config = ValidationConfig {
+            max_header_length: None,
+            ..Default::default()
+        };
+        assert_eq!(header_of(150, &config).check_header_length(), None);
+    }
+
+    // ── sign-off ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn signoff_renders_as_last_trailer() {
+        let msg = CommitMessage::new(
+            CommitType::Feat,
+            Some("api".into()),
+            "redesign".into(),
+            Some("Body.".into()),
+            Some("v1 removed".into()),
+            vec![("Refs".into(), "#88".into())],
+            &ValidationConfig::default(),
+        )
+        .unwrap()
+        .with_signoff("Jane Doe <jane@example.com>".into());
+        let expected = "feat(api)!: redesign\n\n\
+                        Body.\n\n\
+                        BREAKING CHANGE: v1 removed\n\
+                        Refs: #88\n\
+                        Signed-off-by: Jane Doe <jane@example.com>";
+        assert_eq!(msg.to_conventional_commit(), expected);
+    }
+
+    #[test]
+    fn signoff_without_other_footers() {
         let msg = CommitMessage::new(
             CommitType::Fix,
             None,
-            "patch null pointer".into(),
+            "patch".into(),
             None,
             None,
-            vec![("Refs".into(), "#42".into())],
+            no_footers(),
+            &ValidationConfig::default(),
         )
-        .unwrap();
+        .unwrap()
+        .with_signoff("A <a@x>".into());
         assert_eq!(
             msg.to_conventional_commit(),
-            "fix: patch null pointer\n\nRefs: #42"
+            "fix: patch\n\nSigned-off-by: A <a@x>"
         );
     }
 
     #[test]
-    fn renders_multiple_footers_in_order() {
+    fn signoff_is_not_duplicated() {
         let msg = CommitMessage::new(
             CommitType::Fix,
             None,
-            "patch thing".into(),
+            "patch".into(),
             None,
             None,
-            vec![
-                ("Refs".into(), "#42".into()),
-                ("Closes".into(), "#99".into()),
-            ],
+            vec![("Signed-off-by".into(), "A <a@x>".into())],
+            &ValidationConfig::default(),
         )
-        .unwrap();
-        let out = msg.to_conventional_commit();
-        assert!(out.find("Refs:").unwrap() < out.find("Closes:").unwrap());
+        .unwrap()
+        .with_signoff("A <a@x>".into());
+        assert_eq!(
+            msg.to_conventional_commit()
+                .matches("Signed-off-by")
+                .count(),
+            1
+        );
     }
 
+    // ── co-authors ────────────────────────────────────────────────────────────
+
     #[test]
-    fn renders_breaking_change_before_other_footers() {
+    fn co_authors_render_after_body_and_breaking_change() {
         let msg = CommitMessage::new(
             CommitType::Feat,
-            Some("api".into()),
-            "redesign".into(),
             None,
+            "pair on parser".into(),
+            Some("Body.".into()),
             Some("v1 removed".into()),
             vec![("Refs".into(), "#88".into())],
+            &ValidationConfig::default(),
         )
-        .unwrap();
-        let out = msg.to_conventional_commit();
-        assert!(out.find("BREAKING CHANGE:").unwrap() < out.find("Refs:").unwrap());
+        .unwrap()
+        .with_co_author("Jane Doe <jane@example.com>".into())
+        .with_co_author("Sam Roe <sam@example.com>".into())
+        .with_signoff("A <a@x>".into());
+        let expected = "feat!: pair on parser\n\n\
+                        Body.\n\n\
+                        BREAKING CHANGE: v1 removed\n\
+                        Refs: #88\n\
+                        Co-authored-by: Jane Doe <jane@example.com>\n\
+                        Co-authored-by: Sam Roe <sam@example.com>\n\
+                        Signed-off-by: A <a@x>";
+        assert_eq!(msg.to_conventional_commit(), expected);
     }
 
     #[test]
-    fn renders_full_commit() {
-        let msg = CommitMessage::new(
-            CommitType::Feat,
-            Some("auth".into()),
-            "implement OAuth".into(),
-            Some("Added OAuth 2.0 support".into()),
-            Some("Old sessions removed".into()),
-            vec![("Refs".into(), "#142".into())],
-        )
-        .unwrap();
-        let expected = "feat(auth)!: implement OAuth\n\n\
-                        Added OAuth 2.0 support\n\n\
-                        BREAKING CHANGE: Old sessions removed\n\
-                        Refs: #142";
-        assert_eq!(msg.to_conventional_commit(), expected);
+    fn parse_co_author_normalizes_spacing() {
+        assert_eq!(
+            CommitMessage::parse_co_author("  Jane   Doe<jane@example.com > "),
+            Ok("Jane Doe <jane@example.com>".into())
+        );
+    }
+
+    #[test]
+    fn parse_co_author_rejects_malformed_entries() {
+        for input in [
+            "no-brackets",
+            "Jane Doe jane@example.com",
+            "<jane@example.com>",
+            "Jane <jane>",
+            "Jane <jane@example.com",
+            "Jane <ja ne@example.com>",
+        ] {
+            assert_eq!(
+                CommitMessage::parse_co_author(input),
+                Err(DomainError::InvalidCoAuthor(input.trim().into())),
+                "{}",
+                input
+            );
+        }
     }
 
     // ── TryFrom<CommitAst> ────────────────────────────────────────────────────
 
+    #[test]
+    fn multi_paragraph_body_round_trips_through_compiler() {
+        use crate::compiler::CompilerPipeline;
+        let input = "feat: x\n\nPara one.\n\nPara two.\n\nRefs: #1";
+        let ast = CompilerPipeline::new().compile(input).unwrap();
+        let msg = CommitMessage::try_from(ast).unwrap();
+        assert_eq!(msg.to_conventional_commit(), input);
+    }
+
+    #[test]
+    fn arbitrary_trailers_round_trip_in_order() {
+        use crate::compiler::CompilerPipeline;
+        let input = "fix: x\n\nBREAKING CHANGE: gone\nCo-authored-by: A <a@x>\nRefs: #1";
+        let ast = CompilerPipeline::new().compile(input).unwrap();
+        let msg = CommitMessage::try_from(ast).unwrap();
+        assert_eq!(
+            msg.footers,
+            vec![
+                ("Co-authored-by".to_string(), "A <a@x>".to_string()),
+                ("Refs".to_string(), "#1".to_string()),
+            ]
+        );
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "fix!: x\n\nBREAKING CHANGE: gone\nCo-authored-by: A <a@x>\nRefs: #1"
+        );
+    }
+
+    #[test]
+    fn from_ast_accepts_allowed_custom_type_and_renders_it_verbatim() {
+        use crate::compiler::CompilerPipeline;
+        let ast = CompilerPipeline::new()
+            .compile("deps(cargo): bump clap")
+            .unwrap();
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        let msg = CommitMessage::from_ast(ast, &config).unwrap();
+        assert_eq!(msg.to_conventional_commit(), "deps(cargo): bump clap");
+    }
+
+    #[test]
+    fn from_ast_rejects_custom_type_outside_allow_list() {
+        use crate::compiler::CompilerPipeline;
+        let ast = CompilerPipeline::new().compile("wip: half done").unwrap();
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            CommitMessage::from_ast(ast, &config),
+            Err(DomainError::InvalidCommitType(..))
+        ));
+    }
+
     #[test]
     fn try_from_ast_invalid_type_is_domain_error() {
         use crate::compiler::CompilerPipeline;
@@ -402,7 +2706,23 @@ mod tests {
             .unwrap();
         assert!(matches!(
             CommitMessage::try_from(ast),
-            Err(DomainError::InvalidCommitType(_))
+            Err(DomainError::InvalidCommitType(..))
+        ));
+    }
+
+    #[test]
+    fn from_ast_honors_config_limit() {
+        use crate::compiler::CompilerPipeline;
+        let ast = CompilerPipeline::new()
+            .compile("feat: add a fairly long description")
+            .unwrap();
+        let config = ValidationConfig {
+            max_description_length: 10,
+            ..Default::default()
+        };
+        assert!(matches!(
+            CommitMessage::from_ast(ast, &config),
+            Err(DomainError::DescriptionTooLong { max: 10, .. })
         ));
     }
 }
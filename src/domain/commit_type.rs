@@ -2,9 +2,10 @@
 ///
 /// Represents the type of a conventional commit.
 /// All validation happens at construction time, making invalid states unrepresentable.
+/// Custom types only exist when a caller's allow-list names them.
 use crate::domain::error::DomainError;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CommitType {
     Feat,
     Fix,
@@ -17,11 +18,13 @@ pub enum CommitType {
     Ci,
     Chore,
     Revert,
+    /// A team-specific type from the allow-list, kept verbatim (e.g. "deps").
+    Custom(String),
 }
 
 impl CommitType {
     /// Returns the string representation of the commit type
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             CommitType::Feat => "feat",
             CommitType::Fix => "fix",
@@ -34,6 +37,65 @@ impl CommitType {
             CommitType::Ci => "ci",
             CommitType::Chore => "chore",
             CommitType::Revert => "revert",
+            CommitType::Custom(name) => name,
+        }
+    }
+
+    /// The built-in types, in the order menus present them.
+    pub const BUILTIN: [CommitType; 11] = [
+        CommitType::Feat,
+        CommitType::Fix,
+        CommitType::Docs,
+        CommitType::Style,
+        CommitType::Refactor,
+        CommitType::Perf,
+        CommitType::Test,
+        CommitType::Build,
+        CommitType::Ci,
+        CommitType::Chore,
+        CommitType::Revert,
+    ];
+
+    /// The built-in types, for callers that list them (menus, help text,
+    /// error messages) instead of spelling them out.
+    pub fn all() -> &'static [CommitType] {
+        &Self::BUILTIN
+    }
+
+    /// One-line summary shown next to the type in prompts and menus.
+    pub fn description(&self) -> &'static str {
+        match self {
+            CommitType::Feat => "new feature",
+            CommitType::Fix => "bug fix",
+            CommitType::Docs => "documentation only",
+            CommitType::Style => "formatting, whitespace",
+            CommitType::Refactor => "code restructuring",
+            CommitType::Perf => "performance improvement",
+            CommitType::Test => "adding or fixing tests",
+            CommitType::Build => "build system / dependencies",
+            CommitType::Ci => "CI configuration",
+            CommitType::Chore => "maintenance",
+            CommitType::Revert => "revert a previous commit",
+            CommitType::Custom(_) => "custom type",
+        }
+    }
+
+    /// The gitmoji conventionally paired with this type (gitmoji.dev).
+    /// Custom types have none.
+    pub fn gitmoji(&self) -> Option<&'static str> {
+        match self {
+            CommitType::Feat => Some("✨"),
+            CommitType::Fix => Some("🐛"),
+            CommitType::Docs => Some("📝"),
+            CommitType::Style => Some("🎨"),
+            CommitType::Refactor => Some("♻️"),
+            CommitType::Perf => Some("⚡️"),
+            CommitType::Test => Some("✅"),
+            CommitType::Build => Some("📦️"),
+            CommitType::Ci => Some("👷"),
+            CommitType::Chore => Some("🔧"),
+            CommitType::Revert => Some("⏪️"),
+            CommitType::Custom(_) => None,
         }
     }
 
@@ -45,8 +107,68 @@ impl CommitType {
         ]
     }
 
-    /// Parse a commit type from a string
-    pub fn from_str(s: &str) -> Result<Self, DomainError> {
+    /// The built-in or custom type closest to a mistyped one (`fxi` →
+    /// `fix`), within MAX_SUGGESTION_DISTANCE edits. None when nothing is
+    /// that close, or when the edits would rewrite most of the type (`a` is
+    /// not `ci`). Ties go to the built-ins.
+    pub fn suggest(typo: &str, custom_types: &[String]) -> Option<String> {
+        let typo = typo.to_lowercase();
+        Self::all_as_str()
+            .iter()
+            .copied()
+            .chain(custom_types.iter().map(String::as_str))
+            .map(|candidate| (edit_distance(&typo, &candidate.to_lowercase()), candidate))
+            .filter(|&(d, candidate)| d <= MAX_SUGGESTION_DISTANCE && d < candidate.chars().count())
+            .min_by_key(|&(d, _)| d)
+            .map(|(_, candidate)| candidate.to_string())
+    }
+
+    /// Parse a commit type, also accepting any of the given custom types.
+    ///
+    /// Built-ins win over custom entries of the same name. Custom matches are
+    /// case-insensitive and yield the allow-list's own spelling.
+    pub fn from_str_with_custom(s: &str, custom_types: &[String]) -> Result<Self, DomainError> {
+        s.parse::<Self>().or_else(|_| {
+            custom_types
+                .iter()
+                .find(|c| c.eq_ignore_ascii_case(s))
+                .map(|c| CommitType::Custom(c.clone()))
+                .ok_or_else(|| DomainError::InvalidCommitType(s.to_string(), custom_types.to_vec()))
+        })
+    }
+}
+
+/// How many edits apart a typo may be from the type it suggests.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Levenshtein distance, counting an adjacent swap (`fxi`/`fix`) as one edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    // d[i][j]: distance between the first i chars of a and first j of b.
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// Parse a built-in commit type (case-insensitive).
+impl std::str::FromStr for CommitType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "feat" => Ok(CommitType::Feat),
             "fix" => Ok(CommitType::Fix),
@@ -59,7 +181,7 @@ impl CommitType {
             "ci" => Ok(CommitType::Ci),
             "chore" => Ok(CommitType::Chore),
             "revert" => Ok(CommitType::Revert),
-            _ => Err(DomainError::InvalidCommitType(s.to_string())),
+            _ => Err(DomainError::InvalidCommitType(s.to_string(), Vec::new())),
         }
     }
 }
@@ -73,6 +195,7 @@ impl std::fmt::Display for CommitType {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn commit_type_from_str_valid() {
@@ -97,14 +220,71 @@ mod tests {
     fn commit_type_from_str_invalid() {
         assert!(matches!(
             CommitType::from_str("invalid"),
-            Err(DomainError::InvalidCommitType(_))
+            Err(DomainError::InvalidCommitType(..))
         ));
         assert!(matches!(
             CommitType::from_str("feature"),
-            Err(DomainError::InvalidCommitType(_))
+            Err(DomainError::InvalidCommitType(..))
         ));
     }
 
+    #[test]
+    fn custom_type_accepted_when_allowed() {
+        let custom = vec!["deps".to_string(), "wip".to_string()];
+        assert_eq!(
+            CommitType::from_str_with_custom("deps", &custom).unwrap(),
+            CommitType::Custom("deps".into())
+        );
+        assert_eq!(
+            CommitType::from_str_with_custom("WIP", &custom).unwrap(),
+            CommitType::Custom("wip".into())
+        );
+    }
+
+    #[test]
+    fn custom_type_rejected_when_not_allowed() {
+        assert!(matches!(
+            CommitType::from_str_with_custom("release", &["deps".to_string()]),
+            Err(DomainError::InvalidCommitType(..))
+        ));
+    }
+
+    #[test]
+    fn builtin_wins_over_custom_of_same_name() {
+        let custom = vec!["feat".to_string()];
+        assert_eq!(
+            CommitType::from_str_with_custom("feat", &custom).unwrap(),
+            CommitType::Feat
+        );
+    }
+
+    #[test]
+    fn custom_type_as_str_and_display() {
+        let ct = CommitType::Custom("release".into());
+        assert_eq!(ct.as_str(), "release");
+        assert_eq!(format!("{}", ct), "release");
+    }
+
+    #[test]
+    fn builtin_matches_all_as_str() {
+        let names: Vec<&str> = CommitType::BUILTIN.iter().map(|t| t.as_str()).collect();
+        assert_eq!(names, CommitType::all_as_str());
+    }
+
+    #[test]
+    fn every_type_has_a_description() {
+        assert_eq!(CommitType::Feat.description(), "new feature");
+        assert!(
+            CommitType::BUILTIN
+                .iter()
+                .all(|t| !t.description().is_empty())
+        );
+        assert_eq!(
+            CommitType::Custom("deps".into()).description(),
+            "custom type"
+        );
+    }
+
     #[test]
     fn commit_type_as_str() {
         assert_eq!(CommitType::Feat.as_str(), "feat");
@@ -118,6 +298,23 @@ mod tests {
         assert_eq!(format!("{}", CommitType::Fix), "fix");
     }
 
+    #[test]
+    fn commit_type_parses_with_str_parse() {
+        assert_eq!("feat".parse::<CommitType>(), Ok(CommitType::Feat));
+        assert_eq!(
+            "feature".parse::<CommitType>(),
+            Err(DomainError::InvalidCommitType("feature".into(), vec![]))
+        );
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for commit_type in CommitType::all() {
+            assert_eq!(commit_type.to_string().parse(), Ok(commit_type.clone()));
+        }
+        assert_eq!(CommitType::Custom("deps".into()).to_string(), "deps");
+    }
+
     #[test]
     fn commit_type_all_as_str() {
         let all = CommitType::all_as_str();
@@ -130,4 +327,62 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn all_lists_every_builtin_with_a_description() {
+        let all = CommitType::all();
+        assert_eq!(all.len(), 11);
+        assert_eq!(
+            all.iter().map(CommitType::as_str).collect::<Vec<_>>(),
+            CommitType::all_as_str()
+        );
+        assert!(all.iter().all(|t| !t.description().is_empty()));
+    }
+
+    #[test]
+    fn builtin_types_have_a_gitmoji() {
+        assert_eq!(CommitType::Feat.gitmoji(), Some("✨"));
+        assert_eq!(CommitType::Fix.gitmoji(), Some("🐛"));
+        assert!(CommitType::BUILTIN.iter().all(|t| t.gitmoji().is_some()));
+        assert_eq!(CommitType::Custom("deps".into()).gitmoji(), None);
+    }
+
+    #[test]
+    fn suggests_types_for_near_typos() {
+        let suggest = |typo| CommitType::suggest(typo, &[]);
+        assert_eq!(suggest("fxi").as_deref(), Some("fix"));
+        assert_eq!(suggest("feet").as_deref(), Some("feat"));
+        assert_eq!(suggest("docss").as_deref(), Some("docs"));
+        assert_eq!(suggest("Refactr").as_deref(), Some("refactor"));
+    }
+
+    #[test]
+    fn suggests_custom_types_too() {
+        let custom = ["release".to_string(), "deps".to_string()];
+        assert_eq!(
+            CommitType::suggest("relase", &custom).as_deref(),
+            Some("release")
+        );
+        assert_eq!(CommitType::suggest("dep", &custom).as_deref(), Some("deps"));
+        assert_eq!(CommitType::suggest("relase", &[]), None);
+        assert!(matches!(
+            CommitType::from_str_with_custom("relase", &custom),
+            Err(DomainError::InvalidCommitType(t, c)) if t == "relase" && c == custom
+        ));
+    }
+
+    #[test]
+    fn no_suggestion_for_unrelated_input() {
+        assert_eq!(CommitType::suggest("qqqq", &[]), None);
+        assert_eq!(CommitType::suggest("invalid", &[]), None);
+        assert_eq!(CommitType::suggest("a", &[]), None);
+    }
+
+    #[test]
+    fn swapped_letters_are_one_edit() {
+        assert_eq!(edit_distance("fxi", "fix"), 1);
+        assert_eq!(edit_distance("feet", "feat"), 1);
+        assert_eq!(edit_distance("", "ci"), 2);
+        assert_eq!(edit_distance("chore", "chore"), 0);
+    }
 }
@@ -2,40 +2,109 @@
 ///
 /// Defines all possible validation errors that can occur in the domain layer.
 use crate::domain::commit_type::CommitType;
+use crate::domain::validation::ScopeCase;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum DomainError {
     // Validation errors
-    InvalidCommitType(String),
+    /// (as typed, the config's custom types), for the suggestion and list.
+    InvalidCommitType(String, Vec<String>),
+    /// A known type in the wrong case: (as typed, expected).
+    TypeCase(String, String),
     EmptyDescription,
-    DescriptionTooLong(usize),
+    DescriptionTooLong {
+        len: usize,
+        max: usize,
+    },
+    DescriptionTooShort {
+        len: usize,
+        min: usize,
+    },
+    SubjectEndsWithPeriod,
+    /// A line break or other control character in a header field
+    /// ("description", "scope"), which would end the header early.
+    ControlCharacter(&'static str),
     InvalidScope(String),
+    ForbiddenScope(String),
+    ScopeCase(String, ScopeCase),
+    ScopeRequired(CommitType),
     EmptyBreakingChange,
+    BreakingChangeFooterRequired,
     EmptyBody,
+    BodyNotAllowed(CommitType),
+    BodyLineTooLong {
+        line: usize,
+        len: usize,
+        max: usize,
+    },
+    BodyTooLarge {
+        len: usize,
+        max: usize,
+    },
+    TooManyFooters {
+        count: usize,
+        max: usize,
+    },
+    DuplicateFooter(String),
+    /// A footer the parser would not read back: (key, value).
+    InvalidFooter(String, String),
+    InvalidCoAuthor(String),
+    InvalidIssueReference(String),
+    /// A warning promoted to an error by --strict.
+    Warning(String),
 }
 
 impl std::fmt::Display for DomainError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             // Validation errors
-            DomainError::InvalidCommitType(t) => {
-                let valid_types = CommitType::all_as_str().join(", ");
+            DomainError::InvalidCommitType(t, custom_types) => {
+                if let Some(suggestion) = CommitType::suggest(t, custom_types) {
+                    return write!(
+                        f,
+                        "Invalid commit type: '{}' — did you mean '{}'?",
+                        t, suggestion
+                    );
+                }
+                let valid_types = CommitType::all_as_str()
+                    .iter()
+                    .copied()
+                    .chain(custom_types.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join(", ");
                 write!(
                     f,
                     "Invalid commit type: '{}'. Must be one of: {}",
                     t, valid_types
                 )
             }
+            DomainError::TypeCase(typed, expected) => {
+                write!(
+                    f,
+                    "Commit type '{}' must be lowercase: use '{}'",
+                    typed, expected
+                )
+            }
             DomainError::EmptyDescription => {
                 write!(f, "Description cannot be empty")
             }
-            DomainError::DescriptionTooLong(len) => {
+            DomainError::DescriptionTooLong { len, max } => {
                 write!(
                     f,
-                    "Description is too long ({} characters). Maximum is 72 characters",
-                    len
+                    "Description is too long ({} characters). Maximum is {} characters",
+                    len, max
                 )
             }
+            DomainError::DescriptionTooShort { len, min } => {
+                write!(
+                    f,
+                    "Description is too short ({} characters). Minimum is {} characters",
+                    len, min
+                )
+            }
+            DomainError::SubjectEndsWithPeriod => {
+                write!(f, "Description must not end with a period")
+            }
             DomainError::InvalidScope(s) => {
                 write!(
                     f,
@@ -43,12 +112,85 @@ impl std::fmt::Display for DomainError {
                     s
                 )
             }
+            DomainError::ForbiddenScope(s) => {
+                write!(f, "Scope '{}' is not allowed in this project", s)
+            }
+            DomainError::ScopeCase(s, case) => match case {
+                ScopeCase::Kebab => write!(
+                    f,
+                    "Scope '{}' must be kebab-case (lowercase words joined by '-', e.g. my-scope)",
+                    s
+                ),
+                _ => write!(f, "Scope '{}' must be {}", s, case.as_str()),
+            },
+            DomainError::ScopeRequired(t) => {
+                write!(
+                    f,
+                    "Scope is required for '{}' commits, e.g. {}(api): ...",
+                    t.as_str(),
+                    t.as_str()
+                )
+            }
             DomainError::EmptyBreakingChange => {
                 write!(f, "Breaking change description cannot be empty")
             }
+            DomainError::BreakingChangeFooterRequired => {
+                write!(
+                    f,
+                    "A '!' breaking change needs a BREAKING CHANGE footer saying what broke"
+                )
+            }
             DomainError::EmptyBody => {
                 write!(f, "Body cannot be empty if provided")
             }
+            DomainError::BodyNotAllowed(t) => {
+                write!(
+                    f,
+                    "'{}' commits may not have a body: keep them to the header line",
+                    t.as_str()
+                )
+            }
+            DomainError::BodyLineTooLong { line, len, max } => {
+                write!(
+                    f,
+                    "Body line {} is too long ({} characters). Maximum is {} characters",
+                    line, len, max
+                )
+            }
+            DomainError::BodyTooLarge { len, max } => {
+                write!(
+                    f,
+                    "Body is too large ({} bytes). Maximum is {} bytes",
+                    len, max
+                )
+            }
+            DomainError::TooManyFooters { count, max } => {
+                write!(f, "Too many footers ({}). Maximum is {}", count, max)
+            }
+            DomainError::DuplicateFooter(key) => {
+                write!(f, "Footer '{}' may only appear once", key)
+            }
+            DomainError::InvalidFooter(key, value) => write!(
+                f,
+                "Invalid footer '{}: {}'. Use a capitalized key and a one-line value, e.g. 'Reviewed-by: Jane'",
+                key, value
+            ),
+            DomainError::InvalidCoAuthor(s) => {
+                write!(f, "Invalid co-author: '{}'. Expected 'Name <email>'", s)
+            }
+            DomainError::ControlCharacter(field) => write!(
+                f,
+                "The {} must be a single line without control characters",
+                field
+            ),
+            DomainError::Warning(w) => write!(f, "{}", w),
+            DomainError::InvalidIssueReference(r) => {
+                write!(
+                    f,
+                    "Invalid issue reference: '{}'. Use #123, GH-123, org/repo#123 or a GitHub issue URL",
+                    r
+                )
+            }
         }
     }
 }
@@ -61,11 +203,45 @@ mod tests {
 
     #[test]
     fn domain_error_display_invalid_commit_type() {
-        let error = DomainError::InvalidCommitType("invalid".to_string());
+        let error = DomainError::InvalidCommitType("invalid".to_string(), vec![]);
         let expected = "Invalid commit type: 'invalid'. Must be one of: feat, fix, docs, style, refactor, perf, test, build, ci, chore, revert";
         assert_eq!(error.to_string(), expected);
     }
 
+    #[test]
+    fn invalid_commit_type_suggests_the_nearest_type() {
+        for (typo, suggestion) in [("fxi", "fix"), ("feet", "feat"), ("docss", "docs")] {
+            assert_eq!(
+                DomainError::InvalidCommitType(typo.into(), vec![]).to_string(),
+                format!(
+                    "Invalid commit type: '{}' — did you mean '{}'?",
+                    typo, suggestion
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_commit_type_knows_the_custom_types() {
+        let custom = vec!["release".to_string()];
+        assert_eq!(
+            DomainError::InvalidCommitType("relase".into(), custom.clone()).to_string(),
+            "Invalid commit type: 'relase' — did you mean 'release'?"
+        );
+        assert_eq!(
+            DomainError::InvalidCommitType("qqqq".into(), custom).to_string(),
+            "Invalid commit type: 'qqqq'. Must be one of: feat, fix, docs, style, refactor, perf, test, build, ci, chore, revert, release"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_type_case() {
+        assert_eq!(
+            DomainError::TypeCase("Feat".into(), "feat".into()).to_string(),
+            "Commit type 'Feat' must be lowercase: use 'feat'"
+        );
+    }
+
     #[test]
     fn domain_error_display_empty_description() {
         let error = DomainError::EmptyDescription;
@@ -74,13 +250,31 @@ mod tests {
 
     #[test]
     fn domain_error_display_description_too_long() {
-        let error = DomainError::DescriptionTooLong(100);
+        let error = DomainError::DescriptionTooLong { len: 100, max: 72 };
         assert_eq!(
             error.to_string(),
             "Description is too long (100 characters). Maximum is 72 characters"
         );
     }
 
+    #[test]
+    fn domain_error_display_description_too_long_reports_configured_max() {
+        let error = DomainError::DescriptionTooLong { len: 82, max: 50 };
+        assert_eq!(
+            error.to_string(),
+            "Description is too long (82 characters). Maximum is 50 characters"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_description_too_short() {
+        let error = DomainError::DescriptionTooShort { len: 3, min: 10 };
+        assert_eq!(
+            error.to_string(),
+            "Description is too short (3 characters). Minimum is 10 characters"
+        );
+    }
+
     #[test]
     fn domain_error_display_invalid_scope() {
         let error = DomainError::InvalidScope("invalid!".to_string());
@@ -90,6 +284,106 @@ mod tests {
         );
     }
 
+    #[test]
+    fn domain_error_display_breaking_change_footer_required() {
+        assert_eq!(
+            DomainError::BreakingChangeFooterRequired.to_string(),
+            "A '!' breaking change needs a BREAKING CHANGE footer saying what broke"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_size_limits() {
+        assert_eq!(
+            DomainError::BodyTooLarge {
+                len: 2000,
+                max: 1024
+            }
+            .to_string(),
+            "Body is too large (2000 bytes). Maximum is 1024 bytes"
+        );
+        assert_eq!(
+            DomainError::TooManyFooters { count: 51, max: 50 }.to_string(),
+            "Too many footers (51). Maximum is 50"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_forbidden_scope() {
+        assert_eq!(
+            DomainError::ForbiddenScope("misc".into()).to_string(),
+            "Scope 'misc' is not allowed in this project"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_scope_case() {
+        assert_eq!(
+            DomainError::ScopeCase("Api".into(), ScopeCase::Lower).to_string(),
+            "Scope 'Api' must be lowercase"
+        );
+        assert_eq!(
+            DomainError::ScopeCase("my_scope".into(), ScopeCase::Kebab).to_string(),
+            "Scope 'my_scope' must be kebab-case (lowercase words joined by '-', e.g. my-scope)"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_scope_required() {
+        assert_eq!(
+            DomainError::ScopeRequired(CommitType::Feat).to_string(),
+            "Scope is required for 'feat' commits, e.g. feat(api): ..."
+        );
+    }
+
+    #[test]
+    fn domain_error_display_body_not_allowed() {
+        assert_eq!(
+            DomainError::BodyNotAllowed(CommitType::Chore).to_string(),
+            "'chore' commits may not have a body: keep them to the header line"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_duplicate_footer() {
+        assert_eq!(
+            DomainError::DuplicateFooter("Refs".into()).to_string(),
+            "Footer 'Refs' may only appear once"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_invalid_footer() {
+        assert_eq!(
+            DomainError::InvalidFooter("bad key".into(), "x".into()).to_string(),
+            "Invalid footer 'bad key: x'. Use a capitalized key and a one-line value, e.g. 'Reviewed-by: Jane'"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_invalid_co_author() {
+        assert_eq!(
+            DomainError::InvalidCoAuthor("no-brackets".into()).to_string(),
+            "Invalid co-author: 'no-brackets'. Expected 'Name <email>'"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_control_character() {
+        assert_eq!(
+            DomainError::ControlCharacter("description").to_string(),
+            "The description must be a single line without control characters"
+        );
+    }
+
+    #[test]
+    fn domain_error_display_invalid_issue_reference() {
+        assert_eq!(
+            DomainError::InvalidIssueReference("tracker".into()).to_string(),
+            "Invalid issue reference: 'tracker'. Use #123, GH-123, org/repo#123 or a GitHub issue URL"
+        );
+    }
+
     #[test]
     fn domain_error_display_empty_breaking_change() {
         let error = DomainError::EmptyBreakingChange;
@@ -104,4 +398,23 @@ mod tests {
         let error = DomainError::EmptyBody;
         assert_eq!(error.to_string(), "Body cannot be empty if provided");
     }
+
+    #[test]
+    fn domain_error_display_subject_ends_with_period() {
+        let error = DomainError::SubjectEndsWithPeriod;
+        assert_eq!(error.to_string(), "Description must not end with a period");
+    }
+
+    #[test]
+    fn domain_error_display_body_line_too_long() {
+        let error = DomainError::BodyLineTooLong {
+            line: 3,
+            len: 120,
+            max: 100,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Body line 3 is too long (120 characters). Maximum is 100 characters"
+        );
+    }
 }
@@ -0,0 +1,214 @@
+//! Header Format Template
+//!
+//! How the header line is rendered, for teams whose convention differs from
+//! `type(scope)!: description` — e.g. `{type}/{scope}: {description}`.
+//! Only rendering is templated: messages are still written and parsed in
+//! conventional syntax.
+//!
+//! Placeholders are `{type}`, `{scope}`, `{breaking}` (`!` or nothing) and
+//! `{description}`. Text in `[...]` is optional: the whole segment is left
+//! out when a placeholder inside it is empty, so `{type}[({scope})]` gives
+//! `feat(api)` or just `feat`, never `feat()`.
+
+/// The conventional header, the rendering used when no template is set.
+pub const CONVENTIONAL_HEADER: &str = "{type}[({scope})]{breaking}: {description}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Type,
+    Scope,
+    Breaking,
+    Description,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Text(String),
+    Field(Field),
+    /// A `[...]` segment: text and fields, no nested segments.
+    Optional(Vec<Piece>),
+}
+
+/// A parsed `header_format` template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderFormat {
+    pieces: Vec<Piece>,
+}
+
+/// The values substituted into a template.
+pub struct HeaderFields<'a> {
+    pub commit_type: &'a str,
+    pub scope: Option<&'a str>,
+    pub breaking: bool,
+    pub description: &'a str,
+}
+
+impl HeaderFormat {
+    /// Parse a template, rejecting unknown placeholders, unbalanced braces
+    /// or brackets, and templates without `{description}`.
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut pieces = Vec::new();
+        let mut segment: Option<Vec<Piece>> = None;
+        let mut text = String::new();
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => return Err(format!("unclosed '{{' in \"{}\"", template)),
+                        }
+                    }
+                    let field = match name.as_str() {
+                        "type" => Field::Type,
+                        "scope" => Field::Scope,
+                        "breaking" => Field::Breaking,
+                        "description" => Field::Description,
+                        _ => {
+                            return Err(format!(
+                                "unknown placeholder '{{{}}}' (use {{type}}, {{scope}}, {{breaking}} or {{description}})",
+                                name
+                            ));
+                        }
+                    };
+                    let current = segment.as_mut().unwrap_or(&mut pieces);
+                    flush(&mut text, current);
+                    current.push(Piece::Field(field));
+                }
+                '[' if segment.is_some() => {
+                    return Err(format!("nested '[' in \"{}\"", template));
+                }
+                '[' => {
+                    flush(&mut text, &mut pieces);
+                    segment = Some(Vec::new());
+                }
+                ']' => {
+                    let Some(mut inner) = segment.take() else {
+                        return Err(format!("unmatched ']' in \"{}\"", template));
+                    };
+                    flush(&mut text, &mut inner);
+                    pieces.push(Piece::Optional(inner));
+                }
+                '}' => return Err(format!("unmatched '}}' in \"{}\"", template)),
+                c => text.push(c),
+            }
+        }
+        if segment.is_some() {
+            return Err(format!("unclosed '[' in \"{}\"", template));
+        }
+        flush(&mut text, &mut pieces);
+
+        let format = Self { pieces };
+        if !format.has(Field::Description) {
+            return Err(format!("\"{}\" has no {{description}}", template));
+        }
+        Ok(format)
+    }
+
+    pub fn render(&self, fields: &HeaderFields) -> String {
+        let mut header = String::new();
+        for piece in &self.pieces {
+            match piece {
+                Piece::Optional(inner) => {
+                    // None as soon as one field inside is empty.
+                    let values: Option<Vec<String>> =
+                        inner.iter().map(|p| value(p, fields)).collect();
+                    header.extend(values.into_iter().flatten());
+                }
+                piece => header.extend(value(piece, fields)),
+            }
+        }
+        header
+    }
+
+    fn has(&self, field: Field) -> bool {
+        self.pieces.iter().any(|piece| match piece {
+            Piece::Field(f) => *f == field,
+            Piece::Optional(inner) => inner.contains(&Piece::Field(field)),
+            Piece::Text(_) => false,
+        })
+    }
+}
+
+impl Default for HeaderFormat {
+    fn default() -> Self {
+        Self::parse(CONVENTIONAL_HEADER).expect("the built-in template parses")
+    }
+}
+
+fn flush(text: &mut String, pieces: &mut Vec<Piece>) {
+    if !text.is_empty() {
+        pieces.push(Piece::Text(std::mem::take(text)));
+    }
+}
+
+/// A piece's rendering; None for a field that is empty.
+fn value(piece: &Piece, fields: &HeaderFields) -> Option<String> {
+    let value = match piece {
+        Piece::Text(text) => return Some(text.clone()),
+        Piece::Optional(_) => return None,
+        Piece::Field(Field::Type) => fields.commit_type,
+        Piece::Field(Field::Scope) => fields.scope.unwrap_or(""),
+        Piece::Field(Field::Breaking) => {
+            if fields.breaking {
+                "!"
+            } else {
+                ""
+            }
+        }
+        Piece::Field(Field::Description) => fields.description,
+    };
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(scope: Option<&str>, breaking: bool) -> HeaderFields<'_> {
+        HeaderFields {
+            commit_type: "feat",
+            scope,
+            breaking,
+            description: "add search",
+        }
+    }
+
+    #[test]
+    fn default_is_the_conventional_header() {
+        let format = HeaderFormat::default();
+        assert_eq!(
+            format.render(&fields(Some("api"), true)),
+            "feat(api)!: add search"
+        );
+        assert_eq!(format.render(&fields(None, false)), "feat: add search");
+    }
+
+    #[test]
+    fn optional_segment_is_dropped_without_scope() {
+        let format = HeaderFormat::parse("{type}[/{scope}]: {description}").unwrap();
+        assert_eq!(
+            format.render(&fields(Some("api"), false)),
+            "feat/api: add search"
+        );
+        assert_eq!(format.render(&fields(None, false)), "feat: add search");
+    }
+
+    #[test]
+    fn rejects_malformed_templates() {
+        for template in [
+            "{type}: {desc}",
+            "{type: {description}",
+            "{type}[({scope}): {description}",
+            "{type}]: {description}",
+            "[[{scope}]] {description}",
+            "{type}({scope})",
+        ] {
+            assert!(HeaderFormat::parse(template).is_err(), "{}", template);
+        }
+    }
+}
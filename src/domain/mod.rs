@@ -1,7 +1,22 @@
+mod autosquash;
 mod commit_message;
 mod commit_type;
 mod error;
+mod header_format;
+mod mood;
+mod report;
+mod validation;
 
-pub use commit_message::CommitMessage;
+pub use autosquash::Autosquash;
+pub use commit_message::{
+    CommitMessage, is_breaking_change_key, is_footer_key, is_issue_key, is_issue_reference,
+    split_issue_refs,
+};
 pub use commit_type::CommitType;
 pub use error::DomainError;
+pub use header_format::HeaderFormat;
+pub use report::ValidationReport;
+pub use validation::{
+    DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_DESCRIPTION_LENGTH, DEFAULT_MAX_FOOTERS,
+    DEFAULT_MAX_HEADER_LENGTH, ScopeCase, ScopeRule, ValidationConfig,
+};
@@ -0,0 +1,197 @@
+//! Imperative Mood Lint
+//!
+//! Conventional subjects read as commands: "add login", not "added login"
+//! or "adds login". This is a heuristic on the first word only, so it
+//! produces a suggestion for the user to accept or ignore — never an error.
+
+/// Base forms of verbs that commonly open a commit subject.
+/// A first word that inflects to one of these gets a precise suggestion.
+const COMMON_VERBS: &[&str] = &[
+    "add",
+    "allow",
+    "apply",
+    "bump",
+    "change",
+    "clean",
+    "correct",
+    "create",
+    "delete",
+    "disable",
+    "document",
+    "drop",
+    "enable",
+    "ensure",
+    "extract",
+    "fix",
+    "format",
+    "handle",
+    "implement",
+    "improve",
+    "introduce",
+    "make",
+    "merge",
+    "migrate",
+    "move",
+    "optimize",
+    "prevent",
+    "reduce",
+    "refactor",
+    "release",
+    "remove",
+    "rename",
+    "replace",
+    "return",
+    "revert",
+    "rewrite",
+    "rework",
+    "set",
+    "show",
+    "simplify",
+    "skip",
+    "split",
+    "support",
+    "switch",
+    "test",
+    "tweak",
+    "update",
+    "upgrade",
+    "use",
+    "validate",
+    "wrap",
+];
+
+/// Words ending in "ed"/"ing" that are not past tense or gerunds.
+const NOT_INFLECTED: &[&str] = &[
+    "bed", "embed", "feed", "need", "proceed", "red", "seed", "shed", "speed", "bring", "ping",
+    "ring", "sing", "spring", "string", "swing", "thing", "wing",
+];
+
+/// Suggest an imperative rewrite of the description's first word, if needed.
+pub fn suggestion(description: &str) -> Option<String> {
+    let word = description
+        .split_whitespace()
+        .next()?
+        .trim_end_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+
+    if COMMON_VERBS.contains(&word.as_str()) || NOT_INFLECTED.contains(&word.as_str()) {
+        return None;
+    }
+
+    if let Some(base) = base_form(&word) {
+        return Some(format!("Use '{}' instead of '{}'", base, word));
+    }
+
+    // Unknown verb: only "-ed"/"-ing" are reliable enough to flag. A bare
+    // trailing "s" is just as often a plural noun ("docs", "deps").
+    let inflected =
+        (word.len() > 4 && word.ends_with("ed")) || (word.len() > 5 && word.ends_with("ing"));
+    inflected.then(|| {
+        format!(
+            "Use the imperative mood: '{}' reads as past tense or ongoing (e.g. 'add', not 'added')",
+            word
+        )
+    })
+}
+
+/// Map an inflected form back to a known base verb.
+fn base_form(word: &str) -> Option<&'static str> {
+    let mut candidates = Vec::new();
+
+    for suffix in ["ed", "ing"] {
+        if let Some(stem) = word.strip_suffix(suffix) {
+            candidates.push(stem.to_string());
+            candidates.push(format!("{}e", stem));
+            // "dropped" → "drop", "bumping" → "bump"
+            if let Some(undoubled) = undouble(stem) {
+                candidates.push(undoubled);
+            }
+        }
+    }
+    if let Some(stem) = word
+        .strip_suffix("ied")
+        .or_else(|| word.strip_suffix("ies"))
+    {
+        candidates.push(format!("{}y", stem));
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        candidates.push(stem.to_string());
+    }
+    if let Some(stem) = word.strip_suffix('s') {
+        candidates.push(stem.to_string());
+    }
+
+    candidates
+        .iter()
+        .find_map(|c| COMMON_VERBS.iter().find(|v| **v == c.as_str()).copied())
+}
+
+fn undouble(stem: &str) -> Option<String> {
+    let mut chars = stem.chars().rev();
+    let (last, before) = (chars.next()?, chars.next()?);
+    (last == before).then(|| stem[..stem.len() - last.len_utf8()].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn past_tense_is_flagged() {
+        assert_eq!(
+            suggestion("added login"),
+            Some("Use 'add' instead of 'added'".into())
+        );
+    }
+
+    #[test]
+    fn third_person_is_flagged() {
+        assert_eq!(
+            suggestion("adds login"),
+            Some("Use 'add' instead of 'adds'".into())
+        );
+    }
+
+    #[test]
+    fn imperative_is_accepted() {
+        assert_eq!(suggestion("add login"), None);
+    }
+
+    #[test]
+    fn common_inflections_map_to_base() {
+        assert_eq!(
+            suggestion("updated deps"),
+            Some("Use 'update' instead of 'updated'".into())
+        );
+        assert_eq!(
+            suggestion("Dropped v1"),
+            Some("Use 'drop' instead of 'dropped'".into())
+        );
+        assert_eq!(
+            suggestion("fixes crash"),
+            Some("Use 'fix' instead of 'fixes'".into())
+        );
+        assert_eq!(
+            suggestion("applies patch"),
+            Some("Use 'apply' instead of 'applies'".into())
+        );
+        assert_eq!(
+            suggestion("removing flag"),
+            Some("Use 'remove' instead of 'removing'".into())
+        );
+    }
+
+    #[test]
+    fn unknown_past_tense_gets_generic_hint() {
+        let hint = suggestion("parallelized builds").unwrap();
+        assert!(hint.contains("'parallelized'"));
+    }
+
+    #[test]
+    fn words_that_only_look_inflected_pass() {
+        assert_eq!(suggestion("embed fonts"), None);
+        assert_eq!(suggestion("string helpers"), None);
+        assert_eq!(suggestion("docs for parser"), None);
+        assert_eq!(suggestion("address review"), None);
+    }
+}
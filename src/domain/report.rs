@@ -0,0 +1,46 @@
+/// Validation Report
+///
+/// Everything wrong with one message: hard errors, which block the commit,
+/// and soft warnings (mood, a trailing period, an over-long header), which
+/// are shown but do not. `strict()` turns the warnings into errors.
+use crate::domain::error::DomainError;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub errors: Vec<DomainError>,
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// --strict: every warning becomes an error, after the real ones.
+    pub fn strict(mut self) -> Self {
+        self.errors
+            .extend(self.warnings.drain(..).map(DomainError::Warning));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warnings_alone_are_valid_until_strict() {
+        let report = ValidationReport {
+            errors: vec![],
+            warnings: vec!["Use 'add' instead of 'added'".into()],
+        };
+        assert!(report.is_valid());
+        let strict = report.strict();
+        assert!(!strict.is_valid());
+        assert!(strict.warnings.is_empty());
+        assert_eq!(
+            strict.errors,
+            [DomainError::Warning("Use 'add' instead of 'added'".into())]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+/// Validation Config
+///
+/// Tunable limits applied by CommitMessage at construction time.
+/// Every input source carries one of these so all paths enforce the same rules.
+use crate::domain::commit_type::CommitType;
+use crate::domain::header_format::HeaderFormat;
+
+pub const DEFAULT_MAX_DESCRIPTION_LENGTH: usize = 72;
+
+/// Header length past which GitHub truncates the subject in most views.
+pub const DEFAULT_MAX_HEADER_LENGTH: usize = 100;
+
+/// Far past any real commit: the footer and body limits only stop
+/// runaway pastes and generated input.
+pub const DEFAULT_MAX_FOOTERS: usize = 50;
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationConfig {
+    /// Maximum number of characters allowed in the header description.
+    pub max_description_length: usize,
+
+    /// Minimum number of characters in the header description; None
+    /// (the default) accepts anything non-empty.
+    pub min_description_length: Option<usize>,
+
+    /// Extra commit types accepted on top of the built-ins (e.g. "deps").
+    pub custom_types: Vec<String>,
+
+    /// Which commit types must carry a scope.
+    pub require_scope: ScopeRule,
+
+    /// Commit types that must be header-only (matched case-insensitively).
+    pub body_forbidden_types: Vec<String>,
+
+    /// Letter case scopes must use (default: lowercase).
+    pub scope_case: ScopeCase,
+
+    /// Reject a description ending in '.', e.g. "add feature.".
+    pub reject_trailing_period: bool,
+
+    /// Accept a leading gitmoji, and have interactive mode add the type's.
+    pub gitmoji: bool,
+
+    /// Convert curly quotes, no-break spaces and similar in the header to
+    /// ASCII before compiling. Read by whoever builds the CompilerPipeline.
+    pub normalize_unicode: bool,
+
+    /// Footer keys that may appear at most once (matched case-insensitively).
+    /// Trailers like Co-authored-by legitimately repeat and are not listed.
+    pub single_footers: Vec<String>,
+
+    /// Render footers grouped (refs, then people trailers, then the rest)
+    /// instead of in the order they were written.
+    pub group_footers: bool,
+
+    /// Template the header is rendered with (default: conventional).
+    pub header_format: HeaderFormat,
+
+    /// Warn (never reject) when the rendered header line is longer than
+    /// this; None disables the warning.
+    pub max_header_length: Option<usize>,
+
+    /// Render without trailing whitespace on any line, and with leading
+    /// tabs in the body expanded to spaces. Blank lines are kept.
+    pub strip_whitespace: bool,
+
+    /// Scopes rejected even though well-formed, e.g. "misc" (case-insensitive).
+    pub forbidden_scopes: Vec<String>,
+
+    /// Accept path-like scopes such as `packages/api` (monorepos).
+    pub allow_path_scopes: bool,
+
+    /// Require every Refs/Closes/Fixes reference to be `#123`, `GH-123`,
+    /// `org/repo#123` or a GitHub issue URL.
+    pub strict_issue_refs: bool,
+
+    /// Reject `feat!: x` without a BREAKING CHANGE footer. Off by
+    /// default: the spec makes the '!' enough on its own.
+    pub breaking_requires_footer: bool,
+
+    /// Reject a type not written in lowercase (`Feat: x`) instead of
+    /// lowercasing it. Applies to compiled messages, where the type is
+    /// exactly as typed.
+    pub strict_type_case: bool,
+
+    /// Maximum characters per body line; None disables the check.
+    pub max_body_line_length: Option<usize>,
+
+    /// Let lines that cannot be wrapped (a lone token or a URL) exceed
+    /// max_body_line_length.
+    pub exempt_unbreakable_lines: bool,
+
+    /// Most footers (trailers) one message may carry.
+    pub max_footers: usize,
+
+    /// Largest body, in bytes.
+    pub max_body_bytes: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ScopeRule {
+    /// Scope may always be omitted.
+    #[default]
+    Optional,
+    /// Every commit needs a scope.
+    Always,
+    /// Only the listed types (matched case-insensitively) need a scope.
+    For(Vec<String>),
+}
+
+/// Letter case a scope must use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScopeCase {
+    /// No uppercase letters: `api`, `auth_service`, `v2.api`.
+    #[default]
+    Lower,
+    /// Lowercase words joined by single hyphens: `my-scope`, `api`.
+    Kebab,
+    /// Anything the scope charset allows, e.g. `API`.
+    Any,
+}
+
+impl ScopeCase {
+    /// Whether one scope word (a path segment, with allow_path_scopes)
+    /// follows this policy.
+    pub fn allows(self, word: &str) -> bool {
+        match self {
+            ScopeCase::Any => true,
+            ScopeCase::Lower => !word.chars().any(char::is_uppercase),
+            ScopeCase::Kebab => word.split('-').all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            }),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScopeCase::Lower => "lowercase",
+            ScopeCase::Kebab => "kebab-case",
+            ScopeCase::Any => "any case",
+        }
+    }
+}
+
+impl ScopeRule {
+    pub fn requires(&self, commit_type: &CommitType) -> bool {
+        match self {
+            ScopeRule::Optional => false,
+            ScopeRule::Always => true,
+            ScopeRule::For(types) => types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(commit_type.as_str())),
+        }
+    }
+}
+
+impl ValidationConfig {
+    /// Whether `commit_type` is listed in body_forbidden_types.
+    pub fn forbids_body(&self, commit_type: &CommitType) -> bool {
+        self.body_forbidden_types
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(commit_type.as_str()))
+    }
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            max_description_length: DEFAULT_MAX_DESCRIPTION_LENGTH,
+            min_description_length: None,
+            custom_types: Vec::new(),
+            require_scope: ScopeRule::Optional,
+            body_forbidden_types: Vec::new(),
+            scope_case: ScopeCase::Lower,
+            reject_trailing_period: false,
+            gitmoji: false,
+            normalize_unicode: false,
+            single_footers: vec!["Refs".to_string()],
+            group_footers: false,
+            header_format: HeaderFormat::default(),
+            max_header_length: Some(DEFAULT_MAX_HEADER_LENGTH),
+            strip_whitespace: true,
+            forbidden_scopes: Vec::new(),
+            allow_path_scopes: false,
+            strict_issue_refs: false,
+            breaking_requires_footer: false,
+            strict_type_case: false,
+            max_body_line_length: None,
+            exempt_unbreakable_lines: true,
+            max_footers: DEFAULT_MAX_FOOTERS,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_max_description_length_is_72() {
+        assert_eq!(ValidationConfig::default().max_description_length, 72);
+    }
+
+    #[test]
+    fn default_has_no_custom_types() {
+        assert!(ValidationConfig::default().custom_types.is_empty());
+    }
+
+    #[test]
+    fn default_scope_is_optional() {
+        assert_eq!(
+            ValidationConfig::default().require_scope,
+            ScopeRule::Optional
+        );
+    }
+
+    #[test]
+    fn default_body_line_length_is_unchecked() {
+        assert_eq!(ValidationConfig::default().max_body_line_length, None);
+    }
+
+    #[test]
+    fn scope_rule_for_listed_types_only() {
+        let rule = ScopeRule::For(vec!["feat".into(), "FIX".into()]);
+        assert!(rule.requires(&CommitType::Feat));
+        assert!(rule.requires(&CommitType::Fix));
+        assert!(!rule.requires(&CommitType::Docs));
+    }
+
+    #[test]
+    fn scope_rule_always_and_optional() {
+        assert!(ScopeRule::Always.requires(&CommitType::Chore));
+        assert!(!ScopeRule::Optional.requires(&CommitType::Feat));
+    }
+}
@@ -0,0 +1,39 @@
+/// Top-level error for CommitMessage::parse and library callers of
+/// compile_and_validate.
+///
+/// Wraps the two stages a raw message goes through: structural
+/// compilation, then domain validation.
+use crate::compiler::CompileError;
+use crate::domain::DomainError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandoError {
+    /// The message failed to compile (structural / syntax error).
+    Compile(CompileError),
+
+    /// The message compiled but failed domain validation.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for CommandoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandoError::Compile(e) => write!(f, "{}", e),
+            CommandoError::Domain(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for CommandoError {}
+
+impl From<CompileError> for CommandoError {
+    fn from(e: CompileError) -> Self {
+        CommandoError::Compile(e)
+    }
+}
+
+impl From<DomainError> for CommandoError {
+    fn from(e: DomainError) -> Self {
+        CommandoError::Domain(e)
+    }
+}
@@ -0,0 +1,308 @@
+/// Git hook mode — `commando --hook prepare-commit-msg <file>`.
+///
+/// git runs prepare-commit-msg before the editor opens (or before the
+/// commit is made with -m/-F). There is no TTY, so nothing here prompts:
+/// the message in the file is compiled, validated and written back
+/// formatted, or the hook fails and git aborts the commit.
+///
+/// Everything from the first comment line down (git's instructions, the
+/// status summary, a verbose diff below the scissors line) is kept as is.
+/// A file with no message yet is left untouched so the editor still opens.
+///
+/// Messages git writes itself are not conventional commits and are left
+/// alone too: anything for a merge or squash (git's second hook argument),
+/// and `Merge ...`, `Revert "..."`, `fixup! ...` and `squash! ...` subjects.
+use std::path::{Path, PathBuf};
+
+use crate::compiler::{CompileError, CompilerPipeline};
+use crate::domain::{CommitMessage, DomainError, ValidationConfig};
+
+/// The only hook commando implements.
+pub const PREPARE_COMMIT_MSG: &str = "prepare-commit-msg";
+
+/// Message sources (the hook's second argument) whose message git made up.
+const GIT_SOURCES: &[&str] = &["merge", "squash"];
+
+/// Subjects git (or `git commit --fixup`/`--squash`) writes.
+const GIT_SUBJECTS: &[&str] = &["Merge ", "Revert \"", "fixup!", "squash!"];
+
+#[derive(Debug)]
+pub enum HookError {
+    /// The message file could not be read.
+    Read { path: PathBuf, reason: String },
+
+    /// The formatted message could not be written back.
+    Write { path: PathBuf, reason: String },
+
+    /// The message failed to compile (structural / syntax error).
+    Compile(CompileError),
+
+    /// The message compiled but failed domain validation.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for HookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HookError::Read { path, reason } => {
+                write!(f, "Failed to read {}: {}", path.display(), reason)
+            }
+            HookError::Write { path, reason } => {
+                write!(f, "Failed to write {}: {}", path.display(), reason)
+            }
+            HookError::Compile(e) => write!(f, "{}", e),
+            HookError::Domain(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for HookError {}
+
+/// Validate and reformat the message file in place.
+///
+/// Returns false when the file holds no message yet, or one git wrote, and
+/// was left alone. On error the file is not modified. `source` is git's
+/// optional second argument (message, template, merge, squash or commit);
+/// `comment` is git's core.commentChar.
+pub fn prepare_commit_msg(
+    path: &Path,
+    source: Option<&str>,
+    config: &ValidationConfig,
+    wrap_width: Option<usize>,
+    comment: char,
+) -> Result<bool, HookError> {
+    if source.is_some_and(|source| GIT_SOURCES.contains(&source)) {
+        return Ok(false);
+    }
+    let raw = std::fs::read_to_string(path).map_err(|e| HookError::Read {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })?;
+
+    let Some(formatted) = format(&raw, config, wrap_width, comment)? else {
+        return Ok(false);
+    };
+
+    std::fs::write(path, formatted).map_err(|e| HookError::Write {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })?;
+    Ok(true)
+}
+
+/// The new file contents, or None if there is no message to format.
+fn format(
+    raw: &str,
+    config: &ValidationConfig,
+    wrap_width: Option<usize>,
+    comment: char,
+) -> Result<Option<String>, HookError> {
+    let (message, comments) = split_comments(raw, comment);
+    let message = message.trim();
+    if message.is_empty() || GIT_SUBJECTS.iter().any(|s| message.starts_with(s)) {
+        return Ok(None);
+    }
+
+    let ast = CompilerPipeline::new()
+        .with_gitmoji(config.gitmoji)
+        .with_normalize_unicode(config.normalize_unicode)
+        .compile(message)
+        .map_err(HookError::Compile)?;
+    let commit = CommitMessage::from_ast(ast, config).map_err(HookError::Domain)?;
+    let rendered = match wrap_width {
+        Some(width) => commit.to_wrapped(width),
+        None => commit.to_conventional_commit(),
+    };
+
+    Ok(Some(if comments.is_empty() {
+        format!("{}\n", rendered)
+    } else {
+        format!("{}\n\n{}", rendered, comments)
+    }))
+}
+
+/// Split at the first comment line: (message, that line and everything after).
+/// A `#` line inside a ``` fence is part of the message, as in strip_comments.
+fn split_comments(raw: &str, comment: char) -> (&str, &str) {
+    let mut offset = 0;
+    let mut in_fence = false;
+    for line in raw.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence && trimmed.starts_with(comment) {
+            return raw.split_at(offset);
+        }
+        offset += line.len();
+    }
+    (raw, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const GIT_COMMENTS: &str = "\
+# Please enter the commit message for your changes. Lines starting
+# with '#' will be ignored, and an empty message aborts the commit.
+#
+# On branch main
+";
+
+    fn file_with(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    fn run(contents: &str) -> (Result<bool, HookError>, String) {
+        let file = file_with(contents);
+        let result = prepare_commit_msg(
+            file.path(),
+            None,
+            &ValidationConfig::default(),
+            Some(20),
+            '#',
+        );
+        let after = std::fs::read_to_string(file.path()).unwrap();
+        (result, after)
+    }
+
+    #[test]
+    fn rewrites_valid_message_and_keeps_comments() {
+        let (result, after) = run(&format!(
+            "feat(api)!: drop v1\n\nOld clients must upgrade before release.\n\n{}",
+            GIT_COMMENTS
+        ));
+        assert!(result.unwrap());
+        assert_eq!(
+            after,
+            format!(
+                "feat(api)!: drop v1\n\nOld clients must\nupgrade before\nrelease.\n\n{}",
+                GIT_COMMENTS
+            )
+        );
+    }
+
+    #[test]
+    fn fenced_hash_line_stays_in_the_message() {
+        let (result, after) = run(&format!(
+            "docs: add example\n\n```sh\n# setup\nmake\n```\n\n{}",
+            GIT_COMMENTS
+        ));
+        assert!(result.unwrap());
+        assert_eq!(
+            after,
+            format!(
+                "docs: add example\n\n```sh\n# setup\nmake\n```\n\n{}",
+                GIT_COMMENTS
+            )
+        );
+    }
+
+    #[test]
+    fn message_without_comments_gets_trailing_newline() {
+        let (result, after) = run("fix: handle tabs");
+        assert!(result.unwrap());
+        assert_eq!(after, "fix: handle tabs\n");
+    }
+
+    #[test]
+    fn empty_message_is_left_untouched() {
+        let contents = format!("\n{}", GIT_COMMENTS);
+        let (result, after) = run(&contents);
+        assert!(!result.unwrap());
+        assert_eq!(after, contents);
+    }
+
+    #[test]
+    fn invalid_message_fails_without_touching_the_file() {
+        let contents = format!("feature: add search\n{}", GIT_COMMENTS);
+        let (result, after) = run(&contents);
+        assert!(matches!(result, Err(HookError::Domain(_))));
+        assert_eq!(after, contents);
+
+        let (result, _) = run("add search");
+        assert!(matches!(result, Err(HookError::Compile(_))));
+    }
+
+    #[test]
+    fn merge_message_is_left_untouched() {
+        let contents = format!("Merge branch 'side'\n\n{}", GIT_COMMENTS);
+        let file = file_with(&contents);
+        let result = prepare_commit_msg(
+            file.path(),
+            Some("merge"),
+            &ValidationConfig::default(),
+            None,
+            '#',
+        );
+        assert!(!result.unwrap());
+        assert_eq!(std::fs::read_to_string(file.path()).unwrap(), contents);
+
+        // Without the source argument (an older hook script): by subject.
+        let (result, after) = run(&contents);
+        assert!(!result.unwrap());
+        assert_eq!(after, contents);
+    }
+
+    #[test]
+    fn git_written_subjects_are_left_untouched() {
+        for contents in [
+            "Revert \"feat: add search\"\n\nThis reverts commit 1a2b3c4.\n",
+            "fixup! feat: add search\n",
+            "squash! feat: add search\n",
+        ] {
+            let (result, after) = run(contents);
+            assert!(!result.unwrap(), "{}", contents);
+            assert_eq!(after, contents);
+        }
+    }
+
+    #[test]
+    fn squash_source_is_left_untouched() {
+        let contents = "Squashed commit of the following:\n\ncommit 1a2b3c4\n";
+        let file = file_with(contents);
+        let result = prepare_commit_msg(
+            file.path(),
+            Some("squash"),
+            &ValidationConfig::default(),
+            None,
+            '#',
+        );
+        assert!(!result.unwrap());
+    }
+
+    #[test]
+    fn verbose_diff_below_scissors_is_kept() {
+        let tail = "# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n+fix: not a header\n";
+        let (result, after) = run(&format!("docs: x\n{}", tail));
+        assert!(result.unwrap());
+        assert_eq!(after, format!("docs: x\n\n{}", tail));
+    }
+
+    #[test]
+    fn honors_another_comment_char() {
+        let file = file_with("docs: x\n\n# Usage\n\n; Please enter the commit message\n");
+        let result = prepare_commit_msg(file.path(), None, &ValidationConfig::default(), None, ';');
+        assert!(result.unwrap());
+        assert_eq!(
+            std::fs::read_to_string(file.path()).unwrap(),
+            "docs: x\n\n# Usage\n\n; Please enter the commit message\n"
+        );
+    }
+
+    #[test]
+    fn missing_file_is_read_error() {
+        let err = prepare_commit_msg(
+            Path::new("/nonexistent/COMMIT_EDITMSG"),
+            None,
+            &ValidationConfig::default(),
+            None,
+            '#',
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/COMMIT_EDITMSG"));
+    }
+}
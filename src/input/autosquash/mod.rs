@@ -0,0 +1,107 @@
+use std::convert::Infallible;
+
+use crate::domain::{Autosquash, CommitMessage, ValidationConfig};
+use crate::ports::input::CommitMessageSource;
+
+/// AutosquashSource — the message for `--fixup <REV>` / `--squash <REV>`:
+///
+/// ```text
+/// fixup! <target subject>
+/// ```
+///
+/// The composition root looks up the target's subject; this only formats
+/// it. The result is not a conventional commit, so no rule applies and
+/// resolving cannot fail. Nothing is prompted for, like RevertSource.
+#[derive(Debug, Clone)]
+pub struct AutosquashSource {
+    kind: Autosquash,
+    subject: String,
+    config: ValidationConfig,
+}
+
+impl AutosquashSource {
+    pub fn new(kind: Autosquash, subject: String, config: ValidationConfig) -> Self {
+        Self {
+            kind,
+            subject,
+            config,
+        }
+    }
+}
+
+impl CommitMessageSource for AutosquashSource {
+    type Error = Infallible;
+
+    fn resolve(&self) -> Result<CommitMessage, Infallible> {
+        Ok(CommitMessage::autosquash(
+            self.kind,
+            &self.subject,
+            &self.config,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ScopeRule;
+
+    fn resolve(kind: Autosquash, subject: &str, config: ValidationConfig) -> String {
+        AutosquashSource::new(kind, subject.into(), config)
+            .resolve()
+            .unwrap()
+            .to_conventional_commit()
+    }
+
+    #[test]
+    fn formats_fixup_and_squash_subjects() {
+        assert_eq!(
+            resolve(
+                Autosquash::Fixup,
+                "feat(api): add search",
+                ValidationConfig::default()
+            ),
+            "fixup! feat(api): add search"
+        );
+        assert_eq!(
+            resolve(
+                Autosquash::Squash,
+                "feat(api): add search",
+                ValidationConfig::default()
+            ),
+            "squash! feat(api): add search"
+        );
+    }
+
+    #[test]
+    fn skips_conventional_validation() {
+        // A non-conventional, over-long target with rules that would reject
+        // any regular message.
+        let config = ValidationConfig {
+            require_scope: ScopeRule::Always,
+            reject_trailing_period: true,
+            ..Default::default()
+        };
+        let subject = format!("Merge the {} branch.", "x".repeat(80));
+        assert_eq!(
+            resolve(Autosquash::Fixup, &subject, config),
+            format!("fixup! {}", subject)
+        );
+    }
+
+    #[test]
+    fn trailers_follow_the_subject() {
+        let message = AutosquashSource::new(
+            Autosquash::Fixup,
+            "fix: typo".into(),
+            ValidationConfig::default(),
+        )
+        .resolve()
+        .unwrap()
+        .with_signoff("Jane Doe <jane@example.com>".into());
+        assert_eq!(
+            message.to_conventional_commit(),
+            "fixup! fix: typo\n\nSigned-off-by: Jane Doe <jane@example.com>"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::compiler::CompileError;
 use crate::domain::DomainError;
+use crate::error::CommandoError;
 
 #[derive(Debug)]
 pub enum DirectError {
@@ -27,6 +28,15 @@ impl From<CompileError> for DirectError {
     }
 }
 
+impl From<CommandoError> for DirectError {
+    fn from(e: CommandoError) -> Self {
+        match e {
+            CommandoError::Compile(e) => DirectError::Compile(e),
+            CommandoError::Domain(e) => DirectError::Domain(e),
+        }
+    }
+}
+
 impl From<DomainError> for DirectError {
     fn from(e: DomainError) -> Self {
         DirectError::Domain(e)
@@ -2,7 +2,7 @@ mod error;
 pub use error::DirectError;
 
 use crate::compiler::CompilerPipeline;
-use crate::domain::CommitMessage;
+use crate::domain::{CommitMessage, ValidationConfig};
 use crate::ports::input::CommitMessageSource;
 
 /// DirectSource — compiles an inline message string into a CommitMessage.
@@ -23,11 +23,23 @@ use crate::ports::input::CommitMessageSource;
 pub struct DirectSource {
     raw: String,
     compiler: CompilerPipeline,
+    config: ValidationConfig,
 }
 
 impl DirectSource {
-    pub fn new(raw: String, compiler: CompilerPipeline) -> Self {
-        Self { raw, compiler }
+    pub fn new(raw: String, compiler: CompilerPipeline, config: ValidationConfig) -> Self {
+        Self {
+            raw,
+            compiler,
+            config,
+        }
+    }
+
+    /// True when the message is just a description with no `type:` prefix
+    /// ("add login"): the CLI then offers the type picker instead of failing.
+    /// Any other compile error, or a message that compiles, is left to resolve().
+    pub fn needs_type(&self) -> bool {
+        matches!(self.compiler.compile(&self.raw), Err(e) if e.is_missing_type())
     }
 }
 
@@ -35,17 +47,25 @@ impl CommitMessageSource for DirectSource {
     type Error = DirectError;
 
     fn resolve(&self) -> Result<CommitMessage, DirectError> {
-        let ast = self.compiler.compile(&self.raw)?;
-        CommitMessage::try_from(ast).map_err(DirectError::Domain)
+        Ok(CommitMessage::parse_with(
+            &self.raw,
+            &self.compiler,
+            &self.config,
+        )?)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::{CommitType, DomainError, ScopeRule};
 
     fn source(raw: &str) -> DirectSource {
-        DirectSource::new(raw.to_string(), CompilerPipeline::new())
+        DirectSource::new(
+            raw.to_string(),
+            CompilerPipeline::new(),
+            ValidationConfig::default(),
+        )
     }
 
     // ── valid messages ────────────────────────────────────────────────────────
@@ -68,7 +88,10 @@ mod tests {
     #[test]
     fn with_scope_and_breaking_marker() {
         let msg = source("feat(api)!: remove v1 endpoints").resolve().unwrap();
-        assert!(msg.to_conventional_commit().contains("feat(api):"));
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(api)!: remove v1 endpoints"
+        );
     }
 
     #[test]
@@ -116,6 +139,20 @@ mod tests {
         assert!(matches!(result, Err(DirectError::Compile(_))));
     }
 
+    #[test]
+    fn typeless_message_needs_a_type() {
+        assert!(source("add login").needs_type());
+        assert!(source("add login\n\nWith a body.").needs_type());
+    }
+
+    #[test]
+    fn valid_or_otherwise_broken_message_does_not_need_a_type() {
+        assert!(!source("feat: add login").needs_type());
+        assert!(!source("notavalidtype: do something").needs_type());
+        assert!(!source("").needs_type());
+        assert!(!source("feat(auth: add login").needs_type());
+    }
+
     #[test]
     fn empty_string_is_compile_error() {
         let result = source("").resolve();
@@ -135,6 +172,46 @@ mod tests {
         assert!(matches!(result, Err(DirectError::Domain(_))));
     }
 
+    #[test]
+    fn custom_description_limit_is_honored() {
+        let source = DirectSource::new(
+            "feat: add a login page".to_string(),
+            CompilerPipeline::new(),
+            ValidationConfig {
+                max_description_length: 10,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            source.resolve(),
+            Err(DirectError::Domain(DomainError::DescriptionTooLong {
+                len: 16,
+                max: 10
+            }))
+        ));
+    }
+
+    #[test]
+    fn scope_rule_is_honored() {
+        let source = |raw: &str| {
+            DirectSource::new(
+                raw.to_string(),
+                CompilerPipeline::new(),
+                ValidationConfig {
+                    require_scope: ScopeRule::For(vec!["feat".into(), "fix".into()]),
+                    ..Default::default()
+                },
+            )
+        };
+        assert!(matches!(
+            source("feat: x").resolve(),
+            Err(DirectError::Domain(DomainError::ScopeRequired(
+                CommitType::Feat
+            )))
+        ));
+        assert!(source("feat(api): x").resolve().is_ok());
+    }
+
     #[test]
     fn invalid_scope_is_domain_error() {
         let result = source("feat(invalid scope!): do something").resolve();
@@ -9,6 +9,9 @@ pub enum EditorError {
     /// Could not resolve or spawn the editor process.
     SpawnFailed { editor: String, reason: String },
 
+    /// No editor variable is set and none of these editors is on PATH.
+    NoEditor(Vec<String>),
+
     /// Editor process exited with a non-zero status code.
     EditorFailed(String),
 
@@ -18,11 +21,15 @@ pub enum EditorError {
     /// User explicitly aborted (chose not to edit again).
     Aborted,
 
-    /// The content compiled but failed domain validation.
-    Domain(DomainError),
+    /// The content compiled but failed domain validation — every rule it
+    /// broke, so one editor round trip can fix them all.
+    Domain(Vec<DomainError>),
 
     /// The content failed to compile (structural / syntax error).
     Compile(CompileError),
+
+    /// The guided prompts switched to from the editor failed.
+    Guided(String),
 }
 
 impl std::fmt::Display for EditorError {
@@ -32,13 +39,22 @@ impl std::fmt::Display for EditorError {
             EditorError::SpawnFailed { editor, reason } => {
                 write!(f, "Failed to launch '{}': {}", editor, reason)
             }
+            EditorError::NoEditor(tried) => write!(
+                f,
+                "No editor found: set $GIT_EDITOR, $VISUAL or $EDITOR (tried {} on PATH)",
+                tried.join(", ")
+            ),
             EditorError::EditorFailed(editor) => {
                 write!(f, "Editor '{}' exited with an error", editor)
             }
             EditorError::ReadFailed(e) => write!(f, "Failed to read temp file: {}", e),
             EditorError::Aborted => write!(f, "Commit aborted"),
-            EditorError::Domain(e) => write!(f, "{}", e),
+            EditorError::Domain(errors) => {
+                let lines: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "{}", lines.join("\n"))
+            }
             EditorError::Compile(e) => write!(f, "{}", e),
+            EditorError::Guided(e) => write!(f, "{}", e),
         }
     }
 }
@@ -47,7 +63,7 @@ impl std::error::Error for EditorError {}
 
 impl From<DomainError> for EditorError {
     fn from(e: DomainError) -> Self {
-        EditorError::Domain(e)
+        EditorError::Domain(vec![e])
     }
 }
 
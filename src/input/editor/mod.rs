@@ -7,7 +7,8 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use crate::compiler::CompilerPipeline;
-use crate::domain::CommitMessage;
+use crate::domain::{CommitMessage, ValidationConfig};
+use crate::input::Prefill;
 use crate::ports::input::CommitMessageSource;
 use template::commit_template;
 
@@ -23,14 +24,30 @@ struct TempCommitFile {
 }
 
 impl TempCommitFile {
-    /// Create the file and write the initial template to it.
-    fn create() -> Result<Self, EditorError> {
-        let path = std::env::temp_dir().join(format!("commando-{}.txt", std::process::id()));
-        let mut file =
-            std::fs::File::create(&path).map_err(|e| EditorError::TempFile(e.to_string()))?;
-        file.write_all(commit_template().as_bytes())
-            .map_err(|e| EditorError::TempFile(e.to_string()))?;
-        Ok(Self { path })
+    /// Create the file and write the initial content to it.
+    ///
+    /// The name is never one that exists already: a PID alone can repeat
+    /// (across reboots, or a crashed run's leftover), and a stale file
+    /// must not be opened with someone else's old message in it.
+    fn create(initial: &str) -> Result<Self, EditorError> {
+        let failed = |e: std::io::Error| EditorError::TempFile(e.to_string());
+        let dir = std::env::temp_dir();
+        let (path, mut file) = loop {
+            let path = dir.join(temp_name());
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(file) => break (path, file),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(failed(e)),
+            }
+        };
+        // From here on, Drop cleans up even if the write fails.
+        let created = Self { path };
+        file.write_all(initial.as_bytes()).map_err(failed)?;
+        Ok(created)
     }
 
     fn path(&self) -> &Path {
@@ -42,11 +59,59 @@ impl TempCommitFile {
         std::fs::write(&self.path, content).map_err(|e| EditorError::TempFile(e.to_string()))
     }
 
+    /// Read whatever file is at the path now.
+    ///
+    /// Editors that save atomically (vim with backupcopy=auto, most GUI
+    /// editors) write a new file and rename it over ours, so the inode we
+    /// created is gone. The path is always reopened, and if it was replaced
+    /// again while we were reading, the read is repeated on the new file.
     fn read(&self) -> Result<String, EditorError> {
-        std::fs::read_to_string(&self.path).map_err(|e| EditorError::ReadFailed(e.to_string()))
+        let failed = |e: std::io::Error| EditorError::ReadFailed(e.to_string());
+        loop {
+            let mut file = std::fs::File::open(&self.path).map_err(failed)?;
+            let mut content = String::new();
+            std::io::Read::read_to_string(&mut file, &mut content).map_err(failed)?;
+
+            let opened = file_identity(&file.metadata().map_err(failed)?);
+            let current = file_identity(&std::fs::metadata(&self.path).map_err(failed)?);
+            if opened == current {
+                return Ok(content);
+            }
+        }
     }
 }
 
+/// `commando-<pid>-<nanos>-<random>.txt`. std has no RNG; a freshly
+/// seeded RandomState hashes to a different value every call.
+fn temp_name() -> String {
+    use std::hash::{BuildHasher, RandomState};
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let random = RandomState::new().hash_one(nanos) as u32;
+    format!(
+        "commando-{}-{}-{:08x}.txt",
+        std::process::id(),
+        nanos,
+        random
+    )
+}
+
+/// (device, inode) on Unix; elsewhere files can't be told apart, so every
+/// read is taken as final.
+#[cfg(unix)]
+fn file_identity(metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_identity(_metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
 impl Drop for TempCommitFile {
     fn drop(&mut self) {
         let _ = std::fs::remove_file(&self.path);
@@ -55,11 +120,49 @@ impl Drop for TempCommitFile {
 
 // ── Editor resolution ─────────────────────────────────────────────────────────
 
-fn resolve_editor() -> String {
-    std::env::var("GIT_EDITOR")
-        .or_else(|_| std::env::var("VISUAL"))
-        .or_else(|_| std::env::var("EDITOR"))
-        .unwrap_or_else(|_| "vi".to_string())
+/// Tried in order when no editor is configured, the first one on PATH wins.
+#[cfg(windows)]
+const PLATFORM_EDITORS: &[&str] = &["notepad"];
+#[cfg(not(windows))]
+const PLATFORM_EDITORS: &[&str] = &["nano", "vi"];
+
+/// GIT_EDITOR, VISUAL, EDITOR, then the configured fallback, then the first
+/// of PLATFORM_EDITORS found on PATH.
+fn resolve_editor(fallback: Option<&str>) -> Result<String, EditorError> {
+    resolve_editor_with(fallback, |key| std::env::var(key).ok())
+}
+
+/// resolve_editor with the environment injected, so tests need not touch
+/// the process's own variables. An empty variable counts as unset.
+fn resolve_editor_with(
+    fallback: Option<&str>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<String, EditorError> {
+    let configured = ["GIT_EDITOR", "VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(&env)
+        .find(|editor| !editor.trim().is_empty())
+        .or_else(|| fallback.map(String::from));
+    if let Some(editor) = configured {
+        return Ok(editor);
+    }
+
+    let path = env("PATH").unwrap_or_default();
+    PLATFORM_EDITORS
+        .iter()
+        .find(|editor| on_path(editor, &path))
+        .map(|editor| editor.to_string())
+        .ok_or_else(|| {
+            EditorError::NoEditor(PLATFORM_EDITORS.iter().map(|e| e.to_string()).collect())
+        })
+}
+
+/// Whether `program` is a file in one of PATH's directories.
+fn on_path(program: &str, path: &str) -> bool {
+    std::env::split_paths(path).any(|dir| {
+        let candidate = dir.join(program);
+        candidate.is_file() || (cfg!(windows) && candidate.with_extension("exe").is_file())
+    })
 }
 
 fn spawn_editor(editor: &str, path: &Path) -> Result<(), EditorError> {
@@ -79,30 +182,139 @@ fn spawn_editor(editor: &str, path: &Path) -> Result<(), EditorError> {
 
 // ── Comment handling ──────────────────────────────────────────────────────────
 
+/// The comment character git uses unless `core.commentChar` says otherwise.
+pub const DEFAULT_COMMENT_CHAR: char = '#';
+
 /// Strip comment lines and trim surrounding whitespace.
-/// A comment line is any line whose first non-whitespace character is '#'.
-pub fn strip_comments(input: &str) -> String {
-    input
-        .lines()
-        .filter(|line| !line.trim_start().starts_with('#'))
+/// A comment line is any line whose first non-whitespace character is
+/// `comment` ('#' unless configured), outside a ``` fenced block (see
+/// content_lines).
+pub fn strip_comments(input: &str, comment: char) -> String {
+    content_lines(input, comment)
+        .map(|(_, line)| line)
         .collect::<Vec<_>>()
         .join("\n")
         .trim()
         .to_string()
 }
 
+/// The lines of `input` that are not comments, with their index.
+///
+/// Inside a ``` fence every line is kept: a `#` there starts a shell
+/// comment or a markdown heading in a code snippet, not a template comment.
+fn content_lines(input: &str, comment: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut in_fence = false;
+    input.lines().enumerate().filter(move |(_, line)| {
+        let line = line.trim_start();
+        if line.starts_with("```") {
+            in_fence = !in_fence;
+            return true;
+        }
+        in_fence || !line.starts_with(comment)
+    })
+}
+
 /// Prepend an error as a comment block above the user's existing content.
 ///
 /// The user re-opens the file and sees exactly what went wrong at the top,
 /// with their message intact below. They fix it and save — no content lost.
-fn inject_error_comment(error: &str, existing_content: &str) -> String {
-    let error_block = error
+fn inject_error_comment(error: &str, existing_content: &str, comment: char) -> String {
+    inject_comment("ERROR", error, existing_content, comment)
+}
+
+/// Same as inject_error_comment, for a warning the user chose to fix.
+fn inject_warning_comment(warning: &str, existing_content: &str, comment: char) -> String {
+    inject_comment("WARNING", warning, existing_content, comment)
+}
+
+fn inject_comment(tag: &str, text: &str, existing_content: &str, comment: char) -> String {
+    let block = text
         .lines()
-        .map(|l| format!("# ERROR: {}", l))
+        .map(|l| format!("{} {}: {}", comment, tag, l))
         .collect::<Vec<_>>()
         .join("\n");
 
-    format!("{}\n#\n{}", error_block, existing_content)
+    format!("{}\n{}\n{}", block, comment, existing_content)
+}
+
+/// Drop the "# ERROR"/"# WARNING" lines left by a previous attempt so stale
+/// messages and markers don't pile up across retries.
+fn clear_error_comments(content: &str, comment: char) -> String {
+    let is_error = |line: &str| {
+        let line = line.trim_start();
+        line.strip_prefix(comment)
+            .is_some_and(|l| l.starts_with(" ERROR") || l.starts_with(" WARNING"))
+    };
+    let lines: Vec<&str> = content.lines().collect();
+    lines
+        .iter()
+        .enumerate()
+        .filter(|&(i, line)| {
+            // The bare "#" separator inject_error_comment adds after its block
+            let separator = line.trim() == comment.to_string() && i > 0 && is_error(lines[i - 1]);
+            !is_error(line) && !separator
+        })
+        .map(|(_, line)| *line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Insert a marker comment directly above the file line that produced
+/// `line` of the stripped message (the numbering CompileError reports).
+///
+/// Comment lines and the leading blank lines strip_comments trims are
+/// skipped when mapping back. Returns the content unchanged if the line
+/// can't be found.
+fn mark_error_line(content: &str, line: usize, comment: char) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    match source_line(content, line, comment) {
+        Some(i) => {
+            let marker = format!("{} ERROR: ↓ this line", comment);
+            let mut marked = lines[..i].to_vec();
+            marked.push(&marker);
+            marked.extend(&lines[i..]);
+            marked.join("\n")
+        }
+        None => content.to_string(),
+    }
+}
+
+/// 0-based index of the file line that produced `line` of the stripped
+/// message, mapped back as mark_error_line describes.
+fn source_line(content: &str, line: usize, comment: char) -> Option<usize> {
+    content_lines(content, comment)
+        .skip_while(|(_, l)| l.trim().is_empty())
+        .nth(line.saturating_sub(1))
+        .map(|(i, _)| i)
+}
+
+/// A compile error written into the file: `# ERROR (line N): ...` on top
+/// and the marker above the offending line, N being where that line ends
+/// up in the annotated file. Returns (summary for the terminal, file).
+///
+/// Without a line to point at, this is plain inject_error_comment.
+fn annotate_compile_error(
+    message: &str,
+    line: usize,
+    content: &str,
+    comment: char,
+) -> (String, String) {
+    let Some(i) = source_line(content, line, comment) else {
+        return (
+            message.to_string(),
+            inject_error_comment(message, content, comment),
+        );
+    };
+    // The block and its "#" separator come first, then the content with
+    // the marker at i, which pushes the offending line to i + 1.
+    let file_line = message.lines().count().max(1) + 1 + (i + 1) + 1;
+    let annotated = inject_comment(
+        &format!("ERROR (line {})", file_line),
+        message,
+        &mark_error_line(content, line, comment),
+        comment,
+    );
+    (format!("{} (line {})", message, file_line), annotated)
 }
 
 // ── EditorSource ──────────────────────────────────────────────────────────────
@@ -113,13 +325,110 @@ fn inject_error_comment(error: &str, existing_content: &str) -> String {
 /// If yes: the error is injected as a comment above their content and the
 /// editor reopens with the file intact. If no: the commit is aborted.
 /// The temp file is cleaned up automatically when EditorSource drops.
+///
+/// With `with_guided`, the retry prompt also offers to give up on the
+/// editor and finish the message in the guided prompts instead.
 pub struct EditorSource {
     compiler: CompilerPipeline,
+    config: ValidationConfig,
+    initial_message: Option<String>,
+    initial_body: Option<String>,
+    default_editor: Option<String>,
+    template: Option<PathBuf>,
+    comment_char: char,
+    guided: Option<Guided>,
+}
+
+/// Finishes a message in the guided prompts, starting from what the
+/// editor held. Supplied by the composition root, which owns the terminal.
+pub type Guided = Box<dyn Fn(Prefill) -> Result<CommitMessage, String>>;
+
+/// The answer to the retry prompt after an empty or invalid message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Retry {
+    Edit,
+    Guided,
+    Abort,
 }
 
 impl EditorSource {
-    pub fn new(compiler: CompilerPipeline) -> Self {
-        Self { compiler }
+    pub fn new(compiler: CompilerPipeline, config: ValidationConfig) -> Self {
+        Self {
+            compiler,
+            config,
+            initial_message: None,
+            initial_body: None,
+            default_editor: None,
+            template: None,
+            comment_char: DEFAULT_COMMENT_CHAR,
+            guided: None,
+        }
+    }
+
+    /// Editor to launch when none is set in the environment.
+    pub fn with_default_editor(mut self, editor: Option<String>) -> Self {
+        self.default_editor = editor;
+        self
+    }
+
+    /// Start from this file instead of the built-in template (--template or
+    /// git's commit.template). An unreadable file falls back to the built-in.
+    pub fn with_template(mut self, path: Option<PathBuf>) -> Self {
+        self.template = path;
+        self
+    }
+
+    /// Lines starting with this are comments (git's core.commentChar).
+    /// The built-in template and the injected errors use it too.
+    pub fn with_comment_char(mut self, comment: char) -> Self {
+        self.comment_char = comment;
+        self
+    }
+
+    /// Offer "(s)witch to guided prompts" when a message keeps failing.
+    pub fn with_guided(mut self, guided: Guided) -> Self {
+        self.guided = Some(guided);
+        self
+    }
+
+    /// Seed the editor with an existing message (e.g. HEAD when amending).
+    /// It is written below the template comments so it survives stripping.
+    pub fn with_initial_message(mut self, message: String) -> Self {
+        self.initial_message = Some(message);
+        self
+    }
+
+    /// Seed only the body (--autobody): it goes above the template, after
+    /// an empty first line for the header. Ignored with an initial message.
+    pub fn with_initial_body(mut self, body: Option<String>) -> Self {
+        self.initial_body = body;
+        self
+    }
+
+    fn initial_content(&self) -> String {
+        match (&self.initial_message, &self.initial_body) {
+            (None, Some(body)) => format!("\n\n{}\n{}", body, self.template_content()),
+            (message, _) => self.content_with(message.as_deref()),
+        }
+    }
+
+    /// The template with `message` written below it.
+    fn content_with(&self, message: Option<&str>) -> String {
+        let template = self.template_content();
+        match message {
+            Some(message) if template.ends_with('\n') => {
+                format!("{}{}\n", template, message)
+            }
+            Some(message) => format!("{}\n{}\n", template, message),
+            None => template,
+        }
+    }
+
+    fn template_content(&self) -> String {
+        self.template
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .unwrap_or_else(|| commit_template(self.comment_char))
     }
 }
 
@@ -127,8 +436,24 @@ impl CommitMessageSource for EditorSource {
     type Error = EditorError;
 
     fn resolve(&self) -> Result<CommitMessage, EditorError> {
-        let editor = resolve_editor();
-        let file = TempCommitFile::create()?;
+        self.edit(&self.initial_content())
+    }
+
+    fn can_revise(&self) -> bool {
+        true
+    }
+
+    /// Reopen the editor on the message as it would be committed.
+    fn revise(&self, current: &CommitMessage) -> Result<CommitMessage, EditorError> {
+        self.edit(&self.content_with(Some(&current.to_conventional_commit())))
+    }
+}
+
+impl EditorSource {
+    /// Open the editor on `initial` until it holds a valid message.
+    fn edit(&self, initial: &str) -> Result<CommitMessage, EditorError> {
+        let editor = resolve_editor(self.default_editor.as_deref())?;
+        let file = TempCommitFile::create(initial)?;
 
         loop {
             // ── Open editor ───────────────────────────────────────────
@@ -136,16 +461,18 @@ impl CommitMessageSource for EditorSource {
 
             // ── Read + strip comments ─────────────────────────────────
             let raw = file.read()?;
-            let cleaned = strip_comments(&raw);
+            let cleaned = strip_comments(&raw, self.comment_char);
 
             if cleaned.is_empty() {
                 // Ask whether to retry or abort
-                if prompt_retry("Commit message is empty (nothing was written).")? {
-                    // Reset file to template and loop
-                    file.write(commit_template())?;
-                    continue;
-                } else {
-                    return Err(EditorError::Aborted);
+                match self.prompt_retry("Commit message is empty (nothing was written).")? {
+                    Retry::Edit => {
+                        // Reset file to its initial content and loop
+                        file.write(initial)?;
+                        continue;
+                    }
+                    Retry::Guided => return self.switch_to_guided(&cleaned),
+                    Retry::Abort => return Err(EditorError::Aborted),
                 }
             }
 
@@ -154,20 +481,44 @@ impl CommitMessageSource for EditorSource {
                 .compiler
                 .compile(&cleaned)
                 .map_err(EditorError::Compile)
-                .and_then(|ast| CommitMessage::try_from(ast).map_err(EditorError::Domain));
+                .and_then(|ast| {
+                    CommitMessage::from_ast_all(ast, &self.config).map_err(EditorError::Domain)
+                });
 
             match result {
-                Ok(message) => return Ok(message),
-                Err(e) => {
-                    let error_msg = e.to_string();
-                    if prompt_retry(&format!("Validation error: {}", error_msg))? {
-                        // Inject the error as a comment above the user's content
-                        // so they can see what's wrong without losing their work.
-                        let annotated = inject_error_comment(&error_msg, &raw);
-                        file.write(&annotated)?;
+                Ok(message) => match message.check_mood() {
+                    Some(hint) if prompt_fix_warning(&format!("Warning: {}.", hint))? => {
+                        let content = clear_error_comments(&raw, self.comment_char);
+                        file.write(&inject_warning_comment(&hint, &content, self.comment_char))?;
                         continue;
-                    } else {
-                        return Err(EditorError::Aborted);
+                    }
+                    _ => return Ok(message),
+                },
+                Err(e) => {
+                    let content = clear_error_comments(&raw, self.comment_char);
+                    // Inject the error as a comment above the user's content
+                    // so they can see what's wrong without losing their work.
+                    // Compile errors point at a line of the stripped message;
+                    // that line is also marked and numbered as in the file.
+                    let (error_msg, annotated) = match e {
+                        EditorError::Compile(ref ce) => annotate_compile_error(
+                            &ce.message(),
+                            ce.line(),
+                            &content,
+                            self.comment_char,
+                        ),
+                        _ => (
+                            e.to_string(),
+                            inject_error_comment(&e.to_string(), &content, self.comment_char),
+                        ),
+                    };
+                    match self.prompt_retry(&format!("Validation error: {}", error_msg))? {
+                        Retry::Edit => {
+                            file.write(&annotated)?;
+                            continue;
+                        }
+                        Retry::Guided => return self.switch_to_guided(&cleaned),
+                        Retry::Abort => return Err(EditorError::Aborted),
                     }
                 }
             }
@@ -175,17 +526,75 @@ impl CommitMessageSource for EditorSource {
     }
 }
 
-/// Ask the user whether to re-open the editor.
+impl EditorSource {
+    /// Ask the user whether to re-open the editor.
+    ///
+    /// Prints the reason and prompts "(e)dit / (a)bort", plus "(s)witch to
+    /// guided prompts" when they are available. Reads directly from stdin —
+    /// this is intentionally outside the Ui trait because EditorSource
+    /// predates the AppController confirm flow and runs before
+    /// AppController sees a CommitMessage.
+    fn prompt_retry(&self, reason: &str) -> Result<Retry, EditorError> {
+        use std::io::BufRead;
+
+        let guided = self.guided.is_some();
+        eprintln!("\n{}", reason);
+        eprint!("  {} [e]: ", retry_choices(guided));
+
+        std::io::stderr().flush().ok();
+
+        let line = std::io::BufReader::new(std::io::stdin())
+            .lines()
+            .next()
+            .transpose()
+            .map_err(|e| EditorError::TempFile(e.to_string()))?
+            .unwrap_or_default();
+
+        Ok(parse_retry(&line, guided))
+    }
+
+    /// Hand what the editor held to the guided prompts, as their defaults:
+    /// the compiled fields when it compiles, else its subject and body.
+    fn switch_to_guided(&self, cleaned: &str) -> Result<CommitMessage, EditorError> {
+        let Some(guided) = &self.guided else {
+            return Err(EditorError::Aborted);
+        };
+        let prefill = match self.compiler.compile(cleaned) {
+            Ok(ast) => Prefill::from_ast(ast, &self.config),
+            Err(_) => Prefill::from_raw(cleaned),
+        };
+        guided(prefill).map_err(EditorError::Guided)
+    }
+}
+
+fn retry_choices(guided: bool) -> &'static str {
+    if guided {
+        "(e)dit again / (s)witch to guided prompts / (a)bort"
+    } else {
+        "(e)dit again / (a)bort"
+    }
+}
+
+/// Anything unrecognised aborts, as before the guided option existed;
+/// 's' without guided prompts too.
+fn parse_retry(answer: &str, guided: bool) -> Retry {
+    match answer.trim().to_lowercase().as_str() {
+        "e" | "" | "edit" => Retry::Edit,
+        "s" | "switch" if guided => Retry::Guided,
+        _ => Retry::Abort,
+    }
+}
+
+/// Ask whether to go back and fix a non-fatal warning.
 ///
-/// Prints the reason and prompts "(e)dit / (a)bort". Returns true to retry.
-/// Reads directly from stdin — this is intentionally outside the Ui trait
-/// because EditorSource predates the AppController confirm flow and runs
-/// before AppController sees a CommitMessage.
-fn prompt_retry(reason: &str) -> Result<bool, EditorError> {
+/// Prompts "(c)ommit anyway / (e)dit"; the default is to commit. Returns
+/// true to re-open the editor. Reads stdin for the same reason as
+/// prompt_retry.
+fn prompt_fix_warning(reason: &str) -> Result<bool, EditorError> {
     use std::io::BufRead;
 
     eprintln!("\n{}", reason);
-    eprint!("  (e)dit again / (a)bort [e]: ");
+    eprint!("  (c)ommit anyway / (e)dit [c]: ");
 
     std::io::stderr().flush().ok();
 
@@ -196,10 +605,7 @@ fn prompt_retry(reason: &str) -> Result<bool, EditorError> {
         .map_err(|e| EditorError::TempFile(e.to_string()))?
         .unwrap_or_default();
 
-    Ok(matches!(
-        line.trim().to_lowercase().as_str(),
-        "e" | "" | "edit"
-    ))
+    Ok(matches!(line.trim().to_lowercase().as_str(), "e" | "edit"))
 }
 
 // ── Tests ─────────────────────────────────────────────────────────────────────
@@ -207,24 +613,113 @@ fn prompt_retry(reason: &str) -> Result<bool, EditorError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::CommitType;
+
+    // ── resolve_editor ────────────────────────────────────────────────────────
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    /// A PATH directory holding empty files named after `editors`.
+    fn path_with(editors: &[&str]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for editor in editors {
+            std::fs::write(dir.path().join(editor), "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn git_editor_wins_over_visual_and_editor() {
+        let vars = [
+            ("GIT_EDITOR", "hx"),
+            ("VISUAL", "code -w"),
+            ("EDITOR", "nano"),
+        ];
+        assert_eq!(
+            resolve_editor_with(Some("emacs"), env(&vars)).unwrap(),
+            "hx"
+        );
+    }
+
+    #[test]
+    fn visual_wins_over_editor() {
+        let vars = [("VISUAL", "code -w"), ("EDITOR", "nano")];
+        assert_eq!(resolve_editor_with(None, env(&vars)).unwrap(), "code -w");
+        assert_eq!(
+            resolve_editor_with(None, env(&[("EDITOR", "nano")])).unwrap(),
+            "nano"
+        );
+    }
+
+    #[test]
+    fn empty_variable_is_skipped() {
+        let vars = [("GIT_EDITOR", ""), ("VISUAL", " "), ("EDITOR", "micro")];
+        assert_eq!(resolve_editor_with(None, env(&vars)).unwrap(), "micro");
+    }
+
+    #[test]
+    fn configured_fallback_comes_after_the_environment() {
+        let dir = path_with(PLATFORM_EDITORS);
+        let path = dir.path().to_str().unwrap();
+        assert_eq!(
+            resolve_editor_with(Some("emacs"), env(&[("PATH", path)])).unwrap(),
+            "emacs"
+        );
+    }
+
+    #[test]
+    fn first_platform_editor_on_path_is_used() {
+        let dir = path_with(PLATFORM_EDITORS);
+        let path = dir.path().to_str().unwrap();
+        assert_eq!(
+            resolve_editor_with(None, env(&[("PATH", path)])).unwrap(),
+            PLATFORM_EDITORS[0]
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn falls_through_to_vi_without_nano() {
+        let dir = path_with(&["vi"]);
+        let path = dir.path().to_str().unwrap();
+        assert_eq!(
+            resolve_editor_with(None, env(&[("PATH", path)])).unwrap(),
+            "vi"
+        );
+    }
+
+    #[test]
+    fn no_editor_lists_what_was_tried() {
+        let dir = path_with(&[]);
+        let path = dir.path().to_str().unwrap();
+        let err = resolve_editor_with(None, env(&[("PATH", path)])).unwrap_err();
+        assert!(matches!(err, EditorError::NoEditor(ref tried) if tried == PLATFORM_EDITORS));
+        assert!(err.to_string().contains(&PLATFORM_EDITORS.join(", ")));
+    }
 
     // ── strip_comments ────────────────────────────────────────────────────────
 
     #[test]
     fn strips_pure_comment_file() {
-        assert_eq!(strip_comments("# line one\n# line two"), "");
+        assert_eq!(strip_comments("# line one\n# line two", '#'), "");
     }
 
     #[test]
     fn preserves_non_comment_lines() {
         let input = "# commando template\nfeat: add login\n# ignore this";
-        assert_eq!(strip_comments(input), "feat: add login");
+        assert_eq!(strip_comments(input, '#'), "feat: add login");
     }
 
     #[test]
     fn preserves_multiline_with_comments_interspersed() {
         let input = "feat(auth): add OAuth\n\n# body hint\nDetails.\n\n# footer\nRefs: #42";
-        let result = strip_comments(input);
+        let result = strip_comments(input, '#');
         assert!(result.contains("feat(auth): add OAuth"));
         assert!(result.contains("Details."));
         assert!(result.contains("Refs: #42"));
@@ -234,33 +729,70 @@ mod tests {
     #[test]
     fn trims_surrounding_whitespace() {
         let input = "\n\n# comment\n\nfeat: add login\n\n# end\n\n";
-        assert_eq!(strip_comments(input), "feat: add login");
+        assert_eq!(strip_comments(input, '#'), "feat: add login");
     }
 
     #[test]
     fn empty_input_returns_empty() {
-        assert_eq!(strip_comments(""), "");
+        assert_eq!(strip_comments("", '#'), "");
     }
 
     #[test]
     fn inline_hash_is_not_a_comment() {
         let input = "fix: resolve #42\n# this is a comment";
-        assert_eq!(strip_comments(input), "fix: resolve #42");
+        assert_eq!(strip_comments(input, '#'), "fix: resolve #42");
     }
 
     #[test]
     fn indented_comment_is_stripped() {
         let input = "feat: thing\n  # indented comment\nbody text";
-        let result = strip_comments(input);
+        let result = strip_comments(input, '#');
         assert!(!result.contains("indented comment"));
         assert!(result.contains("body text"));
     }
 
+    #[test]
+    fn hash_lines_inside_a_fence_are_kept() {
+        let input = "\
+feat: add install script
+
+# Please enter the commit message
+Run it with:
+
+```bash
+# not a comment
+./install.sh
+```
+# trailing template comment";
+        assert_eq!(
+            strip_comments(input, '#'),
+            "feat: add install script\n\nRun it with:\n\n```bash\n# not a comment\n./install.sh\n```"
+        );
+    }
+
+    #[test]
+    fn comments_after_a_closed_fence_are_stripped() {
+        let input = "fix: x\n\n```\n# kept\n```\n# dropped\nafter";
+        assert_eq!(
+            strip_comments(input, '#'),
+            "fix: x\n\n```\n# kept\n```\nafter"
+        );
+    }
+
+    #[test]
+    fn another_comment_char_keeps_hash_lines() {
+        let input = "; template\ndocs: add usage\n\n# Usage\n\n  ; indented\nRun it.";
+        assert_eq!(
+            strip_comments(input, ';'),
+            "docs: add usage\n\n# Usage\n\nRun it."
+        );
+    }
+
     // ── inject_error_comment ──────────────────────────────────────────────────
 
     #[test]
     fn injects_error_above_content() {
-        let result = inject_error_comment("bad type 'xyz'", "xyz: do something");
+        let result = inject_error_comment("bad type 'xyz'", "xyz: do something", '#');
         assert!(result.starts_with("# ERROR:"));
         assert!(result.contains("bad type 'xyz'"));
         assert!(result.contains("xyz: do something"));
@@ -270,24 +802,108 @@ mod tests {
 
     #[test]
     fn injected_error_is_stripped_by_strip_comments() {
-        let annotated = inject_error_comment("some error", "feat: fix thing");
-        let stripped = strip_comments(&annotated);
+        let annotated = inject_error_comment("some error", "feat: fix thing", '#');
+        let stripped = strip_comments(&annotated, '#');
         assert_eq!(stripped, "feat: fix thing");
     }
 
     #[test]
     fn multiline_error_each_line_gets_prefix() {
-        let result = inject_error_comment("line one\nline two", "feat: ok");
+        let result = inject_error_comment("line one\nline two", "feat: ok", '#');
         assert!(result.contains("# ERROR: line one"));
         assert!(result.contains("# ERROR: line two"));
     }
 
+    // ── mark_error_line ───────────────────────────────────────────────────────
+
+    #[test]
+    fn marks_the_offending_line_below_template_comments() {
+        let raw = "# template\n# more\n\nfeat: x\n\nBody.\n\nBREAKING CHANGE: a\n  continued";
+        let err = CompilerPipeline::new()
+            .compile(&strip_comments(raw, '#'))
+            .unwrap_err();
+        let marked = mark_error_line(raw, err.line(), '#');
+        let lines: Vec<&str> = marked.lines().collect();
+        let marker = lines.iter().position(|l| l.starts_with("# ERROR")).unwrap();
+        assert_eq!(lines[marker + 1], "  continued");
+    }
+
+    #[test]
+    fn marking_header_error_skips_leading_blank_lines() {
+        let raw = "# template\n\n\nfeat(auth: x";
+        let err = CompilerPipeline::new()
+            .compile(&strip_comments(raw, '#'))
+            .unwrap_err();
+        assert_eq!(err.line(), 1);
+        let marked = mark_error_line(raw, err.line(), '#');
+        assert!(marked.ends_with("# ERROR: ↓ this line\nfeat(auth: x"));
+    }
+
+    #[test]
+    fn marker_is_stripped_with_other_comments() {
+        let marked = mark_error_line("feat: x\n\nRefs: #1", 3, '#');
+        assert_eq!(strip_comments(&marked, '#'), "feat: x\n\nRefs: #1");
+    }
+
+    #[test]
+    fn compile_error_names_the_line_in_the_annotated_file() {
+        let raw = "# template\n\nfeat: x\n\nBody.\n\nBREAKING CHANGE: a\n  continued";
+        let err = CompilerPipeline::new()
+            .compile(&strip_comments(raw, '#'))
+            .unwrap_err();
+        let (summary, annotated) = annotate_compile_error(&err.message(), err.line(), raw, '#');
+
+        // One-line error, "#", then the eight content lines plus the marker.
+        let lines: Vec<&str> = annotated.lines().collect();
+        assert!(lines[0].starts_with("# ERROR (line 11): "));
+        assert_eq!(lines[10 - 1], "# ERROR: ↓ this line");
+        assert_eq!(lines[11 - 1], "  continued");
+        assert!(summary.ends_with("(line 11)"));
+
+        assert_eq!(strip_comments(&annotated, '#'), strip_comments(raw, '#'));
+        assert_eq!(clear_error_comments(&annotated, '#'), raw);
+    }
+
+    #[test]
+    fn error_without_a_line_is_injected_on_top() {
+        let (summary, annotated) = annotate_compile_error("bad", 99, "feat: x", '#');
+        assert_eq!(summary, "bad");
+        assert_eq!(annotated, "# ERROR: bad\n#\nfeat: x");
+    }
+
+    #[test]
+    fn warning_comment_is_stripped_and_cleared() {
+        let annotated =
+            inject_warning_comment("Use 'add' instead of 'added'", "feat: added x", '#');
+        assert!(annotated.starts_with("# WARNING: Use 'add'"));
+        assert_eq!(strip_comments(&annotated, '#'), "feat: added x");
+        assert_eq!(clear_error_comments(&annotated, '#'), "feat: added x");
+    }
+
+    #[test]
+    fn error_comments_use_the_comment_char() {
+        let annotated = inject_error_comment("bad type", "# Heading\nxyz: x", ';');
+        assert_eq!(annotated, "; ERROR: bad type\n;\n# Heading\nxyz: x");
+        assert_eq!(strip_comments(&annotated, ';'), "# Heading\nxyz: x");
+        assert_eq!(clear_error_comments(&annotated, ';'), "# Heading\nxyz: x");
+
+        let (_, annotated) = annotate_compile_error("bad", 1, "; t\nfeat x", ';');
+        assert!(annotated.contains("; ERROR: ↓ this line\nfeat x"));
+        assert_eq!(strip_comments(&annotated, ';'), "feat x");
+    }
+
+    #[test]
+    fn previous_error_comments_are_cleared() {
+        let raw = "# ERROR: old\n#\n# ERROR: ↓ this line\nfeat: x";
+        assert_eq!(clear_error_comments(raw, '#'), "feat: x");
+    }
+
     // ── full pipeline (no editor spawn) ──────────────────────────────────────
 
     #[test]
     fn cleaned_content_compiles_to_commit_message() {
         let raw = "# template\nfeat(auth): add OAuth\n\n# hint\nMigrated.\n\nBREAKING CHANGE: sessions invalidated";
-        let cleaned = strip_comments(raw);
+        let cleaned = strip_comments(raw, '#');
         let ast = CompilerPipeline::new().compile(&cleaned).unwrap();
         let msg = CommitMessage::try_from(ast).unwrap();
         assert!(msg.to_conventional_commit().starts_with("feat(auth)!:"));
@@ -302,6 +918,22 @@ mod tests {
         assert!(CommitMessage::try_from(ast).is_err());
     }
 
+    #[test]
+    fn domain_errors_are_listed_together() {
+        let config = ValidationConfig {
+            max_description_length: 5,
+            ..Default::default()
+        };
+        let ast = CompilerPipeline::new()
+            .compile("nope: far too long")
+            .unwrap();
+        let err = EditorError::Domain(CommitMessage::from_ast_all(ast, &config).unwrap_err());
+        let annotated = inject_error_comment(&err.to_string(), "nope: far too long", '#');
+        assert!(annotated.contains("# ERROR: Invalid commit type: 'nope'"));
+        assert!(annotated.contains("# ERROR: Description is too long (12 characters)"));
+        assert_eq!(strip_comments(&annotated, '#'), "nope: far too long");
+    }
+
     #[test]
     fn compile_error_for_bad_structure() {
         assert!(
@@ -311,14 +943,189 @@ mod tests {
         );
     }
 
+    #[test]
+    fn initial_content_is_template_by_default() {
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default());
+        assert_eq!(source.initial_content(), commit_template('#'));
+    }
+
+    #[test]
+    fn initial_body_leaves_the_header_line_empty() {
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_initial_body(Some("- Modified src/x.rs".into()));
+        let content = source.initial_content();
+        assert!(content.starts_with("\n\n- Modified src/x.rs\n"));
+        // What the user saves after typing the header on the first line.
+        let saved = format!("feat: x{}", content);
+        assert_eq!(
+            strip_comments(&saved, '#'),
+            "feat: x\n\n- Modified src/x.rs"
+        );
+    }
+
+    #[test]
+    fn initial_message_is_seeded_below_template_and_survives_stripping() {
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_initial_message("fix(api): handle timeouts\n\nRetry once.".into());
+        let content = source.initial_content();
+        assert!(content.starts_with(&commit_template('#')));
+        assert_eq!(
+            strip_comments(&content, '#'),
+            "fix(api): handle timeouts\n\nRetry once."
+        );
+    }
+
+    fn template_file(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn custom_template_replaces_built_in() {
+        let template =
+            "chore(deps): \n\n# Team checklist:\n# - changelog updated?\n# - tests added?\n";
+        let file = template_file(template);
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_template(Some(file.path().to_path_buf()));
+        let content = source.initial_content();
+        assert_eq!(content, template);
+        // Non-comment lines survive, the checklist does not.
+        assert_eq!(strip_comments(&content, '#'), "chore(deps):");
+    }
+
+    #[test]
+    fn unreadable_template_falls_back_to_built_in() {
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_template(Some(PathBuf::from("/nonexistent/commando-template")));
+        assert_eq!(source.initial_content(), commit_template('#'));
+    }
+
+    #[test]
+    fn initial_message_follows_custom_template_without_trailing_newline() {
+        let file = template_file("# checklist");
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_template(Some(file.path().to_path_buf()))
+            .with_initial_message("fix: x".into());
+        assert_eq!(source.initial_content(), "# checklist\nfix: x\n");
+        assert_eq!(strip_comments(&source.initial_content(), '#'), "fix: x");
+    }
+
+    #[test]
+    fn message_to_edit_is_written_below_the_template() {
+        // What `commando -m "feat: quick" --edit` puts in the temp file.
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_initial_message("feat: quick".into());
+        let dir = tempfile::tempdir().unwrap();
+        let file = TempCommitFile {
+            path: dir.path().join("COMMIT_EDITMSG"),
+        };
+        file.write(&source.initial_content()).unwrap();
+
+        let written = file.read().unwrap();
+        assert_eq!(written, format!("{}feat: quick\n", commit_template('#')));
+        assert_eq!(strip_comments(&written, '#'), "feat: quick");
+    }
+
     #[test]
     fn temp_file_is_deleted_on_drop() {
         let path = {
-            let file = TempCommitFile::create().unwrap();
+            let file = TempCommitFile::create(&commit_template('#')).unwrap();
             let p = file.path().to_owned();
             assert!(p.exists());
             p
         }; // file dropped here
         assert!(!path.exists());
     }
+
+    #[test]
+    fn retry_offers_the_switch_only_with_guided_prompts() {
+        assert_eq!(
+            retry_choices(true),
+            "(e)dit again / (s)witch to guided prompts / (a)bort"
+        );
+        assert_eq!(retry_choices(false), "(e)dit again / (a)bort");
+        assert_eq!(parse_retry("", true), Retry::Edit);
+        assert_eq!(parse_retry(" S\n", true), Retry::Guided);
+        assert_eq!(parse_retry("s", false), Retry::Abort);
+        assert_eq!(parse_retry("a", true), Retry::Abort);
+    }
+
+    #[test]
+    fn switching_hands_the_content_to_the_guided_prompts() {
+        let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let recorded = seen.clone();
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_guided(Box::new(move |prefill| {
+                recorded.borrow_mut().push(prefill);
+                CommitMessage::new(
+                    CommitType::Feat,
+                    None,
+                    "add search".into(),
+                    None,
+                    None,
+                    vec![],
+                    &ValidationConfig::default(),
+                )
+                .map_err(|e| e.to_string())
+            }));
+
+        // Compiles, fails validation: the parsed fields carry over.
+        let msg = source
+            .switch_to_guided("feat(api): Add search.\n\nServed from v2.")
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat: add search");
+        // Does not compile: the subject becomes the description.
+        source.switch_to_guided("added search").unwrap();
+
+        let seen = seen.borrow();
+        assert_eq!(seen[0].commit_type, Some(CommitType::Feat));
+        assert_eq!(seen[0].scope.as_deref(), Some("api"));
+        assert_eq!(seen[0].body.as_deref(), Some("Served from v2."));
+        assert_eq!(seen[1].commit_type, None);
+        assert_eq!(seen[1].description.as_deref(), Some("added search"));
+    }
+
+    #[test]
+    fn guided_prompt_failure_is_reported() {
+        let source = EditorSource::new(CompilerPipeline::new(), ValidationConfig::default())
+            .with_guided(Box::new(|_| Err("Interrupted".into())));
+        assert!(matches!(
+            source.switch_to_guided("feat: x"),
+            Err(EditorError::Guided(e)) if e == "Interrupted"
+        ));
+    }
+
+    #[test]
+    fn temp_files_never_share_a_path() {
+        let first = TempCommitFile::create("feat: one").unwrap();
+        let second = TempCommitFile::create("feat: two").unwrap();
+        assert_ne!(first.path(), second.path());
+        assert_eq!(first.read().unwrap(), "feat: one");
+        assert_eq!(second.read().unwrap(), "feat: two");
+        let name = first.path().file_name().unwrap().to_string_lossy();
+        assert!(
+            name.starts_with("commando-") && name.ends_with(".txt"),
+            "{}",
+            name
+        );
+    }
+
+    #[test]
+    fn read_follows_a_file_renamed_over_the_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = TempCommitFile {
+            path: dir.path().join("COMMIT_EDITMSG"),
+        };
+        file.write("feat: original").unwrap();
+
+        // What an atomic save does: write elsewhere, rename over the path.
+        let saved = dir.path().join("COMMIT_EDITMSG.swp");
+        std::fs::write(&saved, "feat: edited").unwrap();
+        std::fs::rename(&saved, file.path()).unwrap();
+
+        assert_eq!(file.read().unwrap(), "feat: edited");
+        file.write("feat: retried").unwrap();
+        assert_eq!(file.read().unwrap(), "feat: retried");
+    }
 }
@@ -1,29 +1,36 @@
+use crate::domain::CommitType;
+
 /// The template written to the temp file before the editor opens.
 ///
-/// Comment lines (starting with #) are stripped after the editor closes.
-/// Format follows conventional commits spec.
-pub fn commit_template() -> &'static str {
-    "\n
-# --- commando — conventional commit ---
-#
-# Format:  type(scope)!: description
-#
-# Types:   feat  fix  docs  style  refactor  perf  test  build  ci  chore  revert
-# Scope:   optional — alphanumeric, hyphens, underscores  e.g. (auth), (api)
-# Breaking: add '!' before ':' AND/OR a 'BREAKING CHANGE: ...' footer
-#
-# --- Examples ---
-# feat(auth): add OAuth 2.0 login
-#
-# Migrated from session-based auth to OAuth 2.0.
-# All existing sessions will be invalidated on deploy.
-#
-# BREAKING CHANGE: session cookies are no longer valid after this release
-# Refs: #142
-# ---
-# Lines starting with '#' are ignored.
-# An empty message aborts the commit.
-"
+/// Comment lines (starting with `comment`, normally '#') are stripped after
+/// the editor closes. Format follows conventional commits spec.
+pub fn commit_template(comment: char) -> String {
+    let types: Vec<&str> = CommitType::all().iter().map(CommitType::as_str).collect();
+    format!(
+        "\n
+{c} --- commando — conventional commit ---
+{c}
+{c} Format:  type(scope)!: description
+{c}
+{c} Types:   {types}
+{c} Scope:   optional — alphanumeric, hyphens, underscores  e.g. (auth), (api)
+{c} Breaking: add '!' before ':' AND/OR a 'BREAKING CHANGE: ...' footer
+{c}
+{c} --- Examples ---
+{c} feat(auth): add OAuth 2.0 login
+{c}
+{c} Migrated from session-based auth to OAuth 2.0.
+{c} All existing sessions will be invalidated on deploy.
+{c}
+{c} BREAKING CHANGE: session cookies are no longer valid after this release
+{c} Refs: #142
+{c} ---
+{c} Lines starting with '{c}' are ignored.
+{c} An empty message aborts the commit.
+",
+        c = comment,
+        types = types.join("  ")
+    )
 }
 
 #[cfg(test)]
@@ -32,13 +39,13 @@ mod tests {
 
     #[test]
     fn template_is_non_empty() {
-        assert!(!commit_template().is_empty());
+        assert!(!commit_template('#').is_empty());
     }
 
     #[test]
     fn template_lines_all_start_with_hash() {
         // Every line in the template is a comment — user starts writing below
-        for line in commit_template().lines() {
+        for line in commit_template('#').lines() {
             if !line.is_empty() {
                 assert!(
                     line.starts_with('#'),
@@ -49,13 +56,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn template_uses_the_configured_comment_char() {
+        let template = commit_template(';');
+        assert!(template.lines().all(|l| l.is_empty() || l.starts_with(';')));
+        assert!(template.contains("; Lines starting with ';' are ignored."));
+        // A '#' inside a line is example text, not a marker.
+        assert!(template.contains("; Refs: #142"));
+    }
+
     #[test]
     fn template_mentions_all_types() {
-        let t = commit_template();
-        for kind in &[
-            "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore",
-            "revert",
-        ] {
+        let t = commit_template('#');
+        for kind in CommitType::all_as_str() {
             assert!(t.contains(kind), "template missing type: {}", kind);
         }
     }
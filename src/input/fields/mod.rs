@@ -0,0 +1,146 @@
+use crate::domain::{CommitMessage, CommitType, DomainError, ValidationConfig};
+use crate::ports::input::{CommitMessageSource, StructuredInput};
+
+/// FieldsSource — builds a commit from discrete CLI flags
+/// (`--type feat --scope api --description "add endpoint"`).
+///
+/// There is no message text to lex, so the compiler is skipped entirely;
+/// the fields go through StructuredInput and CommitMessage::new like the
+/// interactive answers do. Meant for scripts and release automation.
+#[derive(Debug, Clone, Default)]
+pub struct FieldsSource {
+    commit_type: String,
+    scope: Option<String>,
+    description: String,
+    body: Option<String>,
+    breaking_change: Option<String>,
+    refs: Option<String>,
+    config: ValidationConfig,
+}
+
+impl FieldsSource {
+    /// The type is kept as typed and checked on resolve(), so an unknown
+    /// type is reported like any other validation error.
+    pub fn new(commit_type: String, description: String, config: ValidationConfig) -> Self {
+        Self {
+            commit_type,
+            description,
+            config,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_scope(mut self, scope: Option<String>) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    pub fn with_body(mut self, body: Option<String>) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// A BREAKING CHANGE footer; also marks the header with '!'.
+    pub fn with_breaking_change(mut self, breaking_change: Option<String>) -> Self {
+        self.breaking_change = breaking_change;
+        self
+    }
+
+    /// Rendered as a single `Refs:` footer.
+    pub fn with_refs(mut self, refs: Option<String>) -> Self {
+        self.refs = refs;
+        self
+    }
+}
+
+impl CommitMessageSource for FieldsSource {
+    type Error = DomainError;
+
+    fn resolve(&self) -> Result<CommitMessage, DomainError> {
+        let commit_type =
+            CommitType::from_str_with_custom(&self.commit_type, &self.config.custom_types)?;
+        StructuredInput {
+            commit_type,
+            scope: self.scope.clone(),
+            description: self.description.clone(),
+            body: self.body.clone(),
+            breaking_change: self.breaking_change.clone(),
+            breaking: false,
+            closes: None,
+            refs: self.refs.clone(),
+            co_authors: vec![],
+            footers: vec![],
+        }
+        .into_commit_message(&self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(commit_type: &str, description: &str) -> FieldsSource {
+        FieldsSource::new(
+            commit_type.into(),
+            description.into(),
+            ValidationConfig::default(),
+        )
+    }
+
+    #[test]
+    fn maps_flags_to_message() {
+        let msg = source("feat", "add endpoint")
+            .with_scope(Some("api".into()))
+            .with_body(Some("Serves /v2/search.".into()))
+            .with_refs(Some("#42".into()))
+            .resolve()
+            .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(api): add endpoint\n\nServes /v2/search.\n\nRefs: #42"
+        );
+    }
+
+    #[test]
+    fn breaking_sets_marker_and_footer() {
+        let msg = source("feat", "add endpoint")
+            .with_scope(Some("api".into()))
+            .with_breaking_change(Some("v1 removed".into()))
+            .resolve()
+            .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(api)!: add endpoint\n\nBREAKING CHANGE: v1 removed"
+        );
+    }
+
+    #[test]
+    fn fields_are_validated() {
+        assert!(matches!(
+            source("feature", "add endpoint").resolve(),
+            Err(DomainError::InvalidCommitType(..))
+        ));
+        assert!(matches!(
+            source("feat", "").resolve(),
+            Err(DomainError::EmptyDescription)
+        ));
+        assert!(matches!(
+            source("feat", "x")
+                .with_scope(Some("bad scope".into()))
+                .resolve(),
+            Err(DomainError::InvalidScope(_))
+        ));
+    }
+
+    #[test]
+    fn custom_types_are_accepted() {
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        let msg = FieldsSource::new("deps".into(), "bump clap".into(), config)
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "deps: bump clap");
+    }
+}
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use crate::compiler::CompileError;
+use crate::domain::DomainError;
+
+/// Every variant carries the path so the user knows which file to fix.
+#[derive(Debug)]
+pub enum FileError {
+    /// The file could not be read (missing, unreadable, not UTF-8).
+    Read { path: PathBuf, reason: String },
+
+    /// Nothing was left after stripping comment lines.
+    Empty(PathBuf),
+
+    /// The content failed to compile (structural / syntax error).
+    Compile { path: PathBuf, error: CompileError },
+
+    /// The content compiled but failed domain validation.
+    Domain { path: PathBuf, error: DomainError },
+}
+
+impl std::fmt::Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileError::Read { path, reason } => {
+                write!(f, "Failed to read {}: {}", path.display(), reason)
+            }
+            FileError::Empty(path) => {
+                write!(f, "{}: commit message is empty", path.display())
+            }
+            FileError::Compile { path, error } => write!(f, "{}: {}", path.display(), error),
+            FileError::Domain { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for FileError {}
@@ -0,0 +1,215 @@
+mod error;
+pub use error::FileError;
+
+use std::cell::RefCell;
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::compiler::CompilerPipeline;
+use crate::domain::{CommitMessage, ValidationConfig};
+use crate::input::editor::{DEFAULT_COMMENT_CHAR, strip_comments};
+use crate::ports::input::CommitMessageSource;
+
+/// FileSource — compiles a commit message stored in a file (-F / --file).
+///
+/// Comment lines are stripped exactly as in editor mode, so a file saved
+/// from the editor template (or by a git hook) can be passed straight in.
+/// The file is read on resolve(), not at construction.
+///
+/// `-F -` reads stdin instead (see from_reader); errors then name `<stdin>`.
+pub struct FileSource {
+    path: PathBuf,
+    reader: Option<RefCell<Box<dyn Read>>>,
+    compiler: CompilerPipeline,
+    config: ValidationConfig,
+    comment_char: char,
+}
+
+/// The path argument that means "read stdin", as in `git commit -F -`.
+pub const STDIN_PATH: &str = "-";
+
+impl FileSource {
+    pub fn new(path: PathBuf, compiler: CompilerPipeline, config: ValidationConfig) -> Self {
+        Self {
+            path,
+            reader: None,
+            compiler,
+            config,
+            comment_char: DEFAULT_COMMENT_CHAR,
+        }
+    }
+
+    /// Read the message from `reader` (stdin in production) until EOF.
+    pub fn from_reader(
+        reader: impl Read + 'static,
+        compiler: CompilerPipeline,
+        config: ValidationConfig,
+    ) -> Self {
+        Self {
+            path: PathBuf::from("<stdin>"),
+            reader: Some(RefCell::new(Box::new(reader))),
+            compiler,
+            config,
+            comment_char: DEFAULT_COMMENT_CHAR,
+        }
+    }
+
+    /// Strip lines starting with this instead of '#' (git's core.commentChar).
+    pub fn with_comment_char(mut self, comment: char) -> Self {
+        self.comment_char = comment;
+        self
+    }
+
+    fn read(&self) -> std::io::Result<String> {
+        match self.reader {
+            Some(ref reader) => {
+                let mut raw = String::new();
+                reader.borrow_mut().read_to_string(&mut raw)?;
+                Ok(raw)
+            }
+            None => std::fs::read_to_string(&self.path),
+        }
+    }
+}
+
+impl CommitMessageSource for FileSource {
+    type Error = FileError;
+
+    fn resolve(&self) -> Result<CommitMessage, FileError> {
+        let raw = self.read().map_err(|e| FileError::Read {
+            path: self.path.clone(),
+            reason: e.to_string(),
+        })?;
+
+        let cleaned = strip_comments(&raw, self.comment_char);
+        if cleaned.is_empty() {
+            return Err(FileError::Empty(self.path.clone()));
+        }
+
+        let ast = self
+            .compiler
+            .compile(&cleaned)
+            .map_err(|error| FileError::Compile {
+                path: self.path.clone(),
+                error,
+            })?;
+        CommitMessage::from_ast(ast, &self.config).map_err(|error| FileError::Domain {
+            path: self.path.clone(),
+            error,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn source(path: PathBuf) -> FileSource {
+        FileSource::new(path, CompilerPipeline::new(), ValidationConfig::default())
+    }
+
+    fn file_with(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn reads_and_compiles_file() {
+        let file = file_with("feat(auth): add OAuth\n\nMigrated.\n");
+        let msg = source(file.path().to_path_buf()).resolve().unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(auth): add OAuth\n\nMigrated."
+        );
+    }
+
+    #[test]
+    fn comment_lines_are_stripped() {
+        let file = file_with("# Please enter the message\nfix: patch crash\n# trailing hint\n");
+        let msg = source(file.path().to_path_buf()).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix: patch crash");
+    }
+
+    #[test]
+    fn configured_comment_char_keeps_hash_lines() {
+        let file = file_with(
+            "; Please enter the message\ndocs: add usage\n\n# Usage\n\nRun it.\n; hint\n",
+        );
+        let msg = source(file.path().to_path_buf())
+            .with_comment_char(';')
+            .resolve()
+            .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "docs: add usage\n\n# Usage\n\nRun it."
+        );
+    }
+
+    #[test]
+    fn missing_file_is_read_error_with_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nope.txt");
+        let err = source(path.clone()).resolve().unwrap_err();
+        assert!(matches!(err, FileError::Read { .. }));
+        assert!(err.to_string().contains(&path.display().to_string()));
+    }
+
+    #[test]
+    fn all_comment_file_is_empty_error() {
+        let file = file_with("# only\n# comments\n\n");
+        let err = source(file.path().to_path_buf()).resolve().unwrap_err();
+        assert!(matches!(err, FileError::Empty(_)));
+        assert!(err.to_string().ends_with(": commit message is empty"));
+    }
+
+    #[test]
+    fn compile_and_domain_errors_name_the_file() {
+        let bad_syntax = file_with("feat add login");
+        let err = source(bad_syntax.path().to_path_buf())
+            .resolve()
+            .unwrap_err();
+        assert!(matches!(err, FileError::Compile { .. }));
+        assert!(
+            err.to_string()
+                .starts_with(&bad_syntax.path().display().to_string())
+        );
+
+        let bad_type = file_with("nope: add login");
+        let err = source(bad_type.path().to_path_buf()).resolve().unwrap_err();
+        assert!(matches!(err, FileError::Domain { .. }));
+    }
+
+    fn from_stdin(input: &'static str) -> Result<CommitMessage, FileError> {
+        FileSource::from_reader(
+            input.as_bytes(),
+            CompilerPipeline::new(),
+            ValidationConfig::default(),
+        )
+        .resolve()
+    }
+
+    #[test]
+    fn reads_message_from_reader() {
+        let msg = from_stdin("# generated\nfeat(api): add search\n\nBody.\n").unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(api): add search\n\nBody."
+        );
+    }
+
+    #[test]
+    fn empty_stdin_is_an_error() {
+        let err = from_stdin("").unwrap_err();
+        assert!(matches!(err, FileError::Empty(_)));
+        assert_eq!(err.to_string(), "<stdin>: commit message is empty");
+        assert!(matches!(from_stdin("\n  \n"), Err(FileError::Empty(_))));
+    }
+
+    #[test]
+    fn stdin_errors_name_stdin() {
+        let err = from_stdin("feat add login").unwrap_err();
+        assert!(err.to_string().starts_with("<stdin>: "));
+    }
+}
@@ -5,6 +5,9 @@ use crate::ports::ui::UiError;
 pub enum InteractiveError {
     Domain(DomainError),
     Ui(UiError),
+    /// `:back` was typed: collect() returns to the previous field.
+    /// Never escapes InteractiveSource.
+    Back,
 }
 
 impl std::fmt::Display for InteractiveError {
@@ -12,6 +15,7 @@ impl std::fmt::Display for InteractiveError {
         match self {
             InteractiveError::Domain(e) => write!(f, "{}", e),
             InteractiveError::Ui(e) => write!(f, "{}", e),
+            InteractiveError::Back => write!(f, "Returned to the previous field"),
         }
     }
 }
@@ -1,5 +1,7 @@
 mod error;
+mod prefill;
 pub use error::InteractiveError;
+pub use prefill::{Prefill, body_from_changes, infer_from_branch, infer_scope};
 
 /// Interactive input source — collects commit fields one at a time via prompts.
 ///
@@ -7,87 +9,473 @@ pub use error::InteractiveError;
 /// (the unified trait AppController depends on).
 ///
 /// The Ui trait is injected so this works with TerminalUI in production
-/// and MockUi in tests. collect() and all sections/ are unchanged.
+/// and MockUi in tests. collect() walks EDITABLE_FIELDS, one sections/
+/// prompt per field, and `:back` returns to the previous one.
 mod sections;
 
-use crate::domain::CommitMessage;
+use crate::compiler::CompilerPipeline;
+use crate::domain::{CommitMessage, ValidationConfig};
 use crate::ports::input::{CommitMessageSource, InputSource, StructuredInput};
 use crate::ports::ui::Ui;
+use crate::symbols::Symbols;
+use sections::footer::IssueFooter;
 
 pub struct InteractiveSource<U: Ui> {
     ui: U,
+    config: ValidationConfig,
+    prefill: Prefill,
+    scope_suggestions: Vec<String>,
+    type_only: bool,
+    symbols: Symbols,
 }
 
 impl<U: Ui> InteractiveSource<U> {
-    pub fn new(ui: U) -> Self {
-        Self { ui }
+    pub fn new(ui: U, config: ValidationConfig) -> Self {
+        Self {
+            ui,
+            config,
+            prefill: Prefill::default(),
+            scope_suggestions: Vec::new(),
+            type_only: false,
+            symbols: Symbols::default(),
+        }
+    }
+
+    /// Seed every prompt with a default answer (e.g. HEAD when amending).
+    pub fn with_prefill(mut self, prefill: Prefill) -> Self {
+        self.prefill = prefill;
+        self
+    }
+
+    /// Scopes to offer at the scope prompt, best first (e.g. from git log).
+    pub fn with_scope_suggestions(mut self, scopes: Vec<String>) -> Self {
+        self.scope_suggestions = scopes;
+        self
+    }
+
+    /// Ask only for the type; everything else comes from the prefill
+    /// (e.g. `-m "add login"`, a message written without a `type:` prefix).
+    /// Falls back to the full prompts when the prefill has no description.
+    pub fn with_type_only(mut self, type_only: bool) -> Self {
+        self.type_only = type_only;
+        self
+    }
+
+    /// The marks in front of error and warning lines, as for AppController.
+    pub fn with_symbols(mut self, symbols: Symbols) -> Self {
+        self.symbols = symbols;
+        self
     }
 }
 
-/// Low-level field-by-field collection — unchanged.
-/// Still used by resolve() below and by tests.
+/// Low-level field-by-field collection, before the review loop.
+/// Used by resolve() below and by tests.
 impl<U: Ui> InputSource for InteractiveSource<U> {
     type Output = StructuredInput;
     type Error = InteractiveError;
 
+    /// Asks for each field in EDITABLE_FIELDS order. `:back` at any prompt
+    /// steps to the previous field; answers already given become the
+    /// defaults, so nothing typed so far is lost.
     fn collect(&self) -> Result<StructuredInput, InteractiveError> {
         self.ui.println("\n=== commando ===\n");
+        self.ui.println(&format!(
+            "Type {} at any prompt to return to the previous field.\n",
+            sections::BACK
+        ));
 
-        let commit_type = sections::header::collect_type(&self.ui)?;
-        let scope = sections::header::collect_scope(&self.ui)?;
-        let description = sections::header::collect_description(&self.ui)?;
-        let body = sections::body::collect(&self.ui)?;
-        let breaking_change = sections::footer::collect_breaking_change(&self.ui)?;
-        let refs = sections::footer::collect_refs(&self.ui)?;
+        let mut draft = self.prefill.clone();
+        let mut field = 0;
+        while field < EDITABLE_FIELDS.len() {
+            match self.collect_field(&mut draft, field) {
+                Ok(()) => field += 1,
+                Err(InteractiveError::Back) if field == 0 => {
+                    self.ui.println(&format!(
+                        "  {} Already at the first field.",
+                        self.symbols.error
+                    ));
+                }
+                Err(InteractiveError::Back) => {
+                    self.ui.println("");
+                    field -= 1;
+                    // Past the body prompt too if it was skipped.
+                    if field == BODY && self.skips_body(&draft) {
+                        field -= 1;
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
 
         Ok(StructuredInput {
+            commit_type: draft.commit_type.expect("the type field sets it"),
+            scope: draft.scope,
+            description: draft.description.expect("the description field sets it"),
+            body: draft.body,
+            breaking_change: draft.breaking_change,
+            breaking: draft.breaking,
+            closes: draft.closes,
+            refs: draft.refs,
+            co_authors: draft.co_authors,
+            footers: draft.footers,
+        })
+    }
+}
+
+impl<U: Ui> InteractiveSource<U> {
+    /// `field` is a 0-based index into EDITABLE_FIELDS; the answer is
+    /// stored in `draft`, whose current value is the prompt's default.
+    fn collect_field(&self, draft: &mut Prefill, field: usize) -> Result<(), InteractiveError> {
+        let (ui, config) = (&self.ui, &self.config);
+        match field {
+            0 => {
+                draft.commit_type = Some(sections::header::collect_type(
+                    ui,
+                    self.symbols,
+                    config,
+                    draft.commit_type.as_ref(),
+                )?);
+            }
+            1 => {
+                let commit_type = draft.commit_type.as_ref().expect("type comes first");
+                draft.scope = sections::header::collect_scope(
+                    ui,
+                    self.symbols,
+                    config,
+                    commit_type,
+                    draft.scope.as_deref(),
+                    &self.scope_suggestions,
+                )?;
+            }
+            2 => {
+                draft.description = Some(sections::header::collect_description(
+                    ui,
+                    self.symbols,
+                    config,
+                    draft.description.as_deref(),
+                )?);
+            }
+            BODY if self.skips_body(draft) => draft.body = None,
+            BODY => draft.body = sections::body::collect(ui, draft.body.as_deref())?,
+            4 => {
+                (draft.breaking, draft.breaking_change) =
+                    sections::footer::collect_breaking_change(
+                        ui,
+                        self.symbols,
+                        draft.breaking_change.as_deref(),
+                        draft.breaking,
+                    )?;
+            }
+            5 => {
+                draft.closes = sections::footer::collect_issue_footer(
+                    ui,
+                    self.symbols,
+                    config,
+                    IssueFooter::Closes,
+                    draft.closes.as_deref(),
+                )?;
+            }
+            6 => {
+                draft.refs = sections::footer::collect_issue_footer(
+                    ui,
+                    self.symbols,
+                    config,
+                    IssueFooter::Refs,
+                    draft.refs.as_deref(),
+                )?;
+            }
+            _ => {
+                draft.co_authors =
+                    sections::footer::collect_co_authors(ui, self.symbols, &draft.co_authors)?
+            }
+        }
+        Ok(())
+    }
+
+    /// body_forbidden_types: no body prompt for the chosen type.
+    fn skips_body(&self, draft: &Prefill) -> bool {
+        draft
+            .commit_type
+            .as_ref()
+            .is_some_and(|t| self.config.forbids_body(t))
+    }
+}
+
+/// Index of the body in EDITABLE_FIELDS.
+const BODY: usize = 3;
+
+/// Fields the review loop can re-collect, in prompt order.
+const EDITABLE_FIELDS: [&str; 8] = [
+    "type",
+    "scope",
+    "description",
+    "body",
+    "breaking change",
+    "closes",
+    "refs",
+    "co-authors",
+];
+
+impl<U: Ui> InteractiveSource<U> {
+    /// Preview the message and re-collect fields until the user accepts it.
+    /// Each re-prompt defaults to the current value.
+    fn review(&self, mut input: StructuredInput) -> Result<CommitMessage, InteractiveError> {
+        loop {
+            let message = self.build(&input)?;
+            self.ui.show_preview(&message.to_conventional_commit());
+
+            let fields: Vec<String> = EDITABLE_FIELDS
+                .iter()
+                .enumerate()
+                .map(|(i, field)| format!("{}) {}", i + 1, field))
+                .collect();
+            self.ui
+                .println(&format!("Edit a field? {}", fields.join("  ")));
+
+            let choice = self.ui.prompt("Field number (Enter to continue): ")?;
+            match choice.trim().parse::<usize>() {
+                _ if choice.trim().is_empty() => return Ok(message),
+                Ok(n) if (1..=EDITABLE_FIELDS.len()).contains(&n) => {
+                    self.ui.println("");
+                    match self.edit_field(&mut input, n) {
+                        // Nothing before this field here: keep its value.
+                        Err(InteractiveError::Back) => self.ui.println(""),
+                        result => result?,
+                    }
+                }
+                _ => self.ui.println(&format!(
+                    "  {} Enter a number from 1 to {}, or press Enter.",
+                    self.symbols.error,
+                    EDITABLE_FIELDS.len()
+                )),
+            }
+        }
+    }
+
+    fn build(&self, input: &StructuredInput) -> Result<CommitMessage, InteractiveError> {
+        let message = input.clone().into_commit_message(&self.config)?;
+        Ok(if self.config.gitmoji {
+            message.with_gitmoji(input.commit_type.gitmoji().map(String::from))
+        } else {
+            message
+        })
+    }
+
+    /// The type picker alone, combined with the prefilled description.
+    /// No review loop: the app's own preview and confirmation follow.
+    fn collect_type_only(&self, description: &str) -> Result<CommitMessage, InteractiveError> {
+        let p = &self.prefill;
+        let commit_type = loop {
+            match sections::header::collect_type(
+                &self.ui,
+                self.symbols,
+                &self.config,
+                p.commit_type.as_ref(),
+            ) {
+                Err(InteractiveError::Back) => self.ui.println(&format!(
+                    "  {} Already at the first field.",
+                    self.symbols.error
+                )),
+                result => break result?,
+            }
+        };
+        self.build(&StructuredInput {
             commit_type,
-            scope,
-            description,
-            body,
-            breaking_change,
-            refs,
+            scope: p.scope.clone(),
+            description: description.to_string(),
+            body: p.body.clone(),
+            breaking_change: p.breaking_change.clone(),
+            breaking: p.breaking,
+            closes: p.closes.clone(),
+            refs: p.refs.clone(),
+            co_authors: p.co_authors.clone(),
+            footers: p.footers.clone(),
         })
     }
+
+    /// `field` is a 1-based index into EDITABLE_FIELDS.
+    fn edit_field(
+        &self,
+        input: &mut StructuredInput,
+        field: usize,
+    ) -> Result<(), InteractiveError> {
+        let (ui, config) = (&self.ui, &self.config);
+        match field {
+            1 => {
+                input.commit_type = sections::header::collect_type(
+                    ui,
+                    self.symbols,
+                    config,
+                    Some(&input.commit_type),
+                )?;
+                if input.body.is_some() && config.forbids_body(&input.commit_type) {
+                    ui.println(&format!(
+                        "  Body removed: '{}' commits are header-only.",
+                        input.commit_type.as_str()
+                    ));
+                    input.body = None;
+                }
+                // The new type may require a scope the message does not have.
+                if input.scope.is_none() && config.require_scope.requires(&input.commit_type) {
+                    input.scope = sections::header::collect_scope(
+                        ui,
+                        self.symbols,
+                        config,
+                        &input.commit_type,
+                        None,
+                        &self.scope_suggestions,
+                    )?;
+                }
+            }
+            2 => {
+                input.scope = sections::header::collect_scope(
+                    ui,
+                    self.symbols,
+                    config,
+                    &input.commit_type,
+                    input.scope.as_deref(),
+                    &self.scope_suggestions,
+                )?;
+            }
+            3 => {
+                input.description = sections::header::collect_description(
+                    ui,
+                    self.symbols,
+                    config,
+                    Some(&input.description),
+                )?;
+            }
+            4 if config.forbids_body(&input.commit_type) => ui.println(&format!(
+                "  {} '{}' commits may not have a body.",
+                self.symbols.error,
+                input.commit_type.as_str()
+            )),
+            4 => input.body = sections::body::collect(ui, input.body.as_deref())?,
+            5 => {
+                (input.breaking, input.breaking_change) =
+                    sections::footer::collect_breaking_change(
+                        ui,
+                        self.symbols,
+                        input.breaking_change.as_deref(),
+                        input.breaking,
+                    )?;
+            }
+            6 => {
+                input.closes = sections::footer::collect_issue_footer(
+                    ui,
+                    self.symbols,
+                    config,
+                    IssueFooter::Closes,
+                    input.closes.as_deref(),
+                )?;
+            }
+            7 => {
+                input.refs = sections::footer::collect_issue_footer(
+                    ui,
+                    self.symbols,
+                    config,
+                    IssueFooter::Refs,
+                    input.refs.as_deref(),
+                )?;
+            }
+            _ => {
+                input.co_authors =
+                    sections::footer::collect_co_authors(ui, self.symbols, &input.co_authors)?
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Unified trait impl — what AppController calls.
 ///
-/// Wraps collect() and TryFrom. No changes to sections/.
-/// InteractiveError already has a Domain variant from the existing error.rs.
+/// Wraps collect(), then lets the user revise fields before handing the
+/// message over. InteractiveError already has a Domain variant.
 impl<U: Ui> CommitMessageSource for InteractiveSource<U> {
     type Error = InteractiveError;
 
     fn resolve(&self) -> Result<CommitMessage, InteractiveError> {
+        if self.type_only
+            && let Some(description) = &self.prefill.description
+        {
+            return self.collect_type_only(description);
+        }
         let structured = self.collect()?;
-        CommitMessage::try_from(structured).map_err(InteractiveError::Domain)
+        self.review(structured)
+    }
+
+    fn can_revise(&self) -> bool {
+        true
+    }
+
+    /// Back to the review loop, starting from `current`'s fields.
+    fn revise(&self, current: &CommitMessage) -> Result<CommitMessage, InteractiveError> {
+        let compiler = CompilerPipeline::new()
+            .with_gitmoji(self.config.gitmoji)
+            .with_normalize_unicode(self.config.normalize_unicode);
+        let prefill = compiler
+            .compile(&current.to_conventional_commit())
+            .map(|ast| Prefill::from_ast(ast, &self.config))
+            .unwrap_or_default();
+        match (prefill.commit_type, prefill.description) {
+            (Some(commit_type), Some(description)) => self.review(StructuredInput {
+                commit_type,
+                scope: prefill.scope,
+                description,
+                body: prefill.body,
+                breaking_change: prefill.breaking_change,
+                breaking: prefill.breaking,
+                closes: prefill.closes,
+                refs: prefill.refs,
+                co_authors: prefill.co_authors,
+                footers: prefill.footers,
+            }),
+            // Only if the message no longer compiles: ask for everything.
+            _ => self.resolve(),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::CommitType;
+    use crate::domain::{CommitType, ScopeRule};
     use crate::ports::ui::{Ui, UiError};
     use std::cell::RefCell;
 
+    type SelectCall = (String, Vec<String>, Option<usize>);
+
     struct MockUi {
         responses: RefCell<Vec<String>>,
+        /// Index returned from select(); None behaves like a UI with no menu.
+        selection: Option<usize>,
+        /// (label, option values, default) for every select() call.
+        selects: RefCell<Vec<SelectCall>>,
+        printed: RefCell<Vec<String>>,
     }
 
     impl MockUi {
         fn new(responses: Vec<&str>) -> Self {
             Self {
                 responses: RefCell::new(responses.iter().map(|s| s.to_string()).collect()),
+                selection: None,
+                selects: RefCell::new(Vec::new()),
+                printed: RefCell::new(Vec::new()),
             }
         }
 
+        fn selecting(mut self, index: usize) -> Self {
+            self.selection = Some(index);
+            self
+        }
+
+        /// Next scripted answer; an exhausted script just presses Enter.
         fn pop(&self) -> String {
-            self.responses
-                .borrow_mut()
-                .drain(..1)
-                .next()
-                .unwrap_or_default()
+            let mut responses = self.responses.borrow_mut();
+            if responses.is_empty() {
+                return String::new();
+            }
+            responses.remove(0)
         }
     }
 
@@ -99,15 +487,33 @@ mod tests {
             Ok(matches!(self.pop().to_lowercase().as_str(), "y" | "yes"))
         }
         fn show_preview(&self, _content: &str) {}
-        fn println(&self, _msg: &str) {}
+        fn println(&self, msg: &str) {
+            self.printed.borrow_mut().push(msg.to_string());
+        }
+        /// One scripted answer holds the whole text.
+        fn multiline_prompt(&self, _label: &str) -> Result<String, UiError> {
+            Ok(self.pop())
+        }
+        fn select(
+            &self,
+            label: &str,
+            options: &[(&str, &str)],
+            default: Option<usize>,
+        ) -> Result<Option<usize>, UiError> {
+            let values = options.iter().map(|(v, _)| v.to_string()).collect();
+            self.selects
+                .borrow_mut()
+                .push((label.to_string(), values, default));
+            Ok(self.selection)
+        }
     }
 
-    // ── existing collect() tests — all unchanged ──────────────────────────────
+    // ── collect() ─────────────────────────────────────────────────────────────
 
     #[test]
     fn collects_minimal_commit() {
         let ui = MockUi::new(vec!["feat", "", "add login page", "n", "n", ""]);
-        let source = InteractiveSource::new(ui);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
         let result = source.collect().unwrap();
         assert_eq!(result.commit_type, CommitType::Feat);
         assert_eq!(result.scope, None);
@@ -128,7 +534,7 @@ mod tests {
             "n",
             "",
         ]);
-        let source = InteractiveSource::new(ui);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
         let result = source.collect().unwrap();
         assert_eq!(result.commit_type, CommitType::Fix);
     }
@@ -141,15 +547,117 @@ mod tests {
             "update installation guide",
             "n",
             "n",
+            "",
             "#42",
         ]);
-        let source = InteractiveSource::new(ui);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
         let result = source.collect().unwrap();
         assert_eq!(result.commit_type, CommitType::Docs);
         assert_eq!(result.scope, Some("readme".to_string()));
         assert_eq!(result.refs, Some("#42".to_string()));
     }
 
+    #[test]
+    fn collects_multi_paragraph_body() {
+        let ui = MockUi::new(vec![
+            "fix",
+            "",
+            "handle tabs",
+            "y",
+            "Tabs were counted as one column.\n\nNow they expand to 4.",
+            "n",
+            "",
+        ]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(
+            result.body,
+            Some("Tabs were counted as one column.\n\nNow they expand to 4.".into())
+        );
+    }
+
+    #[test]
+    fn back_returns_to_the_previous_field() {
+        // scope "api", then back from the description to change it
+        let ui = MockUi::new(vec![
+            "feat",
+            "api",
+            ":back",
+            "web",
+            "add search",
+            "n",
+            "n",
+            "",
+            "",
+            "",
+        ]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(result.commit_type, CommitType::Feat);
+        assert_eq!(result.scope, Some("web".into()));
+        assert_eq!(result.description, "add search");
+    }
+
+    #[test]
+    fn back_keeps_answers_already_given() {
+        // back from the body question twice: Enter keeps scope and description
+        let ui = MockUi::new(vec![
+            "fix",
+            "parser",
+            "handle tabs",
+            ":back",
+            ":back",
+            "",
+            "",
+            "n",
+            "n",
+            "",
+            "",
+            "",
+        ]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(result.scope, Some("parser".into()));
+        assert_eq!(result.description, "handle tabs");
+    }
+
+    #[test]
+    fn back_at_the_first_field_asks_again() {
+        let ui = MockUi::new(vec![":back", "docs", "", "x", "n", "n", "", "", ""]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(result.commit_type, CommitType::Docs);
+    }
+
+    #[test]
+    fn body_prompt_is_skipped_for_header_only_types() {
+        let config = ValidationConfig {
+            body_forbidden_types: vec!["chore".into()],
+            ..Default::default()
+        };
+        // No body answer: straight from the description to breaking change,
+        // and back from there to the description.
+        let ui = MockUi::new(vec![
+            "chore",
+            "",
+            "bump deps",
+            ":back",
+            "bump all deps",
+            "n",
+            "",
+            "",
+            "",
+        ]);
+        let result = InteractiveSource::new(ui, config).collect().unwrap();
+        assert_eq!(result.description, "bump all deps");
+        assert_eq!(result.body, None);
+        assert_eq!(result.breaking_change, None);
+    }
+
     #[test]
     fn collects_with_breaking_change() {
         let ui = MockUi::new(vec![
@@ -161,7 +669,7 @@ mod tests {
             "old tokens are invalidated",
             "",
         ]);
-        let source = InteractiveSource::new(ui);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
         let result = source.collect().unwrap();
         assert_eq!(result.commit_type, CommitType::Feat);
         assert_eq!(result.scope, Some("auth".to_string()));
@@ -171,16 +679,624 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejects_description_over_configured_limit() {
+        let ui = MockUi::new(vec![
+            "feat",
+            "",
+            "add a very long login page",
+            "add login",
+            "n",
+            "n",
+            "",
+        ]);
+        let config = ValidationConfig {
+            max_description_length: 10,
+            ..Default::default()
+        };
+        let source = InteractiveSource::new(ui, config);
+        let result = source.collect().unwrap();
+        assert_eq!(result.description, "add login");
+    }
+
+    #[test]
+    fn reprompts_description_under_configured_minimum() {
+        let ui = MockUi::new(vec!["fix", "", "bug", "handle empty input", "n", "n", ""]);
+        let config = ValidationConfig {
+            min_description_length: Some(10),
+            ..Default::default()
+        };
+        let result = InteractiveSource::new(ui, config).collect().unwrap();
+        assert_eq!(result.description, "handle empty input");
+    }
+
+    #[test]
+    fn reprompts_refs_under_strict_issue_refs() {
+        let ui = MockUi::new(vec![
+            "fix",
+            "",
+            "handle timeouts",
+            "n",
+            "n",
+            "",
+            "see the tracker",
+            "org/repo#12",
+            "",
+        ]);
+        let config = ValidationConfig {
+            strict_issue_refs: true,
+            ..Default::default()
+        };
+        let result = InteractiveSource::new(ui, config).collect().unwrap();
+        assert_eq!(result.refs, Some("org/repo#12".into()));
+    }
+
+    #[test]
+    fn reprompts_refs_that_are_not_references() {
+        let ui = MockUi::new(vec![
+            "fix",
+            "",
+            "handle timeouts",
+            "n",
+            "n",
+            "see tracker",
+            "12",
+            "",
+            "",
+        ]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(result.closes, Some("#12".into()));
+    }
+
+    #[test]
+    fn dash_skips_an_issue_footer_without_a_default() {
+        let ui = MockUi::new(vec!["fix", "", "handle timeouts", "n", "n", "-", "-", ""]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(result.closes, None);
+        assert_eq!(result.refs, None);
+    }
+
+    #[test]
+    fn accepts_configured_custom_type() {
+        let ui = MockUi::new(vec!["deps", "", "bump clap", "n", "n", ""]);
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        let msg = InteractiveSource::new(ui, config).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "deps: bump clap");
+    }
+
+    #[test]
+    fn required_scope_cannot_be_skipped() {
+        let ui = MockUi::new(vec!["feat", "", "auth", "add login", "n", "n", ""]);
+        let config = ValidationConfig {
+            require_scope: ScopeRule::For(vec!["feat".into()]),
+            ..Default::default()
+        };
+        let msg = InteractiveSource::new(ui, config).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(auth): add login");
+    }
+
+    #[test]
+    fn scope_stays_optional_for_unlisted_types() {
+        let ui = MockUi::new(vec!["docs", "", "fix typo", "n", "n", ""]);
+        let config = ValidationConfig {
+            require_scope: ScopeRule::For(vec!["feat".into()]),
+            ..Default::default()
+        };
+        let msg = InteractiveSource::new(ui, config).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "docs: fix typo");
+    }
+
+    #[test]
+    fn trailing_period_is_reasked_when_rejected() {
+        let ui = MockUi::new(vec!["feat", "", "add login.", "add login", "n", "n", ""]);
+        let config = ValidationConfig {
+            reject_trailing_period: true,
+            ..Default::default()
+        };
+        let msg = InteractiveSource::new(ui, config).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat: add login");
+    }
+
+    #[test]
+    fn path_scope_follows_config() {
+        // rejected "packages/api" is re-asked, then "api" accepted
+        let ui = MockUi::new(vec!["feat", "packages/api", "api", "x", "n", "n", ""]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(api): x");
+
+        let ui = MockUi::new(vec!["feat", "packages/api", "x", "n", "n", ""]);
+        let config = ValidationConfig {
+            allow_path_scopes: true,
+            ..Default::default()
+        };
+        let msg = InteractiveSource::new(ui, config).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(packages/api): x");
+    }
+
+    #[test]
+    fn forbidden_scope_is_re_asked() {
+        let ui = MockUi::new(vec!["feat", "Misc", "api", "x", "n", "n", ""]);
+        let config = ValidationConfig {
+            forbidden_scopes: vec!["misc".into()],
+            ..Default::default()
+        };
+        let msg = InteractiveSource::new(ui, config).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(api): x");
+    }
+
+    #[test]
+    fn scope_suggestion_picked_by_number() {
+        let ui = MockUi::new(vec!["fix", "2", "handle tabs", "n", "n", ""]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .with_scope_suggestions(vec!["api".into(), "parser".into()])
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix(parser): handle tabs");
+    }
+
+    #[test]
+    fn scope_suggestions_still_allow_free_text() {
+        // "3" is out of range for two suggestions, so it is a literal scope.
+        let ui = MockUi::new(vec!["fix", "cli", "x", "n", "n", ""]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .with_scope_suggestions(vec!["api".into(), "parser".into()])
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix(cli): x");
+
+        let ui = MockUi::new(vec!["fix", "3", "x", "n", "n", ""]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .with_scope_suggestions(vec!["api".into(), "parser".into()])
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix(3): x");
+    }
+
+    #[test]
+    fn ascii_symbols_keep_prompts_and_errors_ascii() {
+        // :back at the type, a bad type, a period, then a bad issue ref.
+        let ui = MockUi::new(vec![
+            ":back",
+            "feature",
+            "feat",
+            "",
+            "add login.",
+            "add login",
+            "n",
+            "n",
+            "see tracker",
+            "",
+            "",
+            "",
+        ]);
+        let config = ValidationConfig {
+            reject_trailing_period: true,
+            ..Default::default()
+        };
+        let source = InteractiveSource::new(ui, config).with_symbols(Symbols::ASCII);
+        source.collect().unwrap();
+
+        let printed = source.ui.printed.borrow();
+        assert!(printed.iter().all(|l| l.is_ascii()), "{:?}", printed);
+        assert_eq!(
+            printed.iter().filter(|l| l.starts_with("  [X] ")).count(),
+            4,
+            "{:?}",
+            printed
+        );
+    }
+
+    // ── review loop ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn review_edits_scope_then_confirms() {
+        // collect "feat(api): add search", edit field 2 (scope), then accept
+        let ui = MockUi::new(vec![
+            "feat",
+            "api",
+            "add search",
+            "n",
+            "n",
+            "",
+            "",
+            "",
+            "2",
+            "search",
+            "",
+        ]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(search): add search");
+    }
+
+    #[test]
+    fn review_edit_keeps_other_fields_and_loops() {
+        // collect, edit description, edit refs, then accept
+        let ui = MockUi::new(vec![
+            "fix",
+            "",
+            "handle tabs",
+            "n",
+            "n",
+            "",
+            "#1",
+            "",
+            "3",
+            "handle tabs and spaces",
+            "7",
+            "#2",
+            "",
+        ]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .resolve()
+            .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "fix: handle tabs and spaces\n\nRefs: #2"
+        );
+    }
+
+    #[test]
+    fn review_ignores_invalid_choice() {
+        let ui = MockUi::new(vec!["docs", "", "x", "n", "n", "", "", "", "9", "edit", ""]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "docs: x");
+    }
+
+    #[test]
+    fn back_while_editing_keeps_the_field() {
+        let ui = MockUi::new(vec![
+            "feat", "api", "x", "n", "n", "", "", "", "2", ":back", "",
+        ]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .resolve()
+            .unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(api): x");
+    }
+
+    #[test]
+    fn changing_type_prompts_for_newly_required_scope() {
+        // collect "docs: x", change type to feat, which now asks for a scope
+        let ui = MockUi::new(vec![
+            "docs", "", "x", "n", "n", "", "", "", "1", "feat", "api", "",
+        ]);
+        let config = ValidationConfig {
+            require_scope: ScopeRule::For(vec!["feat".into()]),
+            ..Default::default()
+        };
+        let msg = InteractiveSource::new(ui, config).resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "feat(api): x");
+    }
+
+    // ── closes / refs ─────────────────────────────────────────────────────────
+
+    fn issue_footers(closes: &'static str, refs: &'static str) -> String {
+        let ui = MockUi::new(vec![
+            "fix",
+            "",
+            "handle timeouts",
+            "n",
+            "n",
+            closes,
+            refs,
+            "",
+        ]);
+        InteractiveSource::new(ui, ValidationConfig::default())
+            .resolve()
+            .unwrap()
+            .to_conventional_commit()
+    }
+
+    #[test]
+    fn closes_only() {
+        assert_eq!(
+            issue_footers("12", ""),
+            "fix: handle timeouts\n\nCloses: #12"
+        );
+    }
+
+    #[test]
+    fn refs_only() {
+        assert_eq!(
+            issue_footers("", "#34, GH-5"),
+            "fix: handle timeouts\n\nRefs: #34, GH-5"
+        );
+    }
+
+    #[test]
+    fn closes_and_refs_are_separate_footers() {
+        assert_eq!(
+            issue_footers("#12", "#34"),
+            "fix: handle timeouts\n\nCloses: #12\nRefs: #34"
+        );
+    }
+
+    // ── co-authors ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn collects_one_co_author() {
+        let ui = MockUi::new(vec![
+            "feat",
+            "",
+            "add x",
+            "n",
+            "n",
+            "",
+            "",
+            "Jane Doe <jane@example.com>",
+        ]);
+        let msg = InteractiveSource::new(ui, ValidationConfig::default())
+            .resolve()
+            .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat: add x\n\nCo-authored-by: Jane Doe <jane@example.com>"
+        );
+    }
+
+    #[test]
+    fn collects_multiple_co_authors_after_refs() {
+        let ui = MockUi::new(vec![
+            "feat",
+            "",
+            "add x",
+            "n",
+            "n",
+            "",
+            "#4",
+            "Jane Doe <jane@example.com>",
+            "Sam Roe<sam@example.com>",
+            "Jane Doe <jane@example.com>",
+            "",
+        ]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(
+            result.co_authors,
+            vec!["Jane Doe <jane@example.com>", "Sam Roe <sam@example.com>"]
+        );
+        assert_eq!(
+            result
+                .into_commit_message(&ValidationConfig::default())
+                .unwrap()
+                .to_conventional_commit(),
+            "feat: add x\n\n\
+             Refs: #4\n\
+             Co-authored-by: Jane Doe <jane@example.com>\n\
+             Co-authored-by: Sam Roe <sam@example.com>"
+        );
+    }
+
+    #[test]
+    fn malformed_co_author_is_reasked() {
+        let ui = MockUi::new(vec![
+            "fix",
+            "",
+            "x",
+            "n",
+            "n",
+            "",
+            "",
+            "no-brackets",
+            "Jane <jane@example.com>",
+            "",
+        ]);
+        let result = InteractiveSource::new(ui, ValidationConfig::default())
+            .collect()
+            .unwrap();
+        assert_eq!(result.co_authors, vec!["Jane <jane@example.com>"]);
+    }
+
+    // ── mood ──────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn mood_warning_lets_user_fix_description() {
+        // "added login" → warned, decline → retype "add login"
+        let ui = MockUi::new(vec![
+            "feat",
+            "",
+            "added login",
+            "n",
+            "add login",
+            "n",
+            "n",
+            "",
+        ]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
+        assert_eq!(source.collect().unwrap().description, "add login");
+    }
+
+    #[test]
+    fn mood_warning_lets_user_proceed() {
+        let ui = MockUi::new(vec!["feat", "", "adds login", "y", "n", "n", ""]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
+        assert_eq!(source.collect().unwrap().description, "adds login");
+    }
+
+    // ── select menu ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn type_is_picked_from_select_menu() {
+        // No "type" response: the menu answers, the rest are prompts.
+        let ui = MockUi::new(vec!["", "fix crash", "n", "n", ""]).selecting(1);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
+        let result = source.collect().unwrap();
+        assert_eq!(result.commit_type, CommitType::Fix);
+        assert_eq!(result.description, "fix crash");
+
+        let selects = source.ui.selects.borrow();
+        assert_eq!(selects.len(), 1);
+        let (label, values, default) = &selects[0];
+        assert_eq!(label, "1. Commit type:");
+        assert_eq!(values.len(), 11);
+        assert_eq!(values[0], "feat");
+        assert_eq!(*default, None);
+    }
+
+    #[test]
+    fn select_menu_lists_custom_types_and_preselects_prefill() {
+        let ui = MockUi::new(vec!["", "", "n", "n", ""]).selecting(11);
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        let prefill = Prefill {
+            commit_type: Some(CommitType::Docs),
+            description: Some("bump clap".into()),
+            ..Default::default()
+        };
+        let source = InteractiveSource::new(ui, config).with_prefill(prefill);
+        let result = source.collect().unwrap();
+        assert_eq!(result.commit_type, CommitType::Custom("deps".into()));
+
+        let selects = source.ui.selects.borrow();
+        assert_eq!(selects[0].1.last().map(String::as_str), Some("deps"));
+        assert_eq!(selects[0].2, Some(2));
+    }
+
+    #[test]
+    fn falls_back_to_text_prompt_without_menu() {
+        let ui = MockUi::new(vec!["perf", "", "cache lookups", "n", "n", ""]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
+        assert_eq!(source.collect().unwrap().commit_type, CommitType::Perf);
+        assert_eq!(source.ui.selects.borrow().len(), 1);
+    }
+
+    // ── prefill ───────────────────────────────────────────────────────────────
+
+    fn full_prefill() -> Prefill {
+        Prefill {
+            commit_type: Some(CommitType::Fix),
+            scope: Some("api".into()),
+            description: Some("handle timeouts".into()),
+            body: Some("Retry once.".into()),
+            breaking_change: None,
+            closes: None,
+            refs: Some("#7".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn enter_keeps_every_prefilled_value() {
+        // type, scope, description, keep body, not breaking, closes, refs
+        let ui = MockUi::new(vec!["", "", "", "y", "n", ""]);
+        let source =
+            InteractiveSource::new(ui, ValidationConfig::default()).with_prefill(full_prefill());
+        let result = source.collect().unwrap();
+        assert_eq!(result.commit_type, CommitType::Fix);
+        assert_eq!(result.scope, Some("api".into()));
+        assert_eq!(result.description, "handle timeouts");
+        assert_eq!(result.body, Some("Retry once.".into()));
+        assert_eq!(result.refs, Some("#7".into()));
+    }
+
+    #[test]
+    fn prefilled_values_can_be_replaced_or_cleared() {
+        // new type, clear scope, new description, drop body, not breaking,
+        // no closes, clear refs
+        let ui = MockUi::new(vec!["feat", "-", "add retries", "n", "n", "n", "", "-"]);
+        let source =
+            InteractiveSource::new(ui, ValidationConfig::default()).with_prefill(full_prefill());
+        let result = source.collect().unwrap();
+        assert_eq!(result.commit_type, CommitType::Feat);
+        assert_eq!(result.scope, None);
+        assert_eq!(result.description, "add retries");
+        assert_eq!(result.body, None);
+        assert_eq!(result.refs, None);
+    }
+
+    #[test]
+    fn redo_keeps_the_bang_and_unprompted_footers() {
+        let head = "feat!: drop v1\n\nRefs: #7\nReviewed-by: R <r@x>\nSigned-off-by: A <a@x>";
+        let ast = CompilerPipeline::new().compile(head).unwrap();
+        let prefill = Prefill::from_ast(ast, &ValidationConfig::default());
+        // type, scope, description, no body, keep '!', closes, refs,
+        // co-authors, accept
+        let ui = MockUi::new(vec!["", "", "", "n", "y", "", "", "", ""]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default()).with_prefill(prefill);
+        assert_eq!(source.resolve().unwrap().to_conventional_commit(), head);
+    }
+
+    #[test]
+    fn dropping_the_bang_marker_is_a_prompt_answer() {
+        let ast = CompilerPipeline::new().compile("feat!: drop v1").unwrap();
+        let prefill = Prefill::from_ast(ast, &ValidationConfig::default());
+        // ... drop '!', not breaking, ...
+        let ui = MockUi::new(vec!["", "", "", "n", "n", "n", "", "", "", ""]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default()).with_prefill(prefill);
+        assert_eq!(
+            source.resolve().unwrap().to_conventional_commit(),
+            "feat: drop v1"
+        );
+    }
+
     // ── resolve() tests ───────────────────────────────────────────────────────
 
     #[test]
     fn resolve_returns_commit_message() {
         let ui = MockUi::new(vec!["feat", "", "add login page", "n", "n", ""]);
-        let source = InteractiveSource::new(ui);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
         let result = source.resolve().unwrap();
         assert_eq!(result.to_conventional_commit(), "feat: add login page");
     }
 
+    #[test]
+    fn resolve_adds_type_gitmoji_when_enabled() {
+        let ui = MockUi::new(vec!["fix", "", "handle tabs", "n", "n", ""]);
+        let config = ValidationConfig {
+            gitmoji: true,
+            ..Default::default()
+        };
+        let source = InteractiveSource::new(ui, config);
+        let msg = source.resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "🐛 fix: handle tabs");
+    }
+
+    #[test]
+    fn revise_starts_from_current_message() {
+        // Edit field 3 (description), then accept.
+        let ui = MockUi::new(vec!["3", "handle tabs", ""]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
+        let current = CommitMessage::new(
+            CommitType::Fix,
+            Some("parser".into()),
+            "handle spaces".into(),
+            None,
+            None,
+            vec![("Refs".into(), "#7".into())],
+            &ValidationConfig::default(),
+        )
+        .unwrap();
+        let msg = source.revise(&current).unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "fix(parser): handle tabs\n\nRefs: #7"
+        );
+    }
+
+    #[test]
+    fn revise_keeps_the_bang_and_other_footers() {
+        let ui = MockUi::new(vec![""]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
+        let current =
+            CommitMessage::parse("fix!: handle tabs\n\nFixes: #3\nSigned-off-by: A <a@x>").unwrap();
+        assert_eq!(source.revise(&current).unwrap(), current);
+    }
+
     #[test]
     fn resolve_with_scope_and_breaking() {
         let ui = MockUi::new(vec![
@@ -192,9 +1308,35 @@ mod tests {
             "sessions invalidated",
             "",
         ]);
-        let source = InteractiveSource::new(ui);
+        let source = InteractiveSource::new(ui, ValidationConfig::default());
         let msg = source.resolve().unwrap();
         assert!(msg.to_conventional_commit().contains("feat(auth)!:"));
         assert!(msg.to_conventional_commit().contains("BREAKING CHANGE:"));
     }
+
+    #[test]
+    fn type_only_asks_just_for_the_type() {
+        // A leftover answer proves no other prompt ran.
+        let ui = MockUi::new(vec!["feat", "unused"]);
+        let source = InteractiveSource::new(ui, ValidationConfig::default())
+            .with_prefill(Prefill::from_raw("add login\n\nWith remember-me."))
+            .with_type_only(true);
+        let msg = source.resolve().unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat: add login\n\nWith remember-me."
+        );
+        assert_eq!(*source.ui.responses.borrow(), vec!["unused".to_string()]);
+    }
+
+    #[test]
+    fn type_only_uses_the_select_menu() {
+        let ui = MockUi::new(vec![]).selecting(1);
+        let source = InteractiveSource::new(ui, ValidationConfig::default())
+            .with_prefill(Prefill::from_raw("handle tabs"))
+            .with_type_only(true);
+        let msg = source.resolve().unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix: handle tabs");
+        assert_eq!(source.ui.selects.borrow().len(), 1);
+    }
 }
@@ -0,0 +1,370 @@
+/// Prefill — default answers for the interactive prompts.
+///
+/// Every field is optional. A prompt with a default shows it in brackets
+/// and keeps it when the user presses Enter. Built from a compiled message
+/// (e.g. HEAD when amending) so the user only retypes what changes.
+use crate::compiler::CommitAst;
+use crate::domain::{CommitType, ValidationConfig, is_breaking_change_key};
+use crate::ports::StagedFile;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Prefill {
+    pub commit_type: Option<CommitType>,
+    pub scope: Option<String>,
+    pub description: Option<String>,
+    pub body: Option<String>,
+    pub breaking_change: Option<String>,
+    /// The header's '!'. Kept even without a breaking_change, as in
+    /// `feat!: x`.
+    pub breaking: bool,
+    pub closes: Option<String>,
+    pub refs: Option<String>,
+    pub co_authors: Vec<String>,
+    /// Footers no prompt asks for (Signed-off-by, Fixes, a second Closes),
+    /// carried over as they are.
+    pub footers: Vec<(String, String)>,
+}
+
+impl From<CommitAst> for Prefill {
+    fn from(ast: CommitAst) -> Self {
+        Self::from_ast(ast, &ValidationConfig::default())
+    }
+}
+
+impl Prefill {
+    /// Best-effort mapping — a type string outside the built-ins and the
+    /// config's custom types simply leaves commit_type empty.
+    ///
+    /// The first breaking change, Closes and Refs footers and every
+    /// co-author fill their prompts (keys matched in any case); all other
+    /// footers land in `footers`, so rebuilding the message loses nothing
+    /// but a repeated BREAKING CHANGE, which CommitMessage drops too.
+    pub fn from_ast(ast: CommitAst, config: &ValidationConfig) -> Self {
+        let mut prefill = Self {
+            commit_type: CommitType::from_str_with_custom(
+                &ast.header.commit_type,
+                &config.custom_types,
+            )
+            .ok(),
+            scope: ast.header.scope,
+            description: Some(ast.header.description),
+            body: ast.body.map(|b| b.content),
+            breaking: ast.header.breaking,
+            ..Default::default()
+        };
+
+        for footer in ast.footers {
+            let key = footer.key.as_str();
+            if is_breaking_change_key(key) {
+                prefill.breaking_change.get_or_insert(footer.value);
+            } else if key.eq_ignore_ascii_case("Closes") && prefill.closes.is_none() {
+                prefill.closes = Some(footer.value);
+            } else if key.eq_ignore_ascii_case("Refs") && prefill.refs.is_none() {
+                prefill.refs = Some(footer.value);
+            } else if key.eq_ignore_ascii_case("Co-authored-by") {
+                prefill.co_authors.push(footer.value);
+            } else {
+                prefill.footers.push((footer.key, footer.value));
+            }
+        }
+        prefill
+    }
+
+    /// Fallback for a message that does not compile: the subject becomes
+    /// the description and everything after the first blank line the body.
+    pub fn from_raw(raw: &str) -> Self {
+        let raw = raw.trim();
+        let (subject, rest) = raw.split_once("\n\n").unwrap_or((raw, ""));
+        let non_empty = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+
+        Self {
+            description: non_empty(subject.lines().next().unwrap_or("")),
+            body: non_empty(rest),
+            ..Default::default()
+        }
+    }
+}
+
+/// Type and scope guessed from a branch named `<type>/<scope>-<topic>` or
+/// `<type>/<scope>/<topic>`: `feat/api-oauth` → (feat, api).
+///
+/// The type must be a built-in or one of the config's custom types, else
+/// nothing is inferred. The scope is the first word after the type, and
+/// only when a topic follows it: `fix/login` names a topic, not a scope.
+/// Issue numbers (`fix/123-crash`) are not scopes either.
+pub fn infer_from_branch(
+    branch: &str,
+    config: &ValidationConfig,
+) -> (Option<CommitType>, Option<String>) {
+    let Some((prefix, rest)) = branch.split_once('/') else {
+        return (None, None);
+    };
+    let Ok(commit_type) = CommitType::from_str_with_custom(prefix, &config.custom_types) else {
+        return (None, None);
+    };
+    let scope = rest
+        .split_once(['-', '/'])
+        .map(|(scope, _)| scope)
+        .filter(|scope| !scope.is_empty() && !scope.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_lowercase);
+    (Some(commit_type), scope)
+}
+
+/// A scope guessed from where the staged files live: the name of their
+/// deepest common directory (`src/api/a.rs`, `src/api/b/c.rs` → `api`).
+///
+/// None when they share no directory, or only `src` which says nothing.
+pub fn infer_scope(files: &[StagedFile]) -> Option<String> {
+    let mut dirs = files.iter().map(|f| {
+        let mut parts: Vec<&str> = f.path.split('/').collect();
+        parts.pop(); // the file name
+        parts
+    });
+    let mut common = dirs.next()?;
+    for dir in dirs {
+        let shared = common.iter().zip(&dir).take_while(|(a, b)| a == b).count();
+        common.truncate(shared);
+    }
+    common
+        .last()
+        .filter(|name| **name != "src")
+        .map(|name| name.to_string())
+}
+
+/// A starting body listing what is staged, one bullet per file (--autobody):
+/// `- Modified src/x.rs`, `- Renamed a.rs to b.rs`. None when nothing is.
+pub fn body_from_changes(files: &[StagedFile]) -> Option<String> {
+    let bullets: Vec<String> = files
+        .iter()
+        .map(|f| match (f.status, &f.from) {
+            ('R', Some(from)) => format!("- Renamed {} to {}", from, f.path),
+            ('C', Some(from)) => format!("- Copied {} to {}", from, f.path),
+            ('A', _) => format!("- Added {}", f.path),
+            ('D', _) => format!("- Deleted {}", f.path),
+            ('T', _) => format!("- Changed the type of {}", f.path),
+            _ => format!("- Modified {}", f.path),
+        })
+        .collect();
+    (!bullets.is_empty()).then(|| bullets.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::CompilerPipeline;
+
+    fn staged(paths: &[&str]) -> Vec<StagedFile> {
+        paths
+            .iter()
+            .map(|p| StagedFile {
+                status: 'M',
+                path: p.to_string(),
+                from: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn body_lists_each_staged_change() {
+        let change = |status, path: &str, from: Option<&str>| StagedFile {
+            status,
+            path: path.into(),
+            from: from.map(Into::into),
+        };
+        let files = [
+            change('M', "src/x.rs", None),
+            change('A', "src/y.rs", None),
+            change('D', "old.txt", None),
+            change('R', "src/b.rs", Some("src/a.rs")),
+            change('C', "docs/copy.md", Some("docs/orig.md")),
+            change('T', "bin/run", None),
+        ];
+        assert_eq!(
+            body_from_changes(&files).unwrap(),
+            "- Modified src/x.rs\n\
+             - Added src/y.rs\n\
+             - Deleted old.txt\n\
+             - Renamed src/a.rs to src/b.rs\n\
+             - Copied docs/orig.md to docs/copy.md\n\
+             - Changed the type of bin/run"
+        );
+        assert_eq!(body_from_changes(&[]), None);
+    }
+
+    #[test]
+    fn infers_scope_from_single_directory() {
+        assert_eq!(
+            infer_scope(&staged(&["src/api/a.rs", "src/api/b.rs"])),
+            Some("api".into())
+        );
+        assert_eq!(infer_scope(&staged(&["docs/x.md"])), Some("docs".into()));
+    }
+
+    #[test]
+    fn infers_deepest_common_directory() {
+        assert_eq!(
+            infer_scope(&staged(&["src/api/handlers/a.rs", "src/api/b.rs"])),
+            Some("api".into())
+        );
+        assert_eq!(
+            infer_scope(&staged(&["src/api/handlers/a.rs", "src/api/handlers/b.rs"])),
+            Some("handlers".into())
+        );
+    }
+
+    fn branch(name: &str) -> (Option<CommitType>, Option<String>) {
+        infer_from_branch(name, &ValidationConfig::default())
+    }
+
+    #[test]
+    fn infers_type_and_scope_from_branch() {
+        assert_eq!(
+            branch("feat/api-oauth"),
+            (Some(CommitType::Feat), Some("api".into()))
+        );
+        assert_eq!(
+            branch("fix/parser/handle-tabs"),
+            (Some(CommitType::Fix), Some("parser".into()))
+        );
+        assert_eq!(
+            branch("Docs/README-typos"),
+            (Some(CommitType::Docs), Some("readme".into()))
+        );
+    }
+
+    #[test]
+    fn branch_with_only_a_topic_gives_just_the_type() {
+        assert_eq!(branch("fix/login"), (Some(CommitType::Fix), None));
+        assert_eq!(branch("fix/123-crash"), (Some(CommitType::Fix), None));
+        assert_eq!(branch("feat/-oauth"), (Some(CommitType::Feat), None));
+    }
+
+    #[test]
+    fn branch_without_a_type_prefix_gives_nothing() {
+        assert_eq!(branch("main"), (None, None));
+        assert_eq!(branch("feature/api-oauth"), (None, None));
+        assert_eq!(branch("jane/feat-api"), (None, None));
+        assert_eq!(branch("feat-api-oauth"), (None, None));
+    }
+
+    #[test]
+    fn branch_with_custom_type_is_inferred() {
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            infer_from_branch("deps/clap-4", &config),
+            (Some(CommitType::Custom("deps".into())), Some("clap".into()))
+        );
+    }
+
+    #[test]
+    fn no_scope_when_files_span_directories() {
+        assert_eq!(infer_scope(&staged(&["src/api/a.rs", "docs/x.md"])), None);
+        assert_eq!(infer_scope(&staged(&["src/api/a.rs", "src/cli.rs"])), None);
+        assert_eq!(infer_scope(&staged(&["Cargo.toml"])), None);
+        assert_eq!(infer_scope(&[]), None);
+    }
+
+    fn prefill(input: &str) -> Prefill {
+        Prefill::from(CompilerPipeline::new().compile(input).unwrap())
+    }
+
+    #[test]
+    fn keeps_every_co_author() {
+        let p = prefill(
+            "fix: x\n\nRefs: #1\nCo-authored-by: A <a@x>\nSigned-off-by: C <c@x>\nco-authored-by: B <b@x>",
+        );
+        assert_eq!(p.co_authors, vec!["A <a@x>", "B <b@x>"]);
+    }
+
+    #[test]
+    fn maps_full_commit() {
+        let p = prefill(
+            "feat(auth)!: migrate to OAuth\n\nBody text.\n\nBREAKING CHANGE: sessions gone\nCloses: #41\nRefs: #42",
+        );
+        assert_eq!(p.commit_type, Some(CommitType::Feat));
+        assert_eq!(p.scope, Some("auth".into()));
+        assert_eq!(p.description, Some("migrate to OAuth".into()));
+        assert_eq!(p.body, Some("Body text.".into()));
+        assert_eq!(p.breaking_change, Some("sessions gone".into()));
+        assert_eq!(p.closes, Some("#41".into()));
+        assert_eq!(p.refs, Some("#42".into()));
+    }
+
+    #[test]
+    fn footers_without_a_prompt_are_carried_over() {
+        let p = prefill(
+            "feat!: x\n\nFixes: #1\nCLOSES: #2\nCloses: #3\nREFS: #4\nSigned-off-by: C <c@x>",
+        );
+        assert!(p.breaking);
+        assert_eq!(p.breaking_change, None);
+        assert_eq!(p.closes, Some("#2".into()));
+        assert_eq!(p.refs, Some("#4".into()));
+        assert_eq!(
+            p.footers,
+            vec![
+                ("Fixes".to_string(), "#1".to_string()),
+                ("Closes".to_string(), "#3".to_string()),
+                ("Signed-off-by".to_string(), "C <c@x>".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn hyphenated_breaking_change_is_prefilled() {
+        let p = prefill("feat: x\n\nBREAKING-CHANGE: gone");
+        assert_eq!(p.breaking_change, Some("gone".into()));
+    }
+
+    #[test]
+    fn minimal_commit_leaves_optional_fields_empty() {
+        let p = prefill("fix: handle tabs");
+        assert_eq!(p.commit_type, Some(CommitType::Fix));
+        assert_eq!(p.scope, None);
+        assert_eq!(p.body, None);
+        assert_eq!(p.breaking_change, None);
+        assert_eq!(p.refs, None);
+    }
+
+    #[test]
+    fn custom_type_is_prefilled_when_allowed() {
+        let ast = CompilerPipeline::new().compile("deps: bump clap").unwrap();
+        let config = ValidationConfig {
+            custom_types: vec!["deps".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            Prefill::from_ast(ast, &config).commit_type,
+            Some(CommitType::Custom("deps".into()))
+        );
+    }
+
+    #[test]
+    fn raw_fallback_keeps_subject_and_body() {
+        let p = Prefill::from_raw("Update readme\n\nMention the install script.\n");
+        assert_eq!(
+            p,
+            Prefill {
+                description: Some("Update readme".into()),
+                body: Some("Mention the install script.".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn raw_fallback_without_body() {
+        let p = Prefill::from_raw("WIP");
+        assert_eq!(p.description, Some("WIP".into()));
+        assert_eq!(p.body, None);
+    }
+
+    #[test]
+    fn unknown_type_leaves_type_empty() {
+        let p = prefill("feature: add thing");
+        assert_eq!(p.commit_type, None);
+        assert_eq!(p.description, Some("add thing".into()));
+    }
+}
@@ -2,13 +2,23 @@
 ///
 /// Asks first before launching into multiline collection.
 /// Blank initial response skips the section entirely.
+/// An existing body (from a prefill) is shown first and can be kept as-is.
 use crate::input::interactive::InteractiveError;
 use crate::ports::ui::Ui;
 
-pub fn collect<U: Ui>(ui: &U) -> Result<Option<String>, InteractiveError> {
-    let wants_body = ui
-        .confirm("4. Add a body with more detail?")
-        .map_err(InteractiveError::Ui)?;
+pub fn collect<U: Ui>(ui: &U, default: Option<&str>) -> Result<Option<String>, InteractiveError> {
+    if let Some(existing) = default {
+        ui.println("4. Current body:");
+        for line in existing.lines() {
+            ui.println(&format!("   {}", line));
+        }
+        if super::confirm(ui, "   Keep it?")? {
+            ui.println("");
+            return Ok(Some(existing.to_string()));
+        }
+    }
+
+    let wants_body = super::confirm(ui, "4. Add a body with more detail?")?;
 
     if !wants_body {
         ui.println("");
@@ -16,20 +26,10 @@ pub fn collect<U: Ui>(ui: &U) -> Result<Option<String>, InteractiveError> {
     }
 
     ui.println("");
-    ui.println("Enter body (blank line or Ctrl+D to finish):");
-    ui.println("");
-
-    let mut lines: Vec<String> = Vec::new();
-
-    loop {
-        let input = ui.prompt("")?;
-        if input.is_empty() && !lines.is_empty() {
-            break;
-        }
-        lines.push(input);
+    let body = ui.multiline_prompt("Enter body")?;
+    if body == super::BACK {
+        return Err(InteractiveError::Back);
     }
-
-    let body = lines.join("\n").trim().to_string();
     ui.println("");
 
     if body.is_empty() {
@@ -1,19 +1,43 @@
+use crate::domain::{CommitMessage, ValidationConfig, split_issue_refs};
 use crate::input::interactive::InteractiveError;
-/// Footer section — breaking change description, issue refs, co-authors.
+/// Footer section — breaking change description, closed and referenced
+/// issues, co-authors.
 ///
 /// Breaking change here is the source of truth. The '!' marker in the
 /// header is derived automatically by CommitMessage when
-/// breaking_change.is_some() — we never ask about it separately.
+/// breaking_change.is_some() — we never ask about it separately, except
+/// to keep a bare '!' from the message being edited.
 use crate::ports::ui::Ui;
+use crate::symbols::Symbols;
 
-pub fn collect_breaking_change<U: Ui>(ui: &U) -> Result<Option<String>, InteractiveError> {
-    let is_breaking = ui
-        .confirm("5. Does this break existing functionality?")
-        .map_err(InteractiveError::Ui)?;
+/// Returns (marked '!', breaking change description). `marked` is the
+/// current '!' of the header.
+pub fn collect_breaking_change<U: Ui>(
+    ui: &U,
+    symbols: Symbols,
+    default: Option<&str>,
+    marked: bool,
+) -> Result<(bool, Option<String>), InteractiveError> {
+    if let Some(existing) = default
+        && super::confirm(ui, &format!("5. Keep breaking change '{}'?", existing))?
+    {
+        ui.println("");
+        return Ok((true, Some(existing.to_string())));
+    }
+
+    if marked
+        && default.is_none()
+        && super::confirm(ui, "5. Keep the '!' breaking marker (no description)?")?
+    {
+        ui.println("");
+        return Ok((true, None));
+    }
+
+    let is_breaking = super::confirm(ui, "5. Does this break existing functionality?")?;
 
     if !is_breaking {
         ui.println("");
-        return Ok(None);
+        return Ok((false, None));
     }
 
     ui.println("");
@@ -21,32 +45,149 @@ pub fn collect_breaking_change<U: Ui>(ui: &U) -> Result<Option<String>, Interact
     ui.println("");
 
     loop {
-        let input = ui
-            .prompt("Breaking change: ")
-            .map_err(InteractiveError::Ui)?;
+        let input = super::prompt(ui, "Breaking change: ")?;
 
         if input.is_empty() {
-            ui.println("  ✗ Description cannot be empty. Press Ctrl+C to abort.");
+            ui.println(&format!(
+                "  {} Description cannot be empty. Press Ctrl+C to abort.",
+                symbols.error
+            ));
             continue;
         }
 
         ui.println("");
-        return Ok(Some(input));
+        return Ok((true, Some(input)));
+    }
+}
+
+/// Which issue footer a prompt collects. Each is asked separately and
+/// becomes its own footer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueFooter {
+    /// `Closes: #12` — the issues this commit resolves.
+    Closes,
+    /// `Refs: #34` — related issues it does not close.
+    Refs,
+}
+
+impl IssueFooter {
+    pub fn key(self) -> &'static str {
+        match self {
+            IssueFooter::Closes => "Closes",
+            IssueFooter::Refs => "Refs",
+        }
+    }
+
+    fn question(self) -> &'static str {
+        match self {
+            IssueFooter::Closes => "6. Closes issue? (#)",
+            IssueFooter::Refs => "7. Refs?",
+        }
+    }
+}
+
+/// A bare number is taken as `#<number>`: the prompt asks for an issue.
+fn issue_refs(input: &str) -> String {
+    if !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+        format!("#{}", input)
+    } else {
+        input.to_string()
     }
 }
 
-pub fn collect_refs<U: Ui>(ui: &U) -> Result<Option<String>, InteractiveError> {
-    ui.println("6. Issue references (optional — press Enter to skip):");
-    ui.println("   e.g. #123, closes #456");
+/// Every reference is checked as the parser would (more narrowly with
+/// strict_issue_refs) and the prompt repeats until they all pass. '-'
+/// clears a default and, without one, skips like Enter.
+pub fn collect_issue_footer<U: Ui>(
+    ui: &U,
+    symbols: Symbols,
+    config: &ValidationConfig,
+    footer: IssueFooter,
+    default: Option<&str>,
+) -> Result<Option<String>, InteractiveError> {
+    let key = footer.key();
+    let label = match default {
+        Some(refs) => {
+            ui.println(&format!(
+                "{} (optional {} press Enter to keep, '-' to clear):",
+                footer.question(),
+                symbols.dash
+            ));
+            format!("{} [{}]: ", key, refs)
+        }
+        None => {
+            ui.println(&format!(
+                "{} (optional {} press Enter to skip):",
+                footer.question(),
+                symbols.dash
+            ));
+            format!("{}: ", key)
+        }
+    };
+    ui.println("   e.g. #123, #456 or GH-78");
     ui.println("");
 
-    let input = ui.prompt("Refs: ").map_err(InteractiveError::Ui)?;
+    loop {
+        let input = super::prompt(ui, &label)?;
+        let refs = match input.as_str() {
+            "" => default.map(str::to_string),
+            "-" => None,
+            _ => Some(issue_refs(&input)),
+        };
+
+        if let Some(e) = refs.as_deref().and_then(|refs| {
+            split_issue_refs(refs)
+                .find_map(|r| CommitMessage::check_issue_reference(r, config).err())
+        }) {
+            ui.println(&format!("  {} {}.", symbols.error, e));
+            continue;
+        }
+
+        ui.println("");
+        return Ok(refs);
+    }
+}
+
+/// One "Name <email>" per prompt until an empty answer. Existing co-authors
+/// (when amending) are kept or dropped as a whole.
+pub fn collect_co_authors<U: Ui>(
+    ui: &U,
+    symbols: Symbols,
+    default: &[String],
+) -> Result<Vec<String>, InteractiveError> {
+    if !default.is_empty()
+        && super::confirm(ui, &format!("8. Keep co-authors {}?", default.join(", ")))?
+    {
+        ui.println("");
+        return Ok(default.to_vec());
+    }
 
+    ui.println(&format!(
+        "8. Co-authors (optional {} one per line, Enter when done):",
+        symbols.dash
+    ));
+    ui.println("   e.g. Jane Doe <jane@example.com>");
     ui.println("");
 
-    if input.is_empty() {
-        Ok(None)
-    } else {
-        Ok(Some(input))
+    let mut co_authors: Vec<String> = Vec::new();
+    loop {
+        let input = super::prompt(ui, "Co-author: ")?;
+        if input.is_empty() {
+            ui.println("");
+            return Ok(co_authors);
+        }
+        match CommitMessage::parse_co_author(&input) {
+            Ok(identity) if co_authors.contains(&identity) => {
+                ui.println(&format!(
+                    "  {} {} is already listed.",
+                    symbols.error, identity
+                ));
+            }
+            Ok(identity) => co_authors.push(identity),
+            Err(_) => ui.println(&format!(
+                "  {} Use the form Name <email>, e.g. Jane Doe <jane@example.com>.",
+                symbols.error
+            )),
+        }
     }
 }
@@ -3,88 +3,249 @@
 /// Each function validates its field immediately at prompt time.
 /// A bad value is rejected before the user moves on — no post-hoc
 /// validation needed for these fields.
-use crate::domain::{CommitMessage, CommitType};
+use crate::domain::{CommitMessage, CommitType, DomainError, ValidationConfig};
 use crate::input::interactive::InteractiveError;
 use crate::ports::ui::Ui;
+use crate::symbols::Symbols;
+
+pub fn collect_type<U: Ui>(
+    ui: &U,
+    symbols: Symbols,
+    config: &ValidationConfig,
+    default: Option<&CommitType>,
+) -> Result<CommitType, InteractiveError> {
+    let types: Vec<CommitType> = CommitType::all()
+        .iter()
+        .cloned()
+        .chain(config.custom_types.iter().cloned().map(CommitType::Custom))
+        .collect();
+
+    // Menu first — UIs without one return None and get the text prompt.
+    let options: Vec<(&str, &str)> = types
+        .iter()
+        .map(|t| (t.as_str(), t.description()))
+        .collect();
+    let preselected = default.and_then(|d| types.iter().position(|t| t == d));
+    if let Some(i) = ui
+        .select("1. Commit type:", &options, preselected)
+        .map_err(InteractiveError::Ui)?
+        && let Some(ct) = types.get(i)
+    {
+        return Ok(ct.clone());
+    }
 
-pub fn collect_type<U: Ui>(ui: &U) -> Result<CommitType, InteractiveError> {
     ui.println("1. Commit type:");
-    ui.println("   feat      — new feature");
-    ui.println("   fix       — bug fix");
-    ui.println("   docs      — documentation only");
-    ui.println("   style     — formatting, whitespace");
-    ui.println("   refactor  — code restructuring");
-    ui.println("   perf      — performance improvement");
-    ui.println("   test      — adding or fixing tests");
-    ui.println("   build     — build system / dependencies");
-    ui.println("   ci        — CI configuration");
-    ui.println("   chore     — maintenance");
-    ui.println("   revert    — revert a previous commit");
+    for ct in &types {
+        ui.println(&format!(
+            "   {:<9} {} {}",
+            ct.as_str(),
+            symbols.dash,
+            ct.description()
+        ));
+    }
     ui.println("");
 
+    let label = match default {
+        Some(ct) => format!("Type [{}]: ", ct),
+        None => "Type: ".to_string(),
+    };
+
     loop {
-        let input = ui.prompt("Type: ").map_err(InteractiveError::Ui)?;
+        let input = super::prompt(ui, &label)?;
+
+        if input.is_empty()
+            && let Some(ct) = default
+        {
+            ui.println("");
+            return Ok(ct.clone());
+        }
 
-        match CommitType::from_str(&input) {
+        match CommitType::from_str_with_custom(&input, &config.custom_types) {
             Ok(ct) => {
                 ui.println("");
                 return Ok(ct);
             }
             Err(_) => {
                 ui.println(&format!(
-                    "  ✗ '{}' is not valid. Choose from the list above.",
-                    input
+                    "  {} '{}' is not valid. Choose from the list above.",
+                    symbols.error, input
                 ));
             }
         }
     }
 }
 
-pub fn collect_scope<U: Ui>(ui: &U) -> Result<Option<String>, InteractiveError> {
-    ui.println("2. Scope (optional — press Enter to skip):");
-    ui.println("   e.g. api, parser, auth-service");
+/// Recent scopes offered by number; the rest are still typeable.
+const MAX_SCOPE_SUGGESTIONS: usize = 5;
+
+pub fn collect_scope<U: Ui>(
+    ui: &U,
+    symbols: Symbols,
+    config: &ValidationConfig,
+    commit_type: &CommitType,
+    default: Option<&str>,
+    suggestions: &[String],
+) -> Result<Option<String>, InteractiveError> {
+    let suggestions = &suggestions[..suggestions.len().min(MAX_SCOPE_SUGGESTIONS)];
+    let required = config.require_scope.requires(commit_type);
+    let label = match default {
+        Some(scope) if required => {
+            ui.println(&format!(
+                "2. Scope (required {} press Enter to keep):",
+                symbols.dash
+            ));
+            format!("Scope [{}]: ", scope)
+        }
+        Some(scope) => {
+            ui.println(&format!(
+                "2. Scope (optional {} press Enter to keep, '-' to clear):",
+                symbols.dash
+            ));
+            format!("Scope [{}]: ", scope)
+        }
+        None if required => {
+            ui.println("2. Scope (required):");
+            "Scope: ".to_string()
+        }
+        None => {
+            ui.println(&format!(
+                "2. Scope (optional {} press Enter to skip):",
+                symbols.dash
+            ));
+            "Scope: ".to_string()
+        }
+    };
+    if suggestions.is_empty() {
+        ui.println("   e.g. api, parser, auth-service");
+    } else {
+        let recent: Vec<String> = suggestions
+            .iter()
+            .enumerate()
+            .map(|(i, scope)| format!("{}) {}", i + 1, scope))
+            .collect();
+        ui.println(&format!("   Recent: {}", recent.join("  ")));
+        ui.println("   Enter a number to reuse one, or type a new scope.");
+    }
     ui.println("");
 
     loop {
-        let input = ui.prompt("Scope: ").map_err(InteractiveError::Ui)?;
+        let input = super::prompt(ui, &label)?;
+        let input = match input.parse::<usize>() {
+            Ok(n) if (1..=suggestions.len()).contains(&n) => suggestions[n - 1].clone(),
+            _ => input,
+        };
 
         if input.is_empty() {
+            if required && default.is_none() {
+                ui.println(&format!(
+                    "  {} Scope is required for '{}' commits.",
+                    symbols.error,
+                    commit_type.as_str()
+                ));
+                continue;
+            }
+            ui.println("");
+            return Ok(default.map(str::to_string));
+        }
+
+        if input == "-" && default.is_some() && !required {
             ui.println("");
             return Ok(None);
         }
 
-        match CommitMessage::validate_scope(&input) {
+        match CommitMessage::validate_scope(&input, config) {
             Ok(()) => {
                 ui.println("");
                 return Ok(Some(input));
             }
+            Err(e @ DomainError::ScopeCase(..)) => {
+                ui.println(&format!("  {} {}.", symbols.error, e))
+            }
+            Err(DomainError::ForbiddenScope(scope)) => {
+                ui.println(&format!(
+                    "  {} Scope '{}' is not allowed here. Choose a more specific one.",
+                    symbols.error, scope
+                ));
+            }
             Err(_) => {
-                ui.println("  ✗ Scope must be alphanumeric with hyphens/underscores only.");
+                let allowed = if config.allow_path_scopes {
+                    "alphanumeric with hyphens/underscores/dots, optionally as a path (packages/api)"
+                } else {
+                    "alphanumeric with hyphens/underscores only"
+                };
+                ui.println(&format!("  {} Scope must be {}.", symbols.error, allowed));
             }
         }
     }
 }
 
-pub fn collect_description<U: Ui>(ui: &U) -> Result<String, InteractiveError> {
-    ui.println("3. Description (max 72 characters):");
+pub fn collect_description<U: Ui>(
+    ui: &U,
+    symbols: Symbols,
+    config: &ValidationConfig,
+    default: Option<&str>,
+) -> Result<String, InteractiveError> {
+    let max = config.max_description_length;
+    match config.min_description_length {
+        Some(min) => ui.println(&format!("3. Description ({}-{} characters):", min, max)),
+        None => ui.println(&format!("3. Description (max {} characters):", max)),
+    }
     ui.println("");
 
+    let label = match default {
+        Some(description) => format!("Description [{}]: ", description),
+        None => "Description: ".to_string(),
+    };
+
     loop {
-        let input = ui.prompt("Description: ").map_err(InteractiveError::Ui)?;
+        let input = super::prompt(ui, &label)?;
+        let input = match default {
+            Some(description) if input.is_empty() => description.to_string(),
+            _ => input,
+        };
 
         if input.is_empty() {
-            ui.println("  ✗ Description cannot be empty.");
+            ui.println(&format!("  {} Description cannot be empty.", symbols.error));
+            continue;
+        }
+
+        if input.len() > max {
+            ui.println(&format!(
+                "  {} {}/{} characters {} too long. Please shorten.",
+                symbols.error,
+                input.len(),
+                max,
+                symbols.dash
+            ));
             continue;
         }
 
-        if input.len() > 72 {
+        if let Some(min) = config.min_description_length {
+            let len = input.trim().chars().count();
+            if len < min {
+                ui.println(&format!(
+                    "  {} {}/{} characters {} too short to be useful in history. Say what changed.",
+                    symbols.error, len, min, symbols.dash
+                ));
+                continue;
+            }
+        }
+
+        if config.reject_trailing_period && input.trim_end().ends_with('.') {
             ui.println(&format!(
-                "  ✗ {}/72 characters — too long. Please shorten.",
-                input.len()
+                "  {} Description must not end with a period.",
+                symbols.error
             ));
             continue;
         }
 
+        if let Some(hint) = CommitMessage::mood_hint(&input) {
+            ui.println(&format!("  {} {}.", symbols.warning, hint));
+            if !super::confirm(ui, "   Keep it anyway?")? {
+                continue;
+            }
+        }
+
         ui.println("");
         return Ok(input);
     }
@@ -1,3 +1,25 @@
 pub mod body;
 pub mod footer;
 pub mod header;
+
+use crate::input::interactive::InteractiveError;
+use crate::ports::ui::{Ui, answer};
+
+/// Typed at any prompt: return to the previous field.
+pub const BACK: &str = ":back";
+
+/// ui.prompt(), with `:back` turned into InteractiveError::Back.
+pub fn prompt<U: Ui>(ui: &U, label: &str) -> Result<String, InteractiveError> {
+    let input = ui.prompt(label).map_err(InteractiveError::Ui)?;
+    if input.trim() == BACK {
+        return Err(InteractiveError::Back);
+    }
+    Ok(input)
+}
+
+/// A yes/no question (Enter is no) that also takes `:back`.
+/// Asked through prompt() so the answer can be something other than y/n.
+pub fn confirm<U: Ui>(ui: &U, msg: &str) -> Result<bool, InteractiveError> {
+    let input = prompt(ui, &format!("{} (y/N): ", msg))?;
+    Ok(answer(&input, false))
+}
@@ -0,0 +1,41 @@
+use crate::domain::DomainError;
+
+#[derive(Debug)]
+pub enum JsonError {
+    /// Not JSON, or not an object of the expected shape.
+    Parse(String),
+
+    /// A `BREAKING CHANGE` entry in `footers`, which belongs in `breaking`.
+    BreakingFooter(String),
+
+    /// The object was well-formed but failed domain validation.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonError::Parse(e) => write!(f, "Invalid JSON commit: {}", e),
+            JsonError::BreakingFooter(key) => write!(
+                f,
+                "Invalid JSON commit: '{}' is not allowed in \"footers\"; describe the breaking change in the \"breaking\" field",
+                key
+            ),
+            JsonError::Domain(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(e: serde_json::Error) -> Self {
+        JsonError::Parse(e.to_string())
+    }
+}
+
+impl From<DomainError> for JsonError {
+    fn from(e: DomainError) -> Self {
+        JsonError::Domain(e)
+    }
+}
@@ -0,0 +1,212 @@
+mod error;
+pub use error::JsonError;
+
+use serde::Deserialize;
+
+use crate::domain::{CommitMessage, CommitType, ValidationConfig, is_breaking_change_key};
+use crate::ports::input::CommitMessageSource;
+
+/// JsonSource — builds a commit from a JSON object, for release tooling
+/// that would otherwise have to quote a multi-line `-m` for the shell:
+///
+/// ```json
+/// {
+///   "type": "feat",
+///   "scope": "api",
+///   "description": "add search",
+///   "body": "Served from /v2/search.",
+///   "breaking": "v1 search is gone",
+///   "footers": [{ "key": "Refs", "value": "#42" }]
+/// }
+/// ```
+///
+/// Only `type` and `description` are required; unknown fields are
+/// rejected, and so is a `BREAKING CHANGE` footer: that is `breaking`.
+/// Like FieldsSource, nothing is compiled: the fields go straight to
+/// CommitMessage::new.
+pub struct JsonSource {
+    raw: String,
+    config: ValidationConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonCommit {
+    #[serde(rename = "type")]
+    commit_type: String,
+    scope: Option<String>,
+    description: String,
+    body: Option<String>,
+    /// The BREAKING CHANGE footer; also marks the header with '!'.
+    breaking: Option<String>,
+    #[serde(default)]
+    footers: Vec<JsonFooter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonFooter {
+    key: String,
+    value: String,
+}
+
+impl JsonSource {
+    pub fn new(raw: String, config: ValidationConfig) -> Self {
+        Self { raw, config }
+    }
+}
+
+impl CommitMessageSource for JsonSource {
+    type Error = JsonError;
+
+    fn resolve(&self) -> Result<CommitMessage, JsonError> {
+        let commit: JsonCommit = serde_json::from_str(&self.raw)?;
+        if let Some(footer) = commit
+            .footers
+            .iter()
+            .find(|f| is_breaking_change_key(&f.key))
+        {
+            return Err(JsonError::BreakingFooter(footer.key.clone()));
+        }
+        let commit_type =
+            CommitType::from_str_with_custom(&commit.commit_type, &self.config.custom_types)?;
+        Ok(CommitMessage::new(
+            commit_type,
+            commit.scope,
+            commit.description,
+            commit.body,
+            commit.breaking,
+            commit
+                .footers
+                .into_iter()
+                .map(|f| (f.key, f.value))
+                .collect(),
+            &self.config,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DomainError;
+
+    fn resolve(raw: &str) -> Result<CommitMessage, JsonError> {
+        JsonSource::new(raw.into(), ValidationConfig::default()).resolve()
+    }
+
+    #[test]
+    fn maps_a_full_object() {
+        let msg = resolve(
+            r##"{
+                "type": "feat",
+                "scope": "api",
+                "description": "add search",
+                "body": "Served from /v2/search.\n\nPaginated.",
+                "breaking": "v1 search is gone",
+                "footers": [
+                    { "key": "Refs", "value": "#42" },
+                    { "key": "Co-authored-by", "value": "Jane Doe <jane@example.com>" }
+                ]
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(api)!: add search\n\nServed from /v2/search.\n\nPaginated.\n\n\
+             BREAKING CHANGE: v1 search is gone\nRefs: #42\n\
+             Co-authored-by: Jane Doe <jane@example.com>"
+        );
+    }
+
+    #[test]
+    fn minimal_object() {
+        let msg = resolve(r#"{"type": "fix", "description": "handle tabs"}"#).unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix: handle tabs");
+    }
+
+    #[test]
+    fn malformed_object_names_the_problem() {
+        let err = resolve(r#"{"type": "fix"}"#).unwrap_err();
+        assert!(matches!(err, JsonError::Parse(_)));
+        assert!(
+            err.to_string().contains("missing field `description`"),
+            "{}",
+            err
+        );
+
+        let err = resolve(r#"{"type": "fix", "description": "x", "subject": "y"}"#).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown field `subject`"),
+            "{}",
+            err
+        );
+
+        assert!(matches!(resolve("feat: x"), Err(JsonError::Parse(_))));
+    }
+
+    #[test]
+    fn fields_are_validated() {
+        assert!(matches!(
+            resolve(r#"{"type": "feature", "description": "x"}"#),
+            Err(JsonError::Domain(DomainError::InvalidCommitType(..)))
+        ));
+        assert!(matches!(
+            resolve(r#"{"type": "feat", "scope": "bad scope", "description": "x"}"#),
+            Err(JsonError::Domain(DomainError::InvalidScope(_)))
+        ));
+    }
+
+    #[test]
+    fn multi_line_description_is_rejected() {
+        assert!(matches!(
+            resolve(r#"{"type": "feat", "description": "a\n\nb"}"#),
+            Err(JsonError::Domain(DomainError::ControlCharacter(
+                "description"
+            )))
+        ));
+    }
+
+    #[test]
+    fn footers_must_parse_back() {
+        assert!(matches!(
+            resolve(
+                r#"{"type": "feat", "description": "a",
+                    "footers": [{"key": "bad key", "value": "x"}]}"#
+            ),
+            Err(JsonError::Domain(DomainError::InvalidFooter(key, _))) if key == "bad key"
+        ));
+        assert!(matches!(
+            resolve(
+                r#"{"type": "feat", "description": "a",
+                    "footers": [{"key": "Reviewed-by", "value": "Jane\nBREAKING CHANGE: y"}]}"#
+            ),
+            Err(JsonError::Domain(DomainError::InvalidFooter(..)))
+        ));
+        assert!(matches!(
+            resolve(
+                r#"{"type": "feat", "description": "a",
+                    "footers": [{"key": "Refs", "value": "see tracker"}]}"#
+            ),
+            Err(JsonError::Domain(DomainError::InvalidIssueReference(r))) if r == "see"
+        ));
+    }
+
+    #[test]
+    fn breaking_change_footer_points_to_the_breaking_field() {
+        for key in ["BREAKING CHANGE", "BREAKING-CHANGE"] {
+            let err = resolve(&format!(
+                r#"{{"type": "feat", "description": "a",
+                    "footers": [{{"key": "{}", "value": "gone"}}]}}"#,
+                key
+            ))
+            .unwrap_err();
+            assert!(matches!(&err, JsonError::BreakingFooter(k) if k == key));
+            assert!(
+                err.to_string().contains(r#"the "breaking" field"#),
+                "{}",
+                err
+            );
+        }
+    }
+}
@@ -1,7 +1,21 @@
+pub mod autosquash;
 pub mod direct;
 pub mod editor;
+pub mod fields;
+pub mod file;
 mod interactive;
+pub mod json;
+pub mod paste;
+pub mod revert;
 
+pub use autosquash::AutosquashSource;
 pub use direct::DirectSource;
-pub use editor::EditorSource;
-pub use interactive::InteractiveSource;
+pub use editor::{EditorSource, Guided};
+pub use fields::FieldsSource;
+pub use file::{FileSource, STDIN_PATH};
+pub use interactive::{
+    InteractiveSource, Prefill, body_from_changes, infer_from_branch, infer_scope,
+};
+pub use json::JsonSource;
+pub use paste::PasteSource;
+pub use revert::RevertSource;
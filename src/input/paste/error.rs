@@ -0,0 +1,52 @@
+use crate::compiler::CompileError;
+use crate::domain::DomainError;
+use crate::error::CommandoError;
+
+#[derive(Debug)]
+pub enum PasteError {
+    /// Reading the pasted text failed.
+    Read(String),
+
+    /// Nothing but whitespace was pasted.
+    Empty,
+
+    /// The pasted text failed to compile (structural / syntax error).
+    Compile(CompileError),
+
+    /// The pasted text compiled but failed domain validation.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for PasteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasteError::Read(e) => write!(f, "Failed to read pasted message: {}", e),
+            PasteError::Empty => write!(f, "No commit message was pasted"),
+            PasteError::Compile(e) => write!(f, "{}", e),
+            PasteError::Domain(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for PasteError {}
+
+impl From<CompileError> for PasteError {
+    fn from(e: CompileError) -> Self {
+        PasteError::Compile(e)
+    }
+}
+
+impl From<CommandoError> for PasteError {
+    fn from(e: CommandoError) -> Self {
+        match e {
+            CommandoError::Compile(e) => PasteError::Compile(e),
+            CommandoError::Domain(e) => PasteError::Domain(e),
+        }
+    }
+}
+
+impl From<DomainError> for PasteError {
+    fn from(e: DomainError) -> Self {
+        PasteError::Domain(e)
+    }
+}
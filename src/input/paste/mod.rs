@@ -0,0 +1,131 @@
+mod error;
+pub use error::PasteError;
+
+use std::cell::RefCell;
+use std::io::Read;
+
+use crate::compiler::CompilerPipeline;
+use crate::domain::{CommitMessage, ValidationConfig};
+use crate::ports::input::CommitMessageSource;
+
+/// PasteSource — reads a complete message from a reader until EOF.
+///
+/// Production passes stdin: the user pastes a message and presses Ctrl-D.
+/// Tests pass any `Read` (e.g. a byte slice), so no terminal is needed.
+///
+/// Trailing newlines are trimmed before compiling; whitespace-only input
+/// is PasteError::Empty rather than a confusing lexer error.
+pub struct PasteSource<R: Read> {
+    reader: RefCell<R>,
+    compiler: CompilerPipeline,
+    config: ValidationConfig,
+}
+
+impl<R: Read> PasteSource<R> {
+    pub fn new(reader: R, compiler: CompilerPipeline, config: ValidationConfig) -> Self {
+        Self {
+            reader: RefCell::new(reader),
+            compiler,
+            config,
+        }
+    }
+}
+
+impl<R: Read> CommitMessageSource for PasteSource<R> {
+    type Error = PasteError;
+
+    fn resolve(&self) -> Result<CommitMessage, PasteError> {
+        let mut raw = String::new();
+        self.reader
+            .borrow_mut()
+            .read_to_string(&mut raw)
+            .map_err(|e| PasteError::Read(e.to_string()))?;
+
+        if raw.trim().is_empty() {
+            return Err(PasteError::Empty);
+        }
+
+        let raw = raw.trim_end_matches(['\n', '\r']);
+        Ok(CommitMessage::parse_with(
+            raw,
+            &self.compiler,
+            &self.config,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(input: &str) -> Result<CommitMessage, PasteError> {
+        PasteSource::new(
+            input.as_bytes(),
+            CompilerPipeline::new(),
+            ValidationConfig::default(),
+        )
+        .resolve()
+    }
+
+    #[test]
+    fn reads_full_message() {
+        let msg = resolve("feat(auth): add OAuth\n\nMigrated.\n\nRefs: #42").unwrap();
+        assert_eq!(
+            msg.to_conventional_commit(),
+            "feat(auth): add OAuth\n\nMigrated.\n\nRefs: #42"
+        );
+    }
+
+    #[test]
+    fn trailing_newlines_are_trimmed() {
+        let msg = resolve("fix: patch crash\n\n\n").unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix: patch crash");
+    }
+
+    #[test]
+    fn crlf_paste_is_accepted() {
+        let msg = resolve("fix: patch crash\r\n\r\nBody.\r\n").unwrap();
+        assert_eq!(msg.to_conventional_commit(), "fix: patch crash\n\nBody.");
+    }
+
+    #[test]
+    fn empty_input_is_a_clear_error() {
+        assert!(matches!(resolve(""), Err(PasteError::Empty)));
+        assert!(matches!(resolve("  \n\n"), Err(PasteError::Empty)));
+        assert_eq!(
+            PasteError::Empty.to_string(),
+            "No commit message was pasted"
+        );
+    }
+
+    #[test]
+    fn compile_error_surfaces() {
+        assert!(matches!(
+            resolve("feat add login\n"),
+            Err(PasteError::Compile(_))
+        ));
+    }
+
+    #[test]
+    fn domain_error_surfaces() {
+        assert!(matches!(
+            resolve("nope: add login\n"),
+            Err(PasteError::Domain(_))
+        ));
+    }
+
+    #[test]
+    fn read_failure_is_reported() {
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("pipe closed"))
+            }
+        }
+        let source = PasteSource::new(Broken, CompilerPipeline::new(), ValidationConfig::default());
+        assert_eq!(
+            source.resolve().unwrap_err().to_string(),
+            "Failed to read pasted message: pipe closed"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::compiler::CompilerPipeline;
+use crate::domain::{CommitMessage, CommitType, DomainError, ValidationConfig};
+use crate::ports::input::CommitMessageSource;
+
+/// RevertSource — the message for reverting an earlier commit
+/// (`--revert <SHA>`), in the conventional form:
+///
+/// ```text
+/// revert: <original subject>
+///
+/// This reverts commit <sha>.
+/// ```
+///
+/// The composition root looks up the target's full SHA and subject; this
+/// only formats them. Nothing is prompted for, like FieldsSource.
+#[derive(Debug, Clone)]
+pub struct RevertSource {
+    sha: String,
+    subject: String,
+    config: ValidationConfig,
+}
+
+impl RevertSource {
+    pub fn new(sha: String, subject: String, config: ValidationConfig) -> Self {
+        Self {
+            sha,
+            subject,
+            config,
+        }
+    }
+}
+
+/// The reverted commit's subject as the new description. A conventional
+/// subject is quoted verbatim — `revert: "feat(api): add search"` — so its
+/// own `type:` does not read as part of this header.
+pub fn revert_description(subject: &str) -> String {
+    let subject = subject.trim();
+    if CompilerPipeline::new().compile(subject).is_ok() {
+        format!("\"{}\"", subject)
+    } else {
+        subject.to_string()
+    }
+}
+
+impl CommitMessageSource for RevertSource {
+    type Error = DomainError;
+
+    fn resolve(&self) -> Result<CommitMessage, DomainError> {
+        CommitMessage::new(
+            CommitType::Revert,
+            None,
+            revert_description(&self.subject),
+            Some(format!("This reverts commit {}.", self.sha)),
+            None,
+            vec![],
+            &self.config,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA: &str = "1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b";
+
+    fn resolve(subject: &str) -> Result<CommitMessage, DomainError> {
+        RevertSource::new(SHA.into(), subject.into(), ValidationConfig::default()).resolve()
+    }
+
+    #[test]
+    fn formats_plain_subject() {
+        assert_eq!(
+            resolve("Add search endpoint")
+                .unwrap()
+                .to_conventional_commit(),
+            format!(
+                "revert: Add search endpoint\n\nThis reverts commit {}.",
+                SHA
+            )
+        );
+    }
+
+    #[test]
+    fn quotes_conventional_subject_verbatim() {
+        assert_eq!(
+            resolve("feat(api)!: add search")
+                .unwrap()
+                .to_conventional_commit(),
+            format!(
+                "revert: \"feat(api)!: add search\"\n\nThis reverts commit {}.",
+                SHA
+            )
+        );
+    }
+
+    #[test]
+    fn reports_subject_too_long_for_the_header() {
+        assert!(matches!(
+            resolve(&format!("feat: {}", "x".repeat(70))),
+            Err(DomainError::DescriptionTooLong { .. })
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+//! commando — conventional commit compiler and validator.
+//!
+//! The binary is a thin shell over this crate. Embedders (pre-commit tools,
+//! CI linters) get the same pipeline the CLI uses:
+//!
+//!   raw string → CompilerPipeline → CommitAst → CommitMessage
+//!
+//! Only the items re-exported here, and the port traits in [`ports`], are
+//! public API. The adapters and input sources stay internal to the CLI.
+//!
+//! To run the commit flow itself, build an [`AppController`]: the default
+//! uses git, the terminal and the editor, and any port can be swapped for
+//! an implementation of its trait.
+//!
+//! ```no_run
+//! use commando::AppControllerBuilder;
+//!
+//! let code = AppControllerBuilder::default().build().run();
+//! ```
+//!
+//! ```
+//! use commando::{CommandoError, compile_and_validate};
+//!
+//! let message = compile_and_validate("feat(api): add search").unwrap();
+//! assert_eq!(message.to_conventional_commit(), "feat(api): add search");
+//!
+//! assert!(matches!(
+//!     compile_and_validate("feat add search"),
+//!     Err(CommandoError::Compile(_))
+//! ));
+//! assert!(matches!(
+//!     compile_and_validate("feature: add search"),
+//!     Err(CommandoError::Domain(_))
+//! ));
+//! ```
+
+mod adapters;
+mod app;
+mod changelog;
+mod cli;
+mod compiler;
+mod config;
+mod domain;
+mod error;
+mod hook;
+mod input;
+pub mod ports;
+mod symbols;
+mod validate;
+
+pub use app::{AppController, AppControllerBuilder};
+pub use cli::run;
+pub use compiler::{CommitAst, CompileError, CompilerPipeline, SpannedToken, Token};
+pub use domain::{CommitMessage, CommitType, DomainError, ValidationConfig, ValidationReport};
+pub use error::CommandoError;
+pub use symbols::Symbols;
+
+/// Compile and validate a raw message with the default rules.
+///
+/// Same as [`CommitMessage::parse`].
+pub fn compile_and_validate(input: &str) -> Result<CommitMessage, CommandoError> {
+    CommitMessage::parse(input)
+}
@@ -1,12 +1,37 @@
 pub struct CommitResult {
     pub sha: String,
     pub summary: String,
+    /// From git's "N files changed" line; None when git's output was not
+    /// captured (--stream) or had no such line.
+    pub files_changed: Option<usize>,
+}
+
+impl CommitResult {
+    /// The first 7 characters of the SHA, as git abbreviates it by default.
+    pub fn short_sha(&self) -> &str {
+        let end = self
+            .sha
+            .char_indices()
+            .nth(7)
+            .map_or(self.sha.len(), |(i, _)| i);
+        &self.sha[..end]
+    }
 }
 
 pub trait CommitExecutor {
     type Error;
 
     fn execute(&self, message: &str) -> Result<CommitResult, Self::Error>;
+
+    /// The committer identity as "Name <email>", used for Signed-off-by.
+    fn identity(&self) -> Result<String, Self::Error>;
+
+    /// The SHA HEAD points at; None before the first commit.
+    fn head(&self) -> Result<Option<String>, Self::Error>;
+
+    /// Point HEAD back at `sha`, keeping the index and working tree
+    /// (`git reset --soft`).
+    fn reset_soft(&self, sha: &str) -> Result<(), Self::Error>;
 }
 
 pub trait DryRunner {
@@ -1,5 +1,5 @@
 /// Input port — contract between input sources and the application.
-use crate::domain::{CommitMessage, CommitType, DomainError};
+use crate::domain::{CommitMessage, CommitType, DomainError, ValidationConfig};
 
 /// Output of InteractiveSource — fields already parsed and individually validated.
 #[derive(Debug, Clone)]
@@ -9,29 +9,60 @@ pub struct StructuredInput {
     pub description: String,
     pub body: Option<String>,
     pub breaking_change: Option<String>,
-    /// Refs, Closes, Co-authored-by, etc. — anything the user typed in the
-    /// refs prompt. Stored as a single raw string and threaded through as a
-    /// single footer entry keyed "Refs" if present.
+    /// Render the header with '!' even without a breaking_change.
+    pub breaking: bool,
+    /// Issues this commit resolves, e.g. "#12" — a `Closes` footer.
+    pub closes: Option<String>,
+    /// Related issues, e.g. "#34, GH-5" — a `Refs` footer after Closes.
     pub refs: Option<String>,
+    /// "Name <email>" entries, each rendered as a Co-authored-by trailer
+    /// after Refs.
+    pub co_authors: Vec<String>,
+    /// Any other footers, rendered as they are after the co-authors
+    /// (e.g. Signed-off-by kept from the message being amended).
+    pub footers: Vec<(String, String)>,
 }
 
 impl TryFrom<StructuredInput> for CommitMessage {
     type Error = DomainError;
 
     fn try_from(s: StructuredInput) -> Result<Self, DomainError> {
-        let footers = match s.refs {
-            Some(refs) => vec![("Refs".to_string(), refs)],
-            None => vec![],
-        };
+        s.into_commit_message(&ValidationConfig::default())
+    }
+}
+
+impl StructuredInput {
+    /// Same as TryFrom, validating against the given config.
+    pub fn into_commit_message(
+        self,
+        config: &ValidationConfig,
+    ) -> Result<CommitMessage, DomainError> {
+        let mut footers: Vec<(String, String)> = [("Closes", self.closes), ("Refs", self.refs)]
+            .into_iter()
+            .filter_map(|(key, value)| Some((key.to_string(), value?)))
+            .collect();
+        footers.extend(
+            self.co_authors
+                .into_iter()
+                .map(|identity| ("Co-authored-by".to_string(), identity)),
+        );
+        footers.extend(self.footers);
+
+        // As from_ast: a bare '!' may need a footer saying what broke.
+        if self.breaking && self.breaking_change.is_none() && config.breaking_requires_footer {
+            return Err(DomainError::BreakingChangeFooterRequired);
+        }
 
         CommitMessage::new(
-            s.commit_type,
-            s.scope,
-            s.description,
-            s.body,
-            s.breaking_change,
+            self.commit_type,
+            self.scope,
+            self.description,
+            self.body,
+            self.breaking_change,
             footers,
+            config,
         )
+        .map(|message| message.with_breaking(self.breaking))
     }
 }
 
@@ -47,4 +78,15 @@ pub trait InputSource {
 pub trait CommitMessageSource {
     type Error: std::fmt::Display;
     fn resolve(&self) -> Result<CommitMessage, Self::Error>;
+
+    /// Whether revise() can change anything — only prompting sources can.
+    fn can_revise(&self) -> bool {
+        false
+    }
+
+    /// Let the user change an already-resolved message, e.g. after git
+    /// rejected the commit. Sources without prompts return it unchanged.
+    fn revise(&self, current: &CommitMessage) -> Result<CommitMessage, Self::Error> {
+        Ok(current.clone())
+    }
 }
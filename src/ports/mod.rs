@@ -4,4 +4,4 @@ pub mod staging;
 pub mod ui;
 
 pub use executor::{CommitExecutor, CommitResult, DryRunner};
-pub use staging::StagingChecker;
+pub use staging::{DiffStat, FileStat, StagedFile, StagingChecker};
@@ -1,5 +1,74 @@
+/// One entry of the index, as `git diff --cached --name-status` reports it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagedFile {
+    /// 'A', 'M', 'D', 'R', ... — the similarity score of a rename is dropped.
+    pub status: char,
+    pub path: String,
+    /// The old path of a rename or copy.
+    pub from: Option<String>,
+}
+
+impl std::fmt::Display for StagedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.from {
+            Some(ref from) => write!(f, "{} {} → {}", self.status, from, self.path),
+            None => write!(f, "{} {}", self.status, self.path),
+        }
+    }
+}
+
+/// One file's line of `git diff --cached --stat`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileStat {
+    pub path: String,
+    /// What follows the `|`: "12 +++++-----", or "Bin 0 -> 1024 bytes".
+    pub changes: String,
+}
+
+/// `git diff --cached --stat`, re-aligned for display before confirming.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffStat {
+    pub files: Vec<FileStat>,
+    /// "2 files changed, 7 insertions(+), 5 deletions(-)"
+    pub summary: String,
+}
+
+impl std::fmt::Display for DiffStat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .files
+            .iter()
+            .map(|file| file.path.chars().count())
+            .max()
+            .unwrap_or(0);
+        for file in &self.files {
+            writeln!(f, " {:<width$} | {}", file.path, file.changes)?;
+        }
+        write!(f, " {}", self.summary)
+    }
+}
+
 pub trait StagingChecker {
     type Error;
 
+    /// Whether the working directory is inside a repository at all.
+    /// Checked before anything else so the user isn't asked for a message
+    /// that can never be committed.
+    fn in_repository(&self) -> Result<bool, Self::Error>;
+
     fn has_staged_changes(&self) -> Result<bool, Self::Error>;
+
+    /// What is staged, shown before prompting to help pick a scope.
+    fn staged_files(&self) -> Result<Vec<StagedFile>, Self::Error>;
+
+    /// Per-file line counts of what is staged, shown with --show-diff.
+    fn staged_diffstat(&self) -> Result<DiffStat, Self::Error>;
+
+    /// Whether anything matching a pathspec (a file or a directory, as
+    /// given on the command line) is staged.
+    fn is_staged(&self, pathspec: &str) -> Result<bool, Self::Error>;
+
+    /// Whether anything matching a pathspec differs between the index and
+    /// the working tree (`git diff --quiet -- <pathspec>` fails).
+    fn has_unstaged_changes(&self, pathspec: &str) -> Result<bool, Self::Error>;
 }
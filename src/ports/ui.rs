@@ -20,6 +20,15 @@ impl From<std::io::Error> for UiError {
     }
 }
 
+/// y/yes is true, an empty answer is `default`, anything else is false.
+pub fn answer(input: &str, default: bool) -> bool {
+    match input.trim().to_lowercase().as_str() {
+        "" => default,
+        "y" | "yes" => true,
+        _ => false,
+    }
+}
+
 pub trait Ui {
     /// Prompt the user with a label, return trimmed input.
     fn prompt(&self, label: &str) -> Result<String, UiError>;
@@ -30,6 +39,155 @@ pub trait Ui {
     /// Ask a yes/no question. Returns true for y/yes.
     fn confirm(&self, msg: &str) -> Result<bool, UiError>;
 
+    /// Ask a yes/no question where pressing Enter means `default`.
+    /// The hint shows which: "(Y/n)" or "(y/N)".
+    fn confirm_or(&self, msg: &str, default: bool) -> Result<bool, UiError> {
+        let hint = if default { "Y/n" } else { "y/N" };
+        let input = self.prompt(&format!("{} ({}): ", msg, hint))?;
+        Ok(answer(&input, default))
+    }
+
     /// Print a line (with newline).
     fn println(&self, msg: &str);
+
+    /// Collect free text spanning several lines (a commit body), trimmed.
+    ///
+    /// The default reads prompt() lines until a blank one, so it cannot
+    /// hold a blank line itself; UIs that can tell "done" apart from an
+    /// empty line (Ctrl-D, a text area) should override it.
+    fn multiline_prompt(&self, label: &str) -> Result<String, UiError> {
+        self.println(&format!("{} (blank line to finish):", label));
+        self.println("");
+        let mut lines: Vec<String> = Vec::new();
+        loop {
+            let line = self.prompt("")?;
+            if line.is_empty() && !lines.is_empty() {
+                break;
+            }
+            lines.push(line);
+        }
+        Ok(lines.join("\n").trim().to_string())
+    }
+
+    /// Offer a menu of `(value, description)` options and return the index
+    /// picked, with `default` preselected.
+    ///
+    /// Ok(None) means this UI has no menu — callers fall back to prompt().
+    /// That is the default, so plain line-based UIs need not implement it.
+    fn select(
+        &self,
+        _label: &str,
+        _options: &[(&str, &str)],
+        _default: Option<usize>,
+    ) -> Result<Option<usize>, UiError> {
+        Ok(None)
+    }
+
+    /// Run `f`, showing that `label` is in progress until it returns.
+    ///
+    /// The default just runs it; a terminal UI draws a spinner.
+    fn with_progress<T>(&self, _label: &str, f: impl FnOnce() -> T) -> T {
+        f()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Answers every prompt with `input` and remembers the last label.
+    /// Scripted `lines`, if any, are answered first.
+    struct MockUi {
+        input: &'static str,
+        lines: RefCell<Vec<&'static str>>,
+        label: RefCell<String>,
+    }
+
+    impl MockUi {
+        fn answering(input: &'static str) -> Self {
+            Self {
+                input,
+                lines: RefCell::new(Vec::new()),
+                label: RefCell::new(String::new()),
+            }
+        }
+
+        fn typing(lines: &[&'static str]) -> Self {
+            let ui = Self::answering("");
+            ui.lines.borrow_mut().extend(lines.iter().rev());
+            ui
+        }
+    }
+
+    impl Ui for MockUi {
+        fn prompt(&self, label: &str) -> Result<String, UiError> {
+            *self.label.borrow_mut() = label.to_string();
+            Ok(self
+                .lines
+                .borrow_mut()
+                .pop()
+                .unwrap_or(self.input)
+                .to_string())
+        }
+        fn show_preview(&self, _: &str) {}
+        fn confirm(&self, msg: &str) -> Result<bool, UiError> {
+            self.confirm_or(msg, false)
+        }
+        fn println(&self, _: &str) {}
+    }
+
+    #[test]
+    fn enter_takes_the_default() {
+        assert!(MockUi::answering("").confirm_or("Proceed?", true).unwrap());
+        assert!(!MockUi::answering("").confirm_or("Proceed?", false).unwrap());
+    }
+
+    #[test]
+    fn explicit_answers_ignore_the_default() {
+        assert!(
+            MockUi::answering("Y")
+                .confirm_or("Proceed?", false)
+                .unwrap()
+        );
+        assert!(
+            !MockUi::answering("no")
+                .confirm_or("Proceed?", true)
+                .unwrap()
+        );
+        assert!(
+            !MockUi::answering("maybe")
+                .confirm_or("Proceed?", true)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn with_progress_runs_the_closure_once() {
+        let calls = RefCell::new(0);
+        let result = MockUi::answering("").with_progress("Working", || {
+            *calls.borrow_mut() += 1;
+            42
+        });
+        assert_eq!(result, 42);
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[test]
+    fn multiline_prompt_reads_lines_until_a_blank_one() {
+        let ui = MockUi::typing(&["", "  First line.", "Second line.", "", "ignored"]);
+        assert_eq!(
+            ui.multiline_prompt("Body").unwrap(),
+            "First line.\nSecond line."
+        );
+    }
+
+    #[test]
+    fn hint_shows_the_default() {
+        let ui = MockUi::answering("");
+        ui.confirm_or("Proceed?", true).unwrap();
+        assert_eq!(*ui.label.borrow(), "Proceed? (Y/n): ");
+        ui.confirm_or("Proceed?", false).unwrap();
+        assert_eq!(*ui.label.borrow(), "Proceed? (y/N): ");
+    }
 }
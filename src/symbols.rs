@@ -0,0 +1,41 @@
+//! Status glyphs — the marks in front of progress, warning and error lines.
+//!
+//! Chosen once at startup and handed to whatever prints them, so plain
+//! ASCII output (`--ascii` / `ascii_output = true`) is a single switch:
+//! for terminals that draw the Unicode marks as boxes, and for logs that
+//! are grepped.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbols {
+    pub ok: &'static str,
+    pub error: &'static str,
+    pub warning: &'static str,
+    /// Separates a message from the hint after it.
+    pub dash: &'static str,
+}
+
+impl Symbols {
+    pub const UNICODE: Self = Self {
+        ok: "✓",
+        error: "✗",
+        warning: "⚠",
+        dash: "—",
+    };
+
+    pub const ASCII: Self = Self {
+        ok: "[OK]",
+        error: "[X]",
+        warning: "[!]",
+        dash: "-",
+    };
+
+    pub fn new(ascii: bool) -> Self {
+        if ascii { Self::ASCII } else { Self::UNICODE }
+    }
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Self::UNICODE
+    }
+}
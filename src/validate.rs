@@ -0,0 +1,275 @@
+/// Validate-only mode — check a message without committing.
+///
+/// Runs the same compiler + domain validation as every input source, but
+/// reports instead of committing, so CI can lint messages (e.g. PR titles
+/// or a commit-msg hook). No git access, no prompts.
+///
+/// The report is built from the AST rather than CommitMessage so that
+/// fields are still shown when only domain validation fails.
+use serde::Serialize;
+
+use crate::compiler::{CommitAst, CompileError, CompilerPipeline};
+use crate::domain::{CommitMessage, DomainError, ValidationConfig, is_breaking_change_key};
+use crate::symbols::Symbols;
+
+/// What --validate prints, as text or JSON: domain::ValidationReport's
+/// errors and warnings, plus the fields that were parsed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidateOutput {
+    pub valid: bool,
+    #[serde(rename = "type")]
+    pub commit_type: Option<String>,
+    pub scope: Option<String>,
+    pub description: Option<String>,
+    pub breaking: bool,
+    pub footers: Vec<FooterReport>,
+    pub errors: Vec<ErrorReport>,
+    /// Mood, trailing period, header length: reported, not failed on.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FooterReport {
+    pub key: String,
+    pub value: String,
+}
+
+/// line/column are present for compile errors, null for domain errors.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ErrorReport {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl ErrorReport {
+    fn domain(error: &DomainError) -> Self {
+        Self {
+            message: error.to_string(),
+            line: None,
+            column: None,
+        }
+    }
+}
+
+impl ValidateOutput {
+    /// `strict` (--strict) makes the warnings fail validation like errors.
+    pub fn new(raw: &str, config: &ValidationConfig, strict: bool) -> Self {
+        let ast = match CompilerPipeline::new()
+            .with_gitmoji(config.gitmoji)
+            .with_normalize_unicode(config.normalize_unicode)
+            .compile(raw)
+        {
+            Ok(ast) => ast,
+            Err(e) => return Self::compile_failure(&e),
+        };
+
+        let mut report = Self::from_ast(&ast);
+        let mut checked = CommitMessage::validate_report(ast, config);
+        if strict {
+            checked = checked.strict();
+        }
+        report.valid = checked.is_valid();
+        report.errors = checked.errors.iter().map(ErrorReport::domain).collect();
+        report.warnings = checked.warnings;
+        report
+    }
+
+    fn from_ast(ast: &CommitAst) -> Self {
+        Self {
+            valid: true,
+            commit_type: Some(ast.header.commit_type.clone()),
+            scope: ast.header.scope.clone(),
+            description: Some(ast.header.description.clone()),
+            breaking: ast.header.breaking
+                || ast.footers.iter().any(|f| is_breaking_change_key(&f.key)),
+            footers: ast
+                .footers
+                .iter()
+                .map(|f| FooterReport {
+                    key: f.key.clone(),
+                    value: f.value.clone(),
+                })
+                .collect(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn compile_failure(error: &CompileError) -> Self {
+        let (line, column) = error.position();
+        Self {
+            valid: false,
+            commit_type: None,
+            scope: None,
+            description: None,
+            breaking: false,
+            footers: Vec::new(),
+            errors: vec![ErrorReport {
+                message: error.message(),
+                line: Some(line),
+                column: Some(column),
+            }],
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        // Plain strings, bools and numbers only — serialization cannot fail.
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// The verdict or the errors, then any warnings.
+    pub fn to_text(&self, symbols: Symbols) -> String {
+        let mut lines: Vec<String> = if self.valid {
+            vec![format!("{} Valid commit message", symbols.ok)]
+        } else {
+            self.errors
+                .iter()
+                .map(|e| match (e.line, e.column) {
+                    (Some(line), Some(column)) => {
+                        format!(
+                            "{} line {}, column {}: {}",
+                            symbols.error, line, column, e.message
+                        )
+                    }
+                    _ => format!("{} {}", symbols.error, e.message),
+                })
+                .collect()
+        };
+        lines.extend(
+            self.warnings
+                .iter()
+                .map(|w| format!("{} {}", symbols.warning, w)),
+        );
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn json_of(raw: &str) -> Value {
+        let report = ValidateOutput::new(raw, &ValidationConfig::default(), false);
+        serde_json::from_str(&report.to_json()).unwrap()
+    }
+
+    #[test]
+    fn valid_message_json_shape() {
+        assert_eq!(
+            json_of("feat(api)!: drop v1\n\nBody.\n\nBREAKING CHANGE: gone\nRefs: #12"),
+            json!({
+                "valid": true,
+                "type": "feat",
+                "scope": "api",
+                "description": "drop v1",
+                "breaking": true,
+                "footers": [
+                    { "key": "BREAKING CHANGE", "value": "gone" },
+                    { "key": "Refs", "value": "#12" }
+                ],
+                "errors": [],
+                "warnings": []
+            })
+        );
+    }
+
+    #[test]
+    fn compile_error_json_has_position_and_no_fields() {
+        assert_eq!(
+            json_of("feat(api: x"),
+            json!({
+                "valid": false,
+                "type": null,
+                "scope": null,
+                "description": null,
+                "breaking": false,
+                "footers": [],
+                "errors": [
+                    { "message": "Unclosed '(' in scope", "line": 1, "column": 5 }
+                ],
+                "warnings": []
+            })
+        );
+    }
+
+    #[test]
+    fn domain_error_json_keeps_parsed_fields() {
+        let value = json_of("nope: add login");
+        assert_eq!(value["valid"], json!(false));
+        assert_eq!(value["type"], json!("nope"));
+        assert_eq!(value["description"], json!("add login"));
+        assert_eq!(value["errors"][0]["line"], Value::Null);
+        assert!(
+            value["errors"][0]["message"]
+                .as_str()
+                .unwrap()
+                .starts_with("Invalid commit type: 'nope'")
+        );
+    }
+
+    #[test]
+    fn every_domain_error_is_reported() {
+        let value = json_of("nope(bad scope): x");
+        let messages: Vec<&str> = value["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["message"].as_str().unwrap())
+            .collect();
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].starts_with("Invalid commit type"));
+        assert!(messages[1].starts_with("Invalid scope"));
+    }
+
+    #[test]
+    fn warnings_are_reported_beside_errors() {
+        let config = ValidationConfig::default();
+        let report = ValidateOutput::new("feat(Bad): added login.", &config, false);
+        assert!(!report.valid);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(
+            report.warnings,
+            [
+                "Use 'add' instead of 'added'",
+                "Description ends with a period"
+            ]
+        );
+
+        let report = ValidateOutput::new("feat: added login", &config, false);
+        assert!(report.valid);
+        assert_eq!(
+            report.to_text(Symbols::UNICODE),
+            "✓ Valid commit message\n⚠ Use 'add' instead of 'added'"
+        );
+    }
+
+    #[test]
+    fn strict_fails_on_warnings() {
+        let config = ValidationConfig::default();
+        let report = ValidateOutput::new("feat: added login", &config, true);
+        assert!(!report.valid);
+        assert!(report.warnings.is_empty());
+        assert_eq!(report.errors[0].message, "Use 'add' instead of 'added'");
+        assert!(ValidateOutput::new("feat: add login", &config, true).valid);
+    }
+
+    #[test]
+    fn text_output() {
+        let config = ValidationConfig::default();
+        assert_eq!(
+            ValidateOutput::new("fix: x", &config, false).to_text(Symbols::UNICODE),
+            "✓ Valid commit message"
+        );
+        assert_eq!(
+            ValidateOutput::new("fix x", &config, false).to_text(Symbols::UNICODE),
+            "✗ line 1, column 6: Missing ':' in header"
+        );
+        assert_eq!(
+            ValidateOutput::new("fix x", &config, false).to_text(Symbols::ASCII),
+            "[X] line 1, column 6: Missing ':' in header"
+        );
+    }
+}